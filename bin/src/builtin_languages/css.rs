@@ -0,0 +1,58 @@
+use matching::matching_configuration::MatchingConfiguration;
+use merge::PrinterConfiguration;
+use parsing::ParserConfiguration;
+
+use crate::LanguageSupport;
+
+/// CSS, mostly merged as the contents of `<style>` elements. Rules and declarations are merged
+/// in order, as later ones override earlier ones.
+pub struct Css {
+    parser: ParserConfiguration,
+    matching: MatchingConfiguration<'static>,
+    printer: PrinterConfiguration,
+}
+
+impl Default for Css {
+    fn default() -> Self {
+        let parser = ParserConfiguration::new(
+            parsing::get_builtin_grammar("css").expect("CSS is a built-in grammar"),
+        )
+        // Selectors and values are kept as they were written, as CSS is sensitive to
+        // whitespace inside them, e.g. in descendant selectors
+        .with_stop_compilation_at(&["selectors", "declaration"])
+        .with_block_end_delimiters(&["}"]);
+
+        let printer = PrinterConfiguration {
+            kinds_with_line_separated_children: ["stylesheet", "block"].into(),
+            ..Default::default()
+        };
+
+        Css {
+            parser,
+            matching: MatchingConfiguration::new().with_delimiters(&["{", "}"]),
+            printer,
+        }
+    }
+}
+
+impl LanguageSupport for Css {
+    fn name(&self) -> &str {
+        "css"
+    }
+
+    fn extensions(&self) -> Vec<&str> {
+        vec!["css"]
+    }
+
+    fn parser_configuration(&self) -> &ParserConfiguration {
+        &self.parser
+    }
+
+    fn matching_configuration(&self) -> &MatchingConfiguration<'static> {
+        &self.matching
+    }
+
+    fn printer_configuration(&self) -> &PrinterConfiguration {
+        &self.printer
+    }
+}
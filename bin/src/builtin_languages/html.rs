@@ -0,0 +1,63 @@
+use matching::matching_configuration::MatchingConfiguration;
+use merge::PrinterConfiguration;
+use parsing::ParserConfiguration;
+
+use crate::LanguageSupport;
+
+const INJECTIONS: &str = r#"
+(script_element (raw_text) @injection.content (#set! injection.language "javascript"))
+(style_element (raw_text) @injection.content (#set! injection.language "css"))
+"#;
+
+pub struct Html {
+    parser: ParserConfiguration,
+    matching: MatchingConfiguration<'static>,
+    printer: PrinterConfiguration,
+}
+
+impl Default for Html {
+    fn default() -> Self {
+        let parser = ParserConfiguration::new(
+            parsing::get_builtin_grammar("html").expect("HTML is a built-in grammar"),
+        )
+        // Tags are kept as they were written, as HTML does not allow whitespace between their
+        // delimiters and names
+        .with_stop_compilation_at(&[
+            "doctype",
+            "start_tag",
+            "end_tag",
+            "self_closing_tag",
+            "erroneous_end_tag",
+        ])
+        .with_injections(INJECTIONS)
+        .expect("Built-in injection queries must be valid");
+
+        Html {
+            parser,
+            matching: MatchingConfiguration::new(),
+            printer: PrinterConfiguration::default(),
+        }
+    }
+}
+
+impl LanguageSupport for Html {
+    fn name(&self) -> &str {
+        "html"
+    }
+
+    fn extensions(&self) -> Vec<&str> {
+        vec!["html", "htm"]
+    }
+
+    fn parser_configuration(&self) -> &ParserConfiguration {
+        &self.parser
+    }
+
+    fn matching_configuration(&self) -> &MatchingConfiguration<'static> {
+        &self.matching
+    }
+
+    fn printer_configuration(&self) -> &PrinterConfiguration {
+        &self.printer
+    }
+}
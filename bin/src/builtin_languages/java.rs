@@ -0,0 +1,127 @@
+use matching::matching_configuration::MatchingConfiguration;
+use matching_handlers::{get_default_java_matching_handlers, DEFAULT_JAVA_KINDS_WITH_LABEL};
+use merge::{ImportOrdering, MergedCSTNode, PrinterConfiguration};
+use model::CSTNode;
+use parsing::ParserConfiguration;
+use parsing_handlers::get_default_java_parsing_handlers;
+
+use crate::LanguageSupport;
+
+/// Lists whose separators are dropped while parsing and printed back between their elements.
+const KINDS_WITH_SEPARATED_CHILDREN: [(&str, &str); 7] = [
+    ("argument_list", ","),
+    ("array_initializer", ","),
+    ("annotation_argument_list", ","),
+    ("element_value_array_initializer", ","),
+    ("enum_body", ","),
+    ("type_list", ","),
+    ("throws", ","),
+];
+
+/// Children of the separated lists that are not elements of the list. The keyword leads the
+/// thrown types, which the parsing handlers group in a type_list, and comments the parsing
+/// handlers are told to keep may sit between elements.
+const SEPARATED_LIST_DELIMITERS: [&str; 8] = [
+    "(",
+    ")",
+    "{",
+    "}",
+    "enum_body_declarations",
+    "throws",
+    "block_comment",
+    "line_comment",
+];
+
+pub struct Java {
+    parser: ParserConfiguration,
+    matching: MatchingConfiguration<'static>,
+    printer: PrinterConfiguration,
+}
+
+impl Default for Java {
+    fn default() -> Self {
+        let parser = ParserConfiguration::new(
+            parsing::get_builtin_grammar("java").expect("Java is a built-in grammar"),
+        )
+        .with_kinds_with_unordered_children(&[
+            "interface_body",
+            "class_body",
+            "enum_body_declarations",
+        ])
+        .with_block_end_delimiters(&["}"])
+        .with_kinds_with_separated_children(&KINDS_WITH_SEPARATED_CHILDREN)
+        .with_separated_list_delimiters(&SEPARATED_LIST_DELIMITERS)
+        .with_handlers(get_default_java_parsing_handlers());
+
+        let matching = MatchingConfiguration::new()
+            .with_delimiters(&["{", "}", ";"])
+            .with_kinds_with_label(&DEFAULT_JAVA_KINDS_WITH_LABEL)
+            .with_handlers(get_default_java_matching_handlers());
+
+        let printer = PrinterConfiguration {
+            kinds_with_separated_children: KINDS_WITH_SEPARATED_CHILDREN.into(),
+            delimiters: SEPARATED_LIST_DELIMITERS.into(),
+            ..Default::default()
+        };
+
+        Java {
+            parser,
+            matching,
+            printer,
+        }
+    }
+}
+
+impl LanguageSupport for Java {
+    fn name(&self) -> &str {
+        "java"
+    }
+
+    fn extensions(&self) -> Vec<&str> {
+        vec!["java"]
+    }
+
+    fn parser_configuration(&self) -> &ParserConfiguration {
+        &self.parser
+    }
+
+    fn parser_configuration_mut(&mut self) -> Option<&mut ParserConfiguration> {
+        Some(&mut self.parser)
+    }
+
+    fn matching_configuration(&self) -> &MatchingConfiguration<'static> {
+        &self.matching
+    }
+
+    fn printer_configuration(&self) -> &PrinterConfiguration {
+        &self.printer
+    }
+
+    fn detect_semantic_conflicts<'a>(
+        &self,
+        _base: &'a CSTNode<'a>,
+        _left: &'a CSTNode<'a>,
+        _right: &'a CSTNode<'a>,
+        merged: MergedCSTNode<'a>,
+    ) -> MergedCSTNode<'a> {
+        merge::detect_java_access_modifier_conflicts(merged)
+    }
+
+    fn normalize_imports<'a>(
+        &self,
+        ordering: &ImportOrdering,
+        left: &'a CSTNode<'a>,
+        right: &'a CSTNode<'a>,
+        merged: MergedCSTNode<'a>,
+    ) -> MergedCSTNode<'a> {
+        merge::normalize_java_imports(ordering, left, right, merged)
+    }
+
+    fn remove_unused_imports<'a>(
+        &self,
+        base: &'a CSTNode<'a>,
+        merged: MergedCSTNode<'a>,
+    ) -> MergedCSTNode<'a> {
+        merge::remove_unused_java_imports(base, merged)
+    }
+}
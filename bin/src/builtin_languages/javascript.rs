@@ -0,0 +1,77 @@
+use matching::matching_configuration::MatchingConfiguration;
+use matching_handlers::get_default_javascript_matching_handlers;
+use merge::PrinterConfiguration;
+use parsing::ParserConfiguration;
+use parsing_handlers::get_default_javascript_parsing_handlers;
+
+use crate::LanguageSupport;
+
+pub struct JavaScript {
+    parser: ParserConfiguration,
+    matching: MatchingConfiguration<'static>,
+    printer: PrinterConfiguration,
+}
+
+impl Default for JavaScript {
+    fn default() -> Self {
+        let parser = ParserConfiguration::new(
+            parsing::get_builtin_grammar("javascript").expect("JavaScript is a built-in grammar"),
+        )
+        .with_stop_compilation_at(&["string", "template_string", "regex"])
+        .with_kinds_with_unordered_children(&["class_body"])
+        .with_block_end_delimiters(&["}"])
+        .with_handlers(get_default_javascript_parsing_handlers());
+
+        let matching = MatchingConfiguration::new()
+            .with_delimiters(&["{", "}", ";"])
+            .with_kinds_with_label(&[
+                "function_declaration",
+                "generator_function_declaration",
+                "class_declaration",
+                "method_definition",
+                "field_definition",
+            ])
+            .with_handlers(get_default_javascript_matching_handlers());
+
+        let printer = PrinterConfiguration {
+            kinds_with_line_separated_children: ["program", "statement_block", "class_body"].into(),
+            ..Default::default()
+        };
+
+        JavaScript {
+            parser,
+            matching,
+            printer,
+        }
+    }
+}
+
+impl LanguageSupport for JavaScript {
+    fn name(&self) -> &str {
+        "javascript"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["js"]
+    }
+
+    fn extensions(&self) -> Vec<&str> {
+        vec!["js", "mjs", "cjs"]
+    }
+
+    fn parser_configuration(&self) -> &ParserConfiguration {
+        &self.parser
+    }
+
+    fn parser_configuration_mut(&mut self) -> Option<&mut ParserConfiguration> {
+        Some(&mut self.parser)
+    }
+
+    fn matching_configuration(&self) -> &MatchingConfiguration<'static> {
+        &self.matching
+    }
+
+    fn printer_configuration(&self) -> &PrinterConfiguration {
+        &self.printer
+    }
+}
@@ -0,0 +1,79 @@
+use matching::matching_configuration::MatchingConfiguration;
+use merge::PrinterConfiguration;
+use parsing::ParserConfiguration;
+
+use crate::LanguageSupport;
+
+const INJECTIONS: &str = r#"
+(fenced_code_block
+  (info_string (language) @injection.language)
+  (code_fence_content) @injection.content)
+"#;
+
+pub struct Markdown {
+    parser: ParserConfiguration,
+    matching: MatchingConfiguration<'static>,
+    printer: PrinterConfiguration,
+}
+
+impl Default for Markdown {
+    fn default() -> Self {
+        let parser = ParserConfiguration::new(
+            parsing::get_builtin_grammar("markdown").expect("Markdown is a built-in grammar"),
+        )
+        // The grammar does not expose the text of blocks as nodes, so only code fences are
+        // parsed further
+        .with_stop_compilation_at(&[
+            "atx_heading",
+            "setext_heading",
+            "paragraph",
+            "list",
+            "block_quote",
+            "html_block",
+            "thematic_break",
+            "indented_code_block",
+            "link_reference_definition",
+            "info_string",
+        ])
+        .with_injections(INJECTIONS)
+        .expect("Built-in injection queries must be valid");
+
+        let printer = PrinterConfiguration {
+            kinds_with_line_separated_children: ["document"].into(),
+            kinds_on_separate_lines: ["code_fence_content"].into(),
+            ..Default::default()
+        };
+
+        Markdown {
+            parser,
+            matching: MatchingConfiguration::new(),
+            printer,
+        }
+    }
+}
+
+impl LanguageSupport for Markdown {
+    fn name(&self) -> &str {
+        "markdown"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["md"]
+    }
+
+    fn extensions(&self) -> Vec<&str> {
+        vec!["md", "markdown"]
+    }
+
+    fn parser_configuration(&self) -> &ParserConfiguration {
+        &self.parser
+    }
+
+    fn matching_configuration(&self) -> &MatchingConfiguration<'static> {
+        &self.matching
+    }
+
+    fn printer_configuration(&self) -> &PrinterConfiguration {
+        &self.printer
+    }
+}
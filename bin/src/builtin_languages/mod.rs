@@ -0,0 +1,21 @@
+mod css;
+mod html;
+mod java;
+mod javascript;
+mod markdown;
+mod php;
+mod protobuf;
+mod scala;
+mod swift;
+mod toml;
+
+pub use self::toml::Toml;
+pub use css::Css;
+pub use html::Html;
+pub use java::Java;
+pub use javascript::JavaScript;
+pub use markdown::Markdown;
+pub use php::Php;
+pub use protobuf::Protobuf;
+pub use scala::Scala;
+pub use swift::Swift;
@@ -0,0 +1,71 @@
+use matching::matching_configuration::MatchingConfiguration;
+use matching_handlers::get_default_php_matching_handlers;
+use merge::PrinterConfiguration;
+use parsing::ParserConfiguration;
+use parsing_handlers::get_default_php_parsing_handlers;
+
+use crate::LanguageSupport;
+
+pub struct Php {
+    parser: ParserConfiguration,
+    matching: MatchingConfiguration<'static>,
+    printer: PrinterConfiguration,
+}
+
+impl Default for Php {
+    fn default() -> Self {
+        let parser = ParserConfiguration::new(
+            parsing::get_builtin_grammar("php").expect("PHP is a built-in grammar"),
+        )
+        .with_kinds_with_unordered_children(&["declaration_list", "enum_declaration_list"])
+        .with_block_end_delimiters(&["}"])
+        .with_handlers(get_default_php_parsing_handlers());
+
+        let matching = MatchingConfiguration::new()
+            .with_delimiters(&["{", "}", ";"])
+            .with_kinds_with_label(&[
+                "namespace_use_declaration",
+                "use_declaration",
+                "const_declaration",
+                "property_declaration",
+                "method_declaration",
+                "class_declaration",
+                "interface_declaration",
+                "trait_declaration",
+                "enum_declaration",
+            ])
+            .with_handlers(get_default_php_matching_handlers());
+
+        Php {
+            parser,
+            matching,
+            printer: PrinterConfiguration::default(),
+        }
+    }
+}
+
+impl LanguageSupport for Php {
+    fn name(&self) -> &str {
+        "php"
+    }
+
+    fn extensions(&self) -> Vec<&str> {
+        vec!["php"]
+    }
+
+    fn parser_configuration(&self) -> &ParserConfiguration {
+        &self.parser
+    }
+
+    fn parser_configuration_mut(&mut self) -> Option<&mut ParserConfiguration> {
+        Some(&mut self.parser)
+    }
+
+    fn matching_configuration(&self) -> &MatchingConfiguration<'static> {
+        &self.matching
+    }
+
+    fn printer_configuration(&self) -> &PrinterConfiguration {
+        &self.printer
+    }
+}
@@ -0,0 +1,89 @@
+use matching::matching_configuration::MatchingConfiguration;
+use matching_handlers::get_default_protobuf_matching_handlers;
+use merge::{MergedCSTNode, PrinterConfiguration};
+use model::CSTNode;
+use parsing::ParserConfiguration;
+use parsing_handlers::get_default_protobuf_parsing_handlers;
+
+use crate::LanguageSupport;
+
+pub struct Protobuf {
+    parser: ParserConfiguration,
+    matching: MatchingConfiguration<'static>,
+    printer: PrinterConfiguration,
+}
+
+impl Default for Protobuf {
+    fn default() -> Self {
+        let parser = ParserConfiguration::new(
+            parsing::get_builtin_grammar("proto").expect("Protobuf is a built-in grammar"),
+        )
+        // The grammar does not expose the content of strings as nodes
+        .with_stop_compilation_at(&["string"])
+        .with_kinds_with_unordered_children(&["message_body", "enum_body", "service_body"])
+        .with_block_end_delimiters(&["}"])
+        .with_handlers(get_default_protobuf_parsing_handlers());
+
+        let matching = MatchingConfiguration::new()
+            .with_delimiters(&["{", "}", ";"])
+            .with_kinds_with_label(&[
+                "import",
+                "field",
+                "map_field",
+                "oneof",
+                "oneof_field",
+                "enum_field",
+                "message",
+                "enum",
+                "service",
+                "rpc",
+            ])
+            .with_handlers(get_default_protobuf_matching_handlers());
+
+        Protobuf {
+            parser,
+            matching,
+            printer: PrinterConfiguration::default(),
+        }
+    }
+}
+
+impl LanguageSupport for Protobuf {
+    fn name(&self) -> &str {
+        "protobuf"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["proto"]
+    }
+
+    fn extensions(&self) -> Vec<&str> {
+        vec!["proto"]
+    }
+
+    fn parser_configuration(&self) -> &ParserConfiguration {
+        &self.parser
+    }
+
+    fn parser_configuration_mut(&mut self) -> Option<&mut ParserConfiguration> {
+        Some(&mut self.parser)
+    }
+
+    fn matching_configuration(&self) -> &MatchingConfiguration<'static> {
+        &self.matching
+    }
+
+    fn printer_configuration(&self) -> &PrinterConfiguration {
+        &self.printer
+    }
+
+    fn detect_semantic_conflicts<'a>(
+        &self,
+        base: &'a CSTNode<'a>,
+        left: &'a CSTNode<'a>,
+        right: &'a CSTNode<'a>,
+        merged: MergedCSTNode<'a>,
+    ) -> MergedCSTNode<'a> {
+        merge::detect_protobuf_field_number_conflicts(base, left, right, merged)
+    }
+}
@@ -0,0 +1,83 @@
+use matching::matching_configuration::MatchingConfiguration;
+use matching_handlers::get_default_scala_matching_handlers;
+use merge::PrinterConfiguration;
+use parsing::ParserConfiguration;
+use parsing_handlers::get_default_scala_parsing_handlers;
+
+use crate::LanguageSupport;
+
+pub struct Scala {
+    parser: ParserConfiguration,
+    matching: MatchingConfiguration<'static>,
+    printer: PrinterConfiguration,
+}
+
+impl Default for Scala {
+    fn default() -> Self {
+        let parser = ParserConfiguration::new(
+            parsing::get_builtin_grammar("scala").expect("Scala is a built-in grammar"),
+        )
+        .with_kinds_with_unordered_children(&["template_body"])
+        .with_block_end_delimiters(&["}"])
+        .with_handlers(get_default_scala_parsing_handlers());
+
+        let matching = MatchingConfiguration::new()
+            .with_delimiters(&["{", "}", ";"])
+            .with_kinds_with_label(&[
+                "import_declaration",
+                "function_definition",
+                "function_declaration",
+                "val_definition",
+                "val_declaration",
+                "var_definition",
+                "var_declaration",
+                "class_definition",
+                "object_definition",
+                "trait_definition",
+            ])
+            .with_handlers(get_default_scala_matching_handlers());
+
+        let printer = PrinterConfiguration {
+            kinds_with_line_separated_children: [
+                "compilation_unit",
+                "import_declarations",
+                "template_body",
+                "block",
+            ]
+            .into(),
+            ..Default::default()
+        };
+
+        Scala {
+            parser,
+            matching,
+            printer,
+        }
+    }
+}
+
+impl LanguageSupport for Scala {
+    fn name(&self) -> &str {
+        "scala"
+    }
+
+    fn extensions(&self) -> Vec<&str> {
+        vec!["scala", "sc"]
+    }
+
+    fn parser_configuration(&self) -> &ParserConfiguration {
+        &self.parser
+    }
+
+    fn parser_configuration_mut(&mut self) -> Option<&mut ParserConfiguration> {
+        Some(&mut self.parser)
+    }
+
+    fn matching_configuration(&self) -> &MatchingConfiguration<'static> {
+        &self.matching
+    }
+
+    fn printer_configuration(&self) -> &PrinterConfiguration {
+        &self.printer
+    }
+}
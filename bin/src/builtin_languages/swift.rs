@@ -0,0 +1,96 @@
+use matching::matching_configuration::MatchingConfiguration;
+use matching_handlers::get_default_swift_matching_handlers;
+use merge::PrinterConfiguration;
+use parsing::ParserConfiguration;
+use parsing_handlers::get_default_swift_parsing_handlers;
+
+use crate::LanguageSupport;
+
+pub struct Swift {
+    parser: ParserConfiguration,
+    matching: MatchingConfiguration<'static>,
+    printer: PrinterConfiguration,
+}
+
+impl Default for Swift {
+    fn default() -> Self {
+        let parser = ParserConfiguration::new(
+            parsing::get_builtin_grammar("swift").expect("Swift is a built-in grammar"),
+        )
+        // Swift is sensitive to whitespace around some operators and inside strings, so these
+        // are kept as they were written
+        .with_stop_compilation_at(&[
+            "line_string_literal",
+            "multi_line_string_literal",
+            "raw_string_literal",
+            "optional_type",
+            "postfix_expression",
+            "prefix_expression",
+            "navigation_expression",
+        ])
+        .with_kinds_with_unordered_children(&["class_body", "protocol_body"])
+        .with_block_end_delimiters(&["}"])
+        .with_handlers(get_default_swift_parsing_handlers());
+
+        let matching = MatchingConfiguration::new()
+            .with_delimiters(&["{", "}"])
+            .with_kinds_with_label(&[
+                "import_declaration",
+                "function_declaration",
+                "protocol_function_declaration",
+                "init_declaration",
+                "subscript_declaration",
+                "property_declaration",
+                "protocol_property_declaration",
+                "class_declaration",
+                "protocol_declaration",
+                "typealias_declaration",
+            ])
+            .with_handlers(get_default_swift_matching_handlers());
+
+        let printer = PrinterConfiguration {
+            kinds_with_line_separated_children: [
+                "source_file",
+                "import_declarations",
+                "class_body",
+                "protocol_body",
+                "enum_class_body",
+                "statements",
+            ]
+            .into(),
+            ..Default::default()
+        };
+
+        Swift {
+            parser,
+            matching,
+            printer,
+        }
+    }
+}
+
+impl LanguageSupport for Swift {
+    fn name(&self) -> &str {
+        "swift"
+    }
+
+    fn extensions(&self) -> Vec<&str> {
+        vec!["swift"]
+    }
+
+    fn parser_configuration(&self) -> &ParserConfiguration {
+        &self.parser
+    }
+
+    fn parser_configuration_mut(&mut self) -> Option<&mut ParserConfiguration> {
+        Some(&mut self.parser)
+    }
+
+    fn matching_configuration(&self) -> &MatchingConfiguration<'static> {
+        &self.matching
+    }
+
+    fn printer_configuration(&self) -> &PrinterConfiguration {
+        &self.printer
+    }
+}
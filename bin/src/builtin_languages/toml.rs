@@ -0,0 +1,80 @@
+use matching::matching_configuration::MatchingConfiguration;
+use matching_handlers::{get_default_toml_matching_handlers, get_toml_matching_handlers};
+use merge::PrinterConfiguration;
+use parsing::ParserConfiguration;
+use parsing_handlers::get_default_toml_parsing_handlers;
+
+use crate::LanguageSupport;
+
+pub struct Toml {
+    parser: ParserConfiguration,
+    matching: MatchingConfiguration<'static>,
+    printer: PrinterConfiguration,
+}
+
+impl Default for Toml {
+    fn default() -> Self {
+        let parser = ParserConfiguration::new(
+            parsing::get_builtin_grammar("toml").expect("TOML is a built-in grammar"),
+        )
+        // The grammar does not expose the content of strings and quoted keys as nodes
+        .with_stop_compilation_at(&["string", "quoted_key"])
+        .with_kinds_with_unordered_children(&["document"])
+        .with_handlers(get_default_toml_parsing_handlers());
+
+        let matching = MatchingConfiguration::new()
+            .with_delimiters(&["comment"])
+            .with_kinds_with_label(&["pairs", "pair", "table", "table_array_element"])
+            .with_handlers(get_default_toml_matching_handlers());
+
+        let printer = PrinterConfiguration {
+            kinds_with_line_separated_children: ["document", "pairs"].into(),
+            ..Default::default()
+        };
+
+        Toml {
+            parser,
+            matching,
+            printer,
+        }
+    }
+}
+
+impl Toml {
+    /// Keys whose values identify the entries of arrays of tables, instead of `name`, e.g. `id`
+    /// for `[[plugin]]` entries.
+    pub fn with_table_array_identity_keys(self, keys: &[&str]) -> Self {
+        Toml {
+            matching: self
+                .matching
+                .with_handlers(get_toml_matching_handlers(keys)),
+            ..self
+        }
+    }
+}
+
+impl LanguageSupport for Toml {
+    fn name(&self) -> &str {
+        "toml"
+    }
+
+    fn extensions(&self) -> Vec<&str> {
+        vec!["toml"]
+    }
+
+    fn parser_configuration(&self) -> &ParserConfiguration {
+        &self.parser
+    }
+
+    fn parser_configuration_mut(&mut self) -> Option<&mut ParserConfiguration> {
+        Some(&mut self.parser)
+    }
+
+    fn matching_configuration(&self) -> &MatchingConfiguration<'static> {
+        &self.matching
+    }
+
+    fn printer_configuration(&self) -> &PrinterConfiguration {
+        &self.printer
+    }
+}
@@ -1,5 +1,7 @@
 use clap::{Parser, Subcommand};
 
+use crate::control::SyntaxErrorPolicy;
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 pub struct CliArgs {
@@ -9,6 +11,99 @@ pub struct CliArgs {
     /// The minimum log level to be displayed in output
     #[arg(long, global=true, default_value_t = log::LevelFilter::Info)]
     pub log_level: log::LevelFilter,
+
+    /// Path to a declarative language definition (TOML, or JSON with a .json extension).
+    /// May be repeated, and takes precedence over the built-in languages.
+    #[arg(long, global = true)]
+    pub language_config: Vec<std::path::PathBuf>,
+
+    /// Maps files matching a glob pattern to a language, e.g. `*.java.template=java`.
+    /// May be repeated, and takes precedence over the extensions of the languages.
+    #[arg(long, global = true, value_parser = parse_language_mapping)]
+    pub language_mapping: Vec<(String, String)>,
+
+    /// Skips files whose language can not be detected instead of failing,
+    /// which is useful when running the tool on many files
+    #[arg(long, global = true)]
+    pub skip_unknown_languages: bool,
+
+    /// What to do when one of the files has a syntax error
+    #[arg(long, global = true, value_enum, default_value_t = SyntaxErrorPolicy::Textual)]
+    pub on_syntax_error: SyntaxErrorPolicy,
+
+    /// Node kinds kept as opaque terminals and merged line by line, e.g. `block` for the bodies
+    /// of Java methods. May be repeated or separated by commas.
+    #[arg(long, global = true, value_delimiter = ',')]
+    pub stop_compilation_at: Vec<String>,
+
+    /// Parsing handlers to skip, e.g. `remove_block_comments` to keep comments in the tree.
+    /// May be repeated or separated by commas.
+    #[arg(long, global = true, value_delimiter = ',')]
+    pub disable_handler: Vec<String>,
+
+    /// Sets an option of a parsing handler, in the format HANDLER.KEY=VALUE, e.g.
+    /// `remove_block_comments.kinds=block_comment,line_comment`. May be repeated.
+    #[arg(long, global = true, value_parser = parse_handler_option)]
+    pub handler_option: Vec<(String, String, String)>,
+
+    /// Always sorts merged Java imports, instead of only when their merged order contradicts
+    /// either revision. Implied by --static-imports-last and --import-groups
+    #[arg(long, global = true)]
+    pub sort_imports: bool,
+
+    /// Places static imports after the other imports. Merged imports are then always sorted,
+    /// instead of only when their merged order contradicts either revision
+    #[arg(long, global = true)]
+    pub static_imports_last: bool,
+
+    /// Top-level packages whose imports come first, e.g. `java,javax`. Merged imports are then
+    /// always sorted, instead of only when their merged order contradicts either revision.
+    /// May be repeated or separated by commas.
+    #[arg(long, global = true, value_delimiter = ',')]
+    pub import_groups: Vec<String>,
+
+    /// Removes imports left unused or duplicated by the merge. Imports already unused in the
+    /// base revision are kept
+    #[arg(long, global = true)]
+    pub remove_unused_imports: bool,
+
+    /// Keys whose values identify the entries of TOML arrays of tables, e.g. `id` for
+    /// `[[plugin]]` entries. Defaults to `name`. May be repeated or separated by commas.
+    #[arg(long, global = true, value_delimiter = ',')]
+    pub toml_table_array_keys: Vec<String>,
+
+    /// Directory where parsed trees are cached, so that files already parsed by previous runs,
+    /// e.g. a base revision shared by many merges, are not parsed again
+    #[arg(long, global = true)]
+    pub parse_cache: Option<std::path::PathBuf>,
+}
+
+fn parse_language_mapping(mapping: &str) -> Result<(String, String), String> {
+    match mapping.split_once('=') {
+        Some((pattern, language)) if !pattern.is_empty() && !language.is_empty() => {
+            Ok((pattern.to_string(), language.to_string()))
+        }
+        _ => Err(format!(
+            "Expected a mapping in the format PATTERN=LANGUAGE, got \"{}\"",
+            mapping
+        )),
+    }
+}
+
+fn parse_handler_option(option: &str) -> Result<(String, String, String), String> {
+    let parsed = option.split_once('=').and_then(|(name, value)| {
+        let (handler, key) = name.split_once('.')?;
+        Some((handler, key, value))
+    });
+    match parsed {
+        Some((handler, key, value)) if !handler.is_empty() && !key.is_empty() => {
+            Ok((handler.to_string(), key.to_string(), value.to_string()))
+        }
+        _ => Err(format!(
+            "Expected an option in the format HANDLER.KEY=VALUE, got \"{}\"",
+            option
+        )),
+    }
 }
 
 #[derive(Subcommand, Debug)]
@@ -30,14 +125,15 @@ pub struct DiffCliArgs {
     pub(crate) right_path: std::path::PathBuf,
 
     /// The language that the files being diffed are written in.
-    /// If not provided the language will try to be inferred by the extension.
+    /// If not provided the language will try to be inferred by the paths and contents of the files.
     #[arg(long)]
     pub(crate) language: Option<String>,
 }
 
 #[derive(Parser, Debug)]
 pub struct MergeCliArgs {
-    /// Path to file in base revision
+    /// Path to file in base revision.
+    /// If not provided both revisions are merged as if they had added the file.
     #[arg(short, long)]
     pub(crate) base_path: Option<std::path::PathBuf>,
 
@@ -54,7 +150,7 @@ pub struct MergeCliArgs {
     pub(crate) merge_path: Option<std::path::PathBuf>,
 
     /// The language that the files being diffed are written in.
-    /// If not provided the language will try to be inferred by the extension.
+    /// If not provided the language will try to be inferred by the paths and contents of the files.
     #[arg(long)]
     pub(crate) language: Option<String>,
 }
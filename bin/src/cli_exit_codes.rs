@@ -4,7 +4,10 @@ pub const SUCCESS_WITH_CONFLICTS: i32 = 1;
 pub const SUCCESS_FILES_FULLY_MATCH: i32 = 0;
 pub const SUCCESS_FILES_DO_NOT_FULLY_MATCH: i32 = 1;
 
+pub const SKIPPED_UNKNOWN_LANGUAGE: i32 = 2;
+
 pub const READING_FILE_ERROR: i32 = 129;
 pub const INVALID_LANGUAGE_ERROR: i32 = 130;
 pub const WRITING_FILE_ERROR: i32 = 131;
 pub const INTERNAL_EXECUTION_ERROR: i32 = 132;
+pub const SYNTAX_ERROR: i32 = 133;
@@ -0,0 +1,182 @@
+/// Number of lines at the start and at the end of a file where editors look for modelines.
+const MODELINE_SEARCH_LINES: usize = 5;
+
+/// Returns the interpreter named by the shebang line of a script, without its version suffix,
+/// e.g. `php` for `#!/usr/bin/env php8.2`.
+pub(crate) fn get_shebang_interpreter(contents: &str) -> Option<String> {
+    let shebang = contents.lines().next()?.strip_prefix("#!")?;
+    let mut arguments = shebang.split_whitespace();
+    let program = arguments.next()?.rsplit('/').next()?;
+
+    let interpreter = match program {
+        "env" => {
+            arguments.find(|argument| !argument.starts_with('-') && !argument.contains('='))?
+        }
+        program => program,
+    };
+
+    let name = interpreter.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+    (!name.is_empty()).then(|| name.to_lowercase())
+}
+
+/// Returns the language set by a vim (`vim: set ft=java:`) or emacs (`-*- mode: java -*-`)
+/// modeline in the first or last lines of a file.
+pub(crate) fn get_modeline_language(contents: &str) -> Option<String> {
+    let lines: Vec<&str> = contents.lines().collect();
+    let tail_start = lines
+        .len()
+        .saturating_sub(MODELINE_SEARCH_LINES)
+        .max(MODELINE_SEARCH_LINES.min(lines.len()));
+
+    lines[..MODELINE_SEARCH_LINES.min(lines.len())]
+        .iter()
+        .chain(&lines[tail_start..])
+        .find_map(|line| {
+            get_vim_modeline_language(line).or_else(|| get_emacs_modeline_language(line))
+        })
+}
+
+fn get_vim_modeline_language(line: &str) -> Option<String> {
+    let start = ["vim:", "vi:", "ex:"]
+        .iter()
+        .filter_map(|marker| line.find(marker).map(|index| index + marker.len()))
+        .min()?;
+
+    line[start..]
+        .split(|c: char| c.is_whitespace() || c == ':')
+        .find_map(|option| {
+            option
+                .strip_prefix("ft=")
+                .or_else(|| option.strip_prefix("filetype="))
+                .or_else(|| option.strip_prefix("syntax="))
+        })
+        .filter(|language| !language.is_empty())
+        .map(str::to_lowercase)
+}
+
+fn get_emacs_modeline_language(line: &str) -> Option<String> {
+    let start = line.find("-*-")? + 3;
+    let end = start + line[start..].find("-*-")?;
+    let variables = line[start..end].trim();
+
+    if !variables.contains(':') {
+        return (!variables.is_empty()).then(|| variables.to_lowercase());
+    }
+
+    variables.split(';').find_map(|variable| {
+        let (key, value) = variable.split_once(':')?;
+        (key.trim().eq_ignore_ascii_case("mode")).then(|| value.trim().to_lowercase())
+    })
+}
+
+/// Matches a glob pattern supporting `*` and `?` against a file path. Patterns without a `/`
+/// are matched against the file name only.
+pub(crate) fn matches_file_pattern(pattern: &str, file_path: &std::path::Path) -> bool {
+    let text = match pattern.contains('/') {
+        true => file_path.to_string_lossy(),
+        false => match file_path.file_name() {
+            Some(file_name) => file_name.to_string_lossy(),
+            None => return false,
+        },
+    };
+
+    matches_glob(pattern.as_bytes(), text.as_bytes())
+}
+
+fn matches_glob(pattern: &[u8], text: &[u8]) -> bool {
+    match (pattern.first(), text.first()) {
+        (None, None) => true,
+        (Some(b'*'), _) => {
+            matches_glob(&pattern[1..], text)
+                || (!text.is_empty() && matches_glob(pattern, &text[1..]))
+        }
+        (Some(b'?'), Some(_)) => matches_glob(&pattern[1..], &text[1..]),
+        (Some(expected), Some(actual)) if expected == actual => {
+            matches_glob(&pattern[1..], &text[1..])
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{get_modeline_language, get_shebang_interpreter, matches_file_pattern};
+
+    #[test]
+    fn it_reads_the_interpreter_of_shebang_lines() {
+        assert_eq!(
+            Some("php".to_string()),
+            get_shebang_interpreter("#!/usr/bin/php\n<?php echo 1;")
+        );
+        assert_eq!(
+            Some("swift".to_string()),
+            get_shebang_interpreter("#!/usr/bin/env swift\nprint(1)")
+        );
+        assert_eq!(
+            Some("php".to_string()),
+            get_shebang_interpreter("#!/usr/bin/env -S php8.2 -d display_errors=1\n")
+        );
+        assert_eq!(None, get_shebang_interpreter("package a;\n#!/usr/bin/php"));
+    }
+
+    #[test]
+    fn it_reads_vim_modelines() {
+        assert_eq!(
+            Some("java".to_string()),
+            get_modeline_language("// vim: set ft=java:\nclass A {}")
+        );
+        assert_eq!(
+            Some("scala".to_string()),
+            get_modeline_language("object A\n// vim: filetype=scala")
+        );
+    }
+
+    #[test]
+    fn it_reads_emacs_modelines() {
+        assert_eq!(
+            Some("java".to_string()),
+            get_modeline_language("// -*- mode: Java; indent-tabs-mode: nil -*-\nclass A {}")
+        );
+        assert_eq!(
+            Some("toml".to_string()),
+            get_modeline_language("# -*- toml -*-\n[a]")
+        );
+    }
+
+    #[test]
+    fn modelines_in_the_middle_of_long_files_are_ignored() {
+        let contents = format!("{}// vim: ft=java\n{}", "a\n".repeat(10), "b\n".repeat(10));
+        assert_eq!(None, get_modeline_language(&contents));
+    }
+
+    #[test]
+    fn file_patterns_match_file_names() {
+        assert!(matches_file_pattern("*.jav", Path::new("src/Example.jav")));
+        assert!(matches_file_pattern(
+            "*.java.template",
+            Path::new("/templates/Service.java.template")
+        ));
+        assert!(matches_file_pattern(
+            "Jenkinsfile?",
+            Path::new("ci/Jenkinsfile2")
+        ));
+        assert!(!matches_file_pattern(
+            "*.jav",
+            Path::new("src/Example.java")
+        ));
+    }
+
+    #[test]
+    fn file_patterns_with_directories_match_whole_paths() {
+        assert!(matches_file_pattern(
+            "*/templates/*.tpl",
+            Path::new("src/templates/Service.tpl")
+        ));
+        assert!(!matches_file_pattern(
+            "*/templates/*.tpl",
+            Path::new("Service.tpl")
+        ));
+    }
+}
@@ -3,19 +3,66 @@ use std::{
     fmt::{self, Display},
 };
 
-use matching::{matching_configuration, MatchingEntry};
-use parsing::ParserConfiguration;
+use matching::MatchingEntry;
+use merge::{ImportOrdering, RevisionSources};
+use model::{CSTNode, OwnedCSTNode, StructuralHashes};
+use parsing::{ParseCache, ParserConfiguration, ParsingError, ParsingOptions};
+
+use crate::{
+    injections::{get_printer_configuration_with_injections, match_injected_regions},
+    LanguageRegistry, LanguageSupport,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Revision {
+    Base,
+    Left,
+    Right,
+}
+
+impl Revision {
+    /// Tells apart the ids of the nodes of each revision, so that they are the same on every run.
+    fn tag(self) -> u32 {
+        match self {
+            Revision::Base => 0,
+            Revision::Left => 1,
+            Revision::Right => 2,
+        }
+    }
+
+    fn parsing_options<'r, 'a>(self, options: &ParsingOptions<'r, 'a>) -> ParsingOptions<'r, 'a> {
+        ParsingOptions {
+            revision_tag: Some(self.tag()),
+            ..*options
+        }
+    }
+}
+
+impl Display for Revision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Revision::Base => write!(f, "base"),
+            Revision::Left => write!(f, "left"),
+            Revision::Right => write!(f, "right"),
+        }
+    }
+}
 
 #[derive(Debug)]
 pub enum ExecutionError {
-    ParsingError(&'static str),
+    ParsingError {
+        revision: Revision,
+        error: ParsingError,
+    },
     MergeError(merge::MergeError),
 }
 
 impl fmt::Display for ExecutionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            ExecutionError::ParsingError(error) => write!(f, "Parsing error occurred: {}", error),
+            ExecutionError::ParsingError { revision, error } => {
+                write!(f, "Parsing error occurred in {} file: {}", revision, error)
+            }
             ExecutionError::MergeError(error) => write!(f, "Merge error occurred: {}", error),
         }
     }
@@ -23,10 +70,40 @@ impl fmt::Display for ExecutionError {
 
 impl Error for ExecutionError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        None
+        match self {
+            ExecutionError::ParsingError { error, .. } => Some(error),
+            ExecutionError::MergeError(_) => None,
+        }
     }
 }
 
+/// What to do when one of the files has a syntax error.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum SyntaxErrorPolicy {
+    /// Fail, reporting the position of the error
+    Fail,
+    /// Merge anyway, keeping the nodes tree-sitter recovered from the error with
+    Merge,
+    /// Merge the files line by line instead
+    #[default]
+    Textual,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ExecutionConfiguration {
+    pub syntax_error_policy: SyntaxErrorPolicy,
+    /// Kinds kept as terminals and merged line by line, in addition to the ones of the language.
+    pub stop_compilation_at: Vec<String>,
+    /// Parsing handlers skipped for every language, e.g. to keep comments.
+    pub disabled_handlers: Vec<String>,
+    pub import_ordering: ImportOrdering,
+    /// Drops the imports the merge left unused or duplicated.
+    pub remove_unused_imports: bool,
+    /// Where parsed trees are stored and loaded from, so that files parsed by previous runs, e.g.
+    /// a base revision shared by many merges, are not parsed again.
+    pub parse_cache: Option<ParseCache>,
+}
+
 #[derive(Debug)]
 pub enum ExecutionResult {
     WithConflicts(String),
@@ -42,49 +119,221 @@ impl Display for ExecutionResult {
     }
 }
 
+/// A part of the files that could not be merged structurally and was merged line by line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextualFallback {
+    /// Kind of the node merged line by line, or `None` if it was the whole file.
+    pub kind: Option<String>,
+    pub reason: String,
+}
+
+impl Display for TextualFallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            Some(kind) => write!(f, "Merged {} line by line: {}", kind, self.reason),
+            None => write!(f, "Merged the whole file line by line: {}", self.reason),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ExecutionReport {
+    pub result: ExecutionResult,
+    pub textual_fallbacks: Vec<TextualFallback>,
+}
+
+impl From<ExecutionResult> for ExecutionReport {
+    fn from(result: ExecutionResult) -> Self {
+        ExecutionReport {
+            result,
+            textual_fallbacks: vec![],
+        }
+    }
+}
+
+impl Display for ExecutionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.result)
+    }
+}
+
+/// Regions of the files written in other languages are parsed and matched with the
+/// configuration of their own language, looked up in the registry.
+///
+/// Files that can not be parsed or merged structurally are merged line by line instead, which
+/// is listed in the report along with the reason. Syntax errors are handled as configured.
 pub fn run_tool_on_merge_scenario(
-    language: model::Language,
+    registry: &LanguageRegistry,
+    language: &dyn LanguageSupport,
+    configuration: &ExecutionConfiguration,
     base: &str,
     left: &str,
     right: &str,
-) -> Result<ExecutionResult, ExecutionError> {
+) -> Result<ExecutionReport, ExecutionError> {
     if base == left {
-        return Ok(ExecutionResult::WithoutConflicts(right.to_string()));
+        return Ok(ExecutionResult::WithoutConflicts(right.to_string()).into());
     }
 
     if base == right {
-        return Ok(ExecutionResult::WithoutConflicts(left.to_string()));
+        return Ok(ExecutionResult::WithoutConflicts(left.to_string()).into());
     }
 
-    let parser_configuration = ParserConfiguration::from(language);
+    match run_structured_merge(registry, language, configuration, base, left, right) {
+        Err(error) if can_fall_back(&error, configuration) => {
+            log::warn!("{}, falling back to textual merge", error);
+            Ok(ExecutionReport {
+                result: run_textual_merge(base, left, right),
+                textual_fallbacks: vec![TextualFallback {
+                    kind: None,
+                    reason: error.to_string(),
+                }],
+            })
+        }
+        result => result,
+    }
+}
 
+/// Left and right are usually small edits of base, so they reuse the unchanged parts of its tree.
+fn parse_incrementally<'a>(
+    parser_configuration: &'a ParserConfiguration,
+    options: &ParsingOptions<'_, 'a>,
+    base: &'a str,
+    left: &'a str,
+    right: &'a str,
+) -> Result<(CSTNode<'a>, CSTNode<'a>, CSTNode<'a>), ExecutionError> {
     log::info!("Started parsing base file");
-    let base_tree =
-        parsing::parse_string(base, &parser_configuration).map_err(ExecutionError::ParsingError)?;
+    let base_file = parsing::parse_file(
+        base,
+        parser_configuration,
+        &Revision::Base.parsing_options(options),
+    )
+    .map_err(|error| ExecutionError::ParsingError {
+        revision: Revision::Base,
+        error,
+    })?;
     log::info!("Finished parsing base file");
+    let parse_edited_version = |src, revision: Revision| {
+        base_file
+            .parse_edited_version(src, &revision.parsing_options(options))
+            .map(|parsed_file| parsed_file.root)
+            .map_err(|error| ExecutionError::ParsingError { revision, error })
+    };
     log::info!("Started parsing left file");
-    let left_tree =
-        parsing::parse_string(left, &parser_configuration).map_err(ExecutionError::ParsingError)?;
+    let left_tree = parse_edited_version(left, Revision::Left)?;
     log::info!("Finished parsing left file");
     log::info!("Started parsing right file");
-    let right_tree = parsing::parse_string(right, &parser_configuration)
-        .map_err(ExecutionError::ParsingError)?;
+    let right_tree = parse_edited_version(right, Revision::Right)?;
     log::info!("Finished parsing right file");
+    Ok((base_file.root, left_tree, right_tree))
+}
 
-    let matching_configuration = matching_configuration::MatchingConfiguration::from(language);
+/// Hashes the subtrees of the revisions once the parsing handlers ran, so that identical subtrees
+/// are matched without comparing them.
+fn get_structural_hashes<'t, 'a: 't>(trees: &[&'t CSTNode<'a>]) -> StructuralHashes<'t> {
+    let mut hashes = StructuralHashes::default();
+    for tree in trees {
+        hashes.insert_tree(tree);
+    }
+    hashes
+}
+
+fn run_structured_merge(
+    registry: &LanguageRegistry,
+    language: &dyn LanguageSupport,
+    configuration: &ExecutionConfiguration,
+    base: &str,
+    left: &str,
+    right: &str,
+) -> Result<ExecutionReport, ExecutionError> {
+    let parser_configuration = language.parser_configuration();
+    let resolve_injected_language = |name: &str| {
+        registry
+            .get_by_name(name)
+            .ok()
+            .map(LanguageSupport::parser_configuration)
+    };
+    let options = ParsingOptions {
+        resolve_injected_language: &resolve_injected_language,
+        allow_syntax_errors: configuration.syntax_error_policy == SyntaxErrorPolicy::Merge,
+        stop_compilation_at: &configuration.stop_compilation_at,
+        disabled_handlers: &configuration.disabled_handlers,
+        revision_tag: None,
+    };
+
+    let cached_trees: Vec<OwnedCSTNode>;
+    let (base_tree, left_tree, right_tree) = match &configuration.parse_cache {
+        // Cached revisions are not parsed incrementally, but give the same trees
+        Some(cache) => {
+            cached_trees = [
+                (base, Revision::Base),
+                (left, Revision::Left),
+                (right, Revision::Right),
+            ]
+            .into_iter()
+            .map(|(src, revision)| {
+                log::info!("Started parsing {} file", revision);
+                let tree = cache
+                    .parse(
+                        language.name(),
+                        src,
+                        parser_configuration,
+                        &revision.parsing_options(&options),
+                    )
+                    .map_err(|error| ExecutionError::ParsingError { revision, error });
+                log::info!("Finished parsing {} file", revision);
+                tree
+            })
+            .collect::<Result<_, _>>()?;
+            (
+                cached_trees[0].as_cst_node(),
+                cached_trees[1].as_cst_node(),
+                cached_trees[2].as_cst_node(),
+            )
+        }
+        None => parse_incrementally(parser_configuration, &options, base, left, right)?,
+    };
+
+    // The revisions are hashed once, as each of them is matched against the other two
+    let hashes = get_structural_hashes(&[&base_tree, &left_tree, &right_tree]);
+
+    let matching_configuration = language.matching_configuration();
     log::info!("Started calculation of matchings between left and base");
-    let matchings_left_base =
-        matching::calculate_matchings(&left_tree, &base_tree, &matching_configuration);
+    let mut matchings_left_base = matching::calculate_matchings_with_hashes(
+        &left_tree,
+        &base_tree,
+        matching_configuration,
+        &hashes,
+    );
     log::info!("Finished calculation of matchings between left and base");
     log::info!("Started calculation of matchings between right and base");
-    let matchings_right_base =
-        matching::calculate_matchings(&right_tree, &base_tree, &matching_configuration);
+    let mut matchings_right_base = matching::calculate_matchings_with_hashes(
+        &right_tree,
+        &base_tree,
+        matching_configuration,
+        &hashes,
+    );
     log::info!("Finished calculation of matchings between right and base");
     log::info!("Started calculation of matchings between left and right");
-    let matchings_left_right =
-        matching::calculate_matchings(&left_tree, &right_tree, &matching_configuration);
+    let mut matchings_left_right = matching::calculate_matchings_with_hashes(
+        &left_tree,
+        &right_tree,
+        matching_configuration,
+        &hashes,
+    );
     log::info!("Finished calculation of matchings between left and right");
 
+    if parser_configuration.injections().is_some() {
+        log::info!("Started calculation of matchings of injected regions");
+        for (matchings, a, b) in [
+            (&mut matchings_left_base, &left_tree, &base_tree),
+            (&mut matchings_right_base, &right_tree, &base_tree),
+            (&mut matchings_left_right, &left_tree, &right_tree),
+        ] {
+            match_injected_regions(matchings, a, b, registry, &hashes);
+        }
+        log::info!("Finished calculation of matchings of injected regions");
+    }
+
     log::info!("Starting merge of the trees");
     let result = merge::merge(
         &base_tree,
@@ -93,40 +342,154 @@ pub fn run_tool_on_merge_scenario(
         &matchings_left_base,
         &matchings_right_base,
         &matchings_left_right,
+        &RevisionSources { base, left, right },
     )
     .map_err(ExecutionError::MergeError)?;
     log::info!("Finished merge of the trees");
 
-    match result.has_conflict() {
-        true => Ok(ExecutionResult::WithConflicts(result.to_string())),
-        false => Ok(ExecutionResult::WithoutConflicts(result.to_string())),
-    }
+    let result = language.detect_semantic_conflicts(&base_tree, &left_tree, &right_tree, result);
+    let result = language.normalize_imports(
+        &configuration.import_ordering,
+        &left_tree,
+        &right_tree,
+        result,
+    );
+    let result = match configuration.remove_unused_imports {
+        true => language.remove_unused_imports(&base_tree, result),
+        false => result,
+    };
+
+    let printer_configuration = get_printer_configuration(
+        registry,
+        parser_configuration,
+        language,
+        &[&left_tree, &right_tree],
+    );
+    let textual_fallbacks = result
+        .get_textual_fallbacks()
+        .into_iter()
+        .map(|(kind, reason)| TextualFallback {
+            kind: Some(kind.to_string()),
+            reason: reason.to_string(),
+        })
+        .collect();
+    let result = match result.has_conflict() {
+        true => ExecutionResult::WithConflicts(result.print(&printer_configuration)),
+        false => ExecutionResult::WithoutConflicts(result.print(&printer_configuration)),
+    };
+    Ok(ExecutionReport {
+        result,
+        textual_fallbacks,
+    })
 }
 
 pub fn run_diff_on_files(
-    language: model::Language,
+    registry: &LanguageRegistry,
+    language: &dyn LanguageSupport,
+    configuration: &ExecutionConfiguration,
     left: &str,
     right: &str,
 ) -> Result<MatchingEntry, ExecutionError> {
-    let parser_configuration = ParserConfiguration::from(language);
+    let parser_configuration = language.parser_configuration();
+    let resolve_injected_language = |name: &str| {
+        registry
+            .get_by_name(name)
+            .ok()
+            .map(LanguageSupport::parser_configuration)
+    };
+    let options = ParsingOptions {
+        resolve_injected_language: &resolve_injected_language,
+        allow_syntax_errors: configuration.syntax_error_policy == SyntaxErrorPolicy::Merge,
+        stop_compilation_at: &configuration.stop_compilation_at,
+        disabled_handlers: &configuration.disabled_handlers,
+        revision_tag: None,
+    };
+    let parse = |src, revision: Revision| {
+        parsing::parse_string_with_options(
+            src,
+            parser_configuration,
+            &revision.parsing_options(&options),
+        )
+        .map_err(|error| ExecutionError::ParsingError { revision, error })
+    };
 
     log::info!("Started parsing left file");
-    let left_tree_root =
-        parsing::parse_string(left, &parser_configuration).map_err(ExecutionError::ParsingError)?;
+    let left_tree_root = parse(left, Revision::Left);
     log::info!("Finished parsing left file");
     log::info!("Started parsing right file");
-    let right_tree_root = parsing::parse_string(right, &parser_configuration)
-        .map_err(ExecutionError::ParsingError)?;
+    let right_tree_root = parse(right, Revision::Right);
     log::info!("Finished parsing right file");
 
-    let matching_configuration = matching_configuration::MatchingConfiguration::from(language);
+    let (left_tree_root, right_tree_root) = match (left_tree_root, right_tree_root) {
+        (Ok(left_tree_root), Ok(right_tree_root)) => (left_tree_root, right_tree_root),
+        (Err(error), _) | (_, Err(error)) if can_fall_back(&error, configuration) => {
+            log::warn!("{}, falling back to textual comparison", error);
+            return Ok(MatchingEntry {
+                score: 0,
+                is_perfect_match: left == right,
+            });
+        }
+        (Err(error), _) | (_, Err(error)) => return Err(error),
+    };
+
+    let hashes = get_structural_hashes(&[&left_tree_root, &right_tree_root]);
+
+    let matching_configuration = language.matching_configuration();
     log::info!("Started calculation of matchings between left and right");
-    let matchings_left_right =
-        matching::calculate_matchings(&left_tree_root, &right_tree_root, &matching_configuration);
+    let mut matchings_left_right = matching::calculate_matchings_with_hashes(
+        &left_tree_root,
+        &right_tree_root,
+        matching_configuration,
+        &hashes,
+    );
     log::info!("Finished calculation of matchings between left and right");
 
+    if parser_configuration.injections().is_some() {
+        match_injected_regions(
+            &mut matchings_left_right,
+            &left_tree_root,
+            &right_tree_root,
+            registry,
+            &hashes,
+        );
+    }
+
     Ok(matchings_left_right
         .get_matching_entry(&left_tree_root, &right_tree_root)
         .unwrap_or_default()
         .to_owned())
 }
+
+fn can_fall_back(error: &ExecutionError, configuration: &ExecutionConfiguration) -> bool {
+    match error {
+        ExecutionError::ParsingError {
+            error: ParsingError::SyntaxError(_),
+            ..
+        } => configuration.syntax_error_policy == SyntaxErrorPolicy::Textual,
+        _ => true,
+    }
+}
+
+/// Merges the files line by line, as git does.
+fn run_textual_merge(base: &str, left: &str, right: &str) -> ExecutionResult {
+    match diffy::merge(base, left, right) {
+        Ok(result) => ExecutionResult::WithoutConflicts(result),
+        Err(result) => ExecutionResult::WithConflicts(result),
+    }
+}
+
+fn get_printer_configuration(
+    registry: &LanguageRegistry,
+    parser_configuration: &ParserConfiguration,
+    language: &dyn LanguageSupport,
+    trees: &[&model::CSTNode],
+) -> merge::PrinterConfiguration {
+    match parser_configuration.injections() {
+        Some(_) => get_printer_configuration_with_injections(
+            language.printer_configuration(),
+            trees,
+            registry,
+        ),
+        None => language.printer_configuration().clone(),
+    }
+}
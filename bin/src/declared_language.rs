@@ -0,0 +1,175 @@
+use matching::matching_configuration::MatchingConfiguration;
+use matching_handlers::get_matching_handlers_from_label_rules;
+use merge::PrinterConfiguration;
+use model::LanguageDefinition;
+use parsing::ParserConfiguration;
+
+use crate::LanguageSupport;
+
+/// A language described by a definition loaded at runtime. The kinds its configurations refer
+/// to are the names owned by its grammar, so they live as long as its parser configuration.
+pub struct DeclaredLanguage {
+    definition: LanguageDefinition,
+    matching: MatchingConfiguration<'static>,
+    printer: PrinterConfiguration,
+    parser: ParserConfiguration,
+}
+
+impl TryFrom<LanguageDefinition> for DeclaredLanguage {
+    type Error = String;
+
+    fn try_from(definition: LanguageDefinition) -> Result<Self, Self::Error> {
+        let parser = ParserConfiguration::try_from(&definition)?;
+
+        let delimiters = parser.get_grammar_kinds(&definition.delimiters)?;
+        let label_rules = definition
+            .labels
+            .iter()
+            .map(|rule| Ok((parser.get_grammar_kind(&rule.kind)?, rule.clone())))
+            .collect::<Result<Vec<_>, String>>()?;
+        let kinds_with_label: Vec<_> = label_rules.iter().map(|(kind, _)| *kind).collect();
+        let matching = MatchingConfiguration::new()
+            .with_delimiters(&delimiters)
+            .with_kinds_with_label(&kinds_with_label)
+            .with_handlers(get_matching_handlers_from_label_rules(label_rules));
+        let matching = match parser.label_queries() {
+            Some(label_queries) => matching.with_identities(label_queries.labelled_kinds()),
+            None => matching,
+        };
+
+        let printer = PrinterConfiguration {
+            kinds_with_line_separated_children: parser
+                .get_grammar_kinds(&definition.kinds_with_line_separated_children)?
+                .into_iter()
+                .collect(),
+            kinds_on_separate_lines: parser
+                .get_grammar_kinds(&definition.kinds_on_separate_lines)?
+                .into_iter()
+                .collect(),
+            kinds_with_separated_children: definition
+                .kinds_with_separated_children
+                .iter()
+                .map(|(kind, separator)| {
+                    Ok((
+                        parser.get_grammar_kind(kind)?,
+                        parser.get_grammar_kind(separator)?,
+                    ))
+                })
+                .collect::<Result<_, String>>()?,
+            delimiters: delimiters.into_iter().collect(),
+        };
+
+        Ok(DeclaredLanguage {
+            definition,
+            matching,
+            printer,
+            parser,
+        })
+    }
+}
+
+impl LanguageSupport for DeclaredLanguage {
+    fn name(&self) -> &str {
+        &self.definition.name
+    }
+
+    fn extensions(&self) -> Vec<&str> {
+        self.definition
+            .extensions
+            .iter()
+            .map(String::as_str)
+            .collect()
+    }
+
+    fn parser_configuration(&self) -> &ParserConfiguration {
+        &self.parser
+    }
+
+    fn matching_configuration(&self) -> &MatchingConfiguration<'static> {
+        &self.matching
+    }
+
+    fn printer_configuration(&self) -> &PrinterConfiguration {
+        &self.printer
+    }
+}
+
+/// Reads a language definition written in TOML, or in JSON if the file has a `.json` extension.
+pub fn load_language_definition(path: &std::path::Path) -> Result<LanguageDefinition, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|error| format!("Could not read {}: {}", path.display(), error))?;
+
+    let mut definition: LanguageDefinition = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => serde_json::from_str(&contents).map_err(|error| error.to_string()),
+        _ => toml::from_str(&contents).map_err(|error| error.to_string()),
+    }
+    .map_err(|error| format!("Invalid language definition {}: {}", path.display(), error))?;
+
+    // Grammars and queries are looked up relative to the definition that references them
+    if let Some(definition_dir) = path.parent() {
+        definition.grammar_path = definition
+            .grammar_path
+            .map(|grammar_path| definition_dir.join(grammar_path));
+        definition.label_queries = definition
+            .label_queries
+            .map(|label_queries| definition_dir.join(label_queries));
+        definition.injections = definition
+            .injections
+            .map(|injections| definition_dir.join(injections));
+    }
+
+    Ok(definition)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::load_language_definition;
+
+    #[test]
+    fn it_loads_definitions_written_in_toml() {
+        let definition =
+            load_language_definition(std::path::Path::new("tests/languages/java.toml")).unwrap();
+
+        assert_eq!("java-declarative", definition.name);
+        assert_eq!("java", definition.grammar);
+        assert!(definition
+            .labels
+            .iter()
+            .any(|rule| rule.kind == "method_declaration"));
+    }
+
+    #[test]
+    fn it_loads_definitions_written_in_json() {
+        let definition =
+            load_language_definition(std::path::Path::new("tests/languages/java.json")).unwrap();
+
+        assert_eq!("java-declarative", definition.name);
+        assert_eq!(
+            vec!["class_body".to_string()],
+            definition.kinds_with_unordered_children
+        );
+    }
+
+    #[test]
+    fn grammar_paths_are_relative_to_the_definition() {
+        let dir = std::env::temp_dir().join("generic-merge-definition-with-grammar");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("dsl.toml");
+        std::fs::write(
+            &path,
+            "name = \"dsl\"\ngrammar = \"dsl\"\ngrammar_path = \"lib/dsl.so\"\n",
+        )
+        .unwrap();
+
+        let definition = load_language_definition(&path).unwrap();
+        assert_eq!(Some(dir.join("lib/dsl.so")), definition.grammar_path);
+    }
+
+    #[test]
+    fn it_rejects_definitions_with_unknown_fields() {
+        let path = std::env::temp_dir().join("generic-merge-invalid-definition.toml");
+        std::fs::write(&path, "name = \"x\"\nunordered = [\"class_body\"]\n").unwrap();
+
+        assert!(load_language_definition(&path).is_err());
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::{BTreeSet, HashSet};
+
+use matching::Matchings;
+use merge::PrinterConfiguration;
+use model::{cst_node::NonTerminal, CSTNode, StructuralHashes, VisitorAction};
+
+use crate::LanguageRegistry;
+
+/// Finds the roots of the regions written in other languages, along with their language.
+pub(crate) fn find_injected_regions<'a>(node: &'a CSTNode<'a>) -> Vec<(&'a CSTNode<'a>, &'a str)> {
+    match node {
+        CSTNode::NonTerminal(NonTerminal {
+            injected_language: Some(language),
+            children,
+            ..
+        }) => children
+            .iter()
+            .map(|root| (root, language.as_str()))
+            .collect(),
+        CSTNode::NonTerminal(non_terminal) => non_terminal
+            .children
+            .iter()
+            .flat_map(find_injected_regions)
+            .collect(),
+        CSTNode::Terminal(_) => vec![],
+    }
+}
+
+/// Regions written in other languages are first matched along with the rest of the file, with
+/// the configuration of its language. Once their roots are matched, the matchings inside them
+/// are replaced by the ones computed with the configuration of their own language.
+pub(crate) fn match_injected_regions<'a>(
+    matchings: &mut Matchings<'a>,
+    left: &'a CSTNode<'a>,
+    right: &'a CSTNode<'a>,
+    registry: &'a LanguageRegistry,
+    hashes: &StructuralHashes,
+) {
+    let right_regions = find_injected_regions(right);
+
+    for (left_region, language_name) in find_injected_regions(left) {
+        let Some(right_region) = matchings
+            .find_matching_for(left_region)
+            .map(|matching| matching.matching_node)
+            .and_then(|matching_node| {
+                right_regions
+                    .iter()
+                    .find(|(region, language)| {
+                        region.id() == matching_node.id() && *language == language_name
+                    })
+                    .map(|(region, _)| *region)
+            })
+        else {
+            continue;
+        };
+        let Ok(language) = registry.get_by_name(language_name) else {
+            continue;
+        };
+
+        let region_ids: HashSet<uuid::Uuid> = get_subtree_ids(left_region)
+            .into_iter()
+            .chain(get_subtree_ids(right_region))
+            .collect();
+        matchings.matching_entries.retain(|pair, _| {
+            !region_ids.contains(&pair.0.id()) && !region_ids.contains(&pair.1.id())
+        });
+        matchings.extend(matching::calculate_matchings_with_hashes(
+            left_region,
+            right_region,
+            language.matching_configuration(),
+            hashes,
+        ));
+    }
+}
+
+/// Injected regions are printed with the printer configuration of their own language as well.
+pub(crate) fn get_printer_configuration_with_injections(
+    printer_configuration: &PrinterConfiguration,
+    trees: &[&CSTNode],
+    registry: &LanguageRegistry,
+) -> PrinterConfiguration {
+    let mut result = printer_configuration.clone();
+
+    let languages: BTreeSet<&str> = trees
+        .iter()
+        .flat_map(|tree| find_injected_regions(tree))
+        .map(|(_, language)| language)
+        .collect();
+    for language in languages {
+        if let Ok(language) = registry.get_by_name(language) {
+            let injected_configuration = language.printer_configuration();
+            result.kinds_with_line_separated_children.extend(
+                injected_configuration
+                    .kinds_with_line_separated_children
+                    .iter(),
+            );
+            result
+                .kinds_on_separate_lines
+                .extend(injected_configuration.kinds_on_separate_lines.iter());
+            result
+                .kinds_with_separated_children
+                .extend(injected_configuration.kinds_with_separated_children.iter());
+            result
+                .delimiters
+                .extend(injected_configuration.delimiters.iter());
+        }
+    }
+
+    result
+}
+
+fn get_subtree_ids(node: &CSTNode) -> Vec<uuid::Uuid> {
+    let mut ids = vec![];
+    node.walk(&mut |node: &CSTNode| {
+        ids.push(node.id());
+        VisitorAction::Continue
+    });
+    ids
+}
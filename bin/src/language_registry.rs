@@ -0,0 +1,358 @@
+use std::path::Path;
+
+use crate::{
+    builtin_languages::{Css, Html, Java, JavaScript, Markdown, Php, Protobuf, Scala, Swift, Toml},
+    content_detection::{get_modeline_language, get_shebang_interpreter, matches_file_pattern},
+    LanguageSupport,
+};
+
+/// The languages the tool is able to merge. Languages registered later take precedence over
+/// the ones registered before them, so built-in languages may be overridden.
+pub struct LanguageRegistry {
+    languages: Vec<Box<dyn LanguageSupport>>,
+    file_patterns: Vec<(String, String)>,
+}
+
+impl Default for LanguageRegistry {
+    fn default() -> Self {
+        let mut registry = LanguageRegistry::empty();
+        registry.register(Css::default());
+        registry.register(Html::default());
+        registry.register(Java::default());
+        registry.register(JavaScript::default());
+        registry.register(Markdown::default());
+        registry.register(Php::default());
+        registry.register(Protobuf::default());
+        registry.register(Scala::default());
+        registry.register(Swift::default());
+        registry.register(Toml::default());
+        registry
+    }
+}
+
+impl LanguageRegistry {
+    /// Creates a registry without any of the built-in languages.
+    pub fn empty() -> Self {
+        LanguageRegistry {
+            languages: vec![],
+            file_patterns: vec![],
+        }
+    }
+
+    pub fn register(&mut self, language: impl LanguageSupport + 'static) {
+        self.languages.push(Box::new(language));
+    }
+
+    pub fn get_by_name(&self, name: &str) -> Result<&dyn LanguageSupport, String> {
+        self.find(|language| language.name() == name || language.aliases().contains(&name))
+            .ok_or(format!("Invalid language provided: {}", name))
+    }
+
+    /// Files matching the glob pattern, e.g. `*.java.template`, are considered to be written
+    /// in the given language, regardless of their extension. Patterns mapped later take
+    /// precedence.
+    pub fn map_file_pattern(&mut self, pattern: &str, language: &str) -> Result<(), String> {
+        self.get_by_name(language)?;
+        self.file_patterns
+            .push((pattern.to_string(), language.to_string()));
+        Ok(())
+    }
+
+    pub fn get_by_file_path(&self, file_path: &Path) -> Result<&dyn LanguageSupport, String> {
+        let mapped_language = self
+            .file_patterns
+            .iter()
+            .rev()
+            .find(|(pattern, _)| matches_file_pattern(pattern, file_path));
+        if let Some((_, language)) = mapped_language {
+            return self.get_by_name(language);
+        }
+
+        file_path
+            .extension()
+            .and_then(std::ffi::OsStr::to_str)
+            .and_then(|extension| self.find(|language| language.extensions().contains(&extension)))
+            .ok_or(format!(
+                "Could not retrieve parsing configuration for file {}",
+                file_path.display()
+            ))
+    }
+
+    /// Detects the language of a file by its shebang line or by an editor modeline.
+    pub fn get_by_contents(&self, contents: &str) -> Result<&dyn LanguageSupport, String> {
+        get_shebang_interpreter(contents)
+            .or_else(|| get_modeline_language(contents))
+            .and_then(|name| self.get_by_name(&name).ok())
+            .ok_or("Could not detect the language from the contents of the file".to_string())
+    }
+
+    /// Detects the language of the revisions of a file, trying all of their paths before
+    /// looking at their contents, as revisions may be missing or stored in temporary files.
+    pub fn detect<'a>(
+        &self,
+        revisions: impl IntoIterator<Item = (Option<&'a Path>, &'a str)>,
+    ) -> Result<&dyn LanguageSupport, String> {
+        let revisions: Vec<_> = revisions.into_iter().collect();
+
+        revisions
+            .iter()
+            .filter_map(|(path, _)| *path)
+            .find_map(|path| self.get_by_file_path(path).ok())
+            .or_else(|| {
+                revisions
+                    .iter()
+                    .find_map(|(_, contents)| self.get_by_contents(contents).ok())
+            })
+            .ok_or(format!(
+                "Could not detect the language of {}",
+                revisions
+                    .iter()
+                    .filter_map(|(path, _)| path.map(|path| path.display().to_string()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+    }
+
+    /// Names of the parsing handlers of every registered language.
+    pub fn handler_names(&self) -> Vec<&str> {
+        self.languages
+            .iter()
+            .flat_map(|language| language.parser_configuration().handlers().names())
+            .collect()
+    }
+
+    /// Sets an option of the parsing handler of the given name, for every language that has it.
+    pub fn set_handler_option(
+        &mut self,
+        handler: &str,
+        key: &str,
+        value: &str,
+    ) -> Result<(), String> {
+        let mut is_known = false;
+        for language in self.languages.iter_mut() {
+            let Some(parser_configuration) = language.parser_configuration_mut() else {
+                continue;
+            };
+            if parser_configuration.handlers().names().contains(&handler) {
+                parser_configuration
+                    .handlers_mut()
+                    .set_option(handler, key, value)?;
+                is_known = true;
+            }
+        }
+
+        match is_known {
+            true => Ok(()),
+            false => Err(format!("Unknown parsing handler {}", handler)),
+        }
+    }
+
+    fn find(
+        &self,
+        predicate: impl Fn(&dyn LanguageSupport) -> bool,
+    ) -> Option<&dyn LanguageSupport> {
+        self.languages
+            .iter()
+            .rev()
+            .map(Box::as_ref)
+            .find(|language| predicate(*language))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::LanguageDefinition;
+
+    use crate::{DeclaredLanguage, LanguageRegistry};
+
+    fn make_definition() -> LanguageDefinition {
+        LanguageDefinition {
+            name: "java".into(),
+            extensions: vec!["java".into(), "jav".into()],
+            grammar: "java".into(),
+            ..Default::default()
+        }
+    }
+
+    fn name_by_file_path(file_path: &str) -> Result<String, String> {
+        LanguageRegistry::default()
+            .get_by_file_path(&std::path::PathBuf::from(file_path))
+            .map(|language| language.name().to_string())
+    }
+
+    #[test]
+    fn languages_registered_later_take_precedence() {
+        let mut registry = LanguageRegistry::default();
+        registry.register(DeclaredLanguage::try_from(make_definition()).unwrap());
+
+        let by_name = registry.get_by_name("java").unwrap();
+        assert_eq!(vec!["java", "jav"], by_name.extensions());
+
+        let by_path = registry
+            .get_by_file_path(&std::path::PathBuf::from("/path/Example.jav"))
+            .unwrap();
+        assert_eq!("java", by_path.name());
+    }
+
+    #[test]
+    fn builtin_languages_are_used_if_no_registered_language_applies() {
+        let mut registry = LanguageRegistry::default();
+        registry.register(DeclaredLanguage::try_from(make_definition()).unwrap());
+
+        assert_eq!("php", registry.get_by_name("php").unwrap().name());
+        assert_eq!(
+            "scala",
+            registry
+                .get_by_file_path(&std::path::PathBuf::from("/path/Job.scala"))
+                .unwrap()
+                .name()
+        );
+    }
+
+    #[test]
+    fn languages_can_be_selected_by_their_aliases() {
+        let registry = LanguageRegistry::default();
+        assert_eq!("protobuf", registry.get_by_name("proto").unwrap().name());
+    }
+
+    #[test]
+    fn file_patterns_take_precedence_over_extensions() {
+        let mut registry = LanguageRegistry::default();
+        registry
+            .map_file_pattern("*.java.template", "java")
+            .unwrap();
+        registry.map_file_pattern("*.proto", "java").unwrap();
+
+        let path = std::path::PathBuf::from("/templates/Service.java.template");
+        assert_eq!("java", registry.get_by_file_path(&path).unwrap().name());
+        let path = std::path::PathBuf::from("/api/orders.proto");
+        assert_eq!("java", registry.get_by_file_path(&path).unwrap().name());
+    }
+
+    #[test]
+    fn file_patterns_can_not_be_mapped_to_unknown_languages() {
+        let mut registry = LanguageRegistry::default();
+        assert!(registry.map_file_pattern("*.cbl", "cobol").is_err());
+    }
+
+    #[test]
+    fn languages_are_detected_by_shebang_lines_and_modelines() {
+        let registry = LanguageRegistry::default();
+
+        let shebang = registry.get_by_contents("#!/usr/bin/env swift\nprint(1)\n");
+        assert_eq!("swift", shebang.unwrap().name());
+        let modeline = registry.get_by_contents("// vim: set ft=java:\nclass A {}\n");
+        assert_eq!("java", modeline.unwrap().name());
+        assert!(registry.get_by_contents("class A {}\n").is_err());
+    }
+
+    #[test]
+    fn detection_falls_back_to_other_revisions_and_then_to_contents() {
+        let registry = LanguageRegistry::default();
+        let base = std::path::PathBuf::from("/tmp/merge_base");
+        let left = std::path::PathBuf::from("/src/Job.scala");
+
+        let by_left_path = registry.detect([
+            (None, ""),
+            (Some(base.as_path()), ""),
+            (Some(left.as_path()), ""),
+        ]);
+        assert_eq!("scala", by_left_path.unwrap().name());
+
+        let by_contents = registry.detect([
+            (Some(base.as_path()), "class A {}"),
+            (None, "#!/usr/bin/php\n<?php echo 1;"),
+        ]);
+        assert_eq!("php", by_contents.unwrap().name());
+
+        assert!(registry.detect([(Some(base.as_path()), "")]).is_err());
+    }
+
+    #[test]
+    fn an_empty_registry_has_no_languages() {
+        let registry = LanguageRegistry::empty();
+        assert!(registry.get_by_name("java").is_err());
+    }
+
+    #[test]
+    fn if_the_language_name_is_unknown_it_returns_error() {
+        assert!(LanguageRegistry::default().get_by_name("cobol").is_err());
+    }
+
+    #[test]
+    fn if_the_file_extension_has_no_parser_available_it_returns_error() {
+        assert!(name_by_file_path("/path/without/extension").is_err())
+    }
+
+    #[test]
+    fn if_the_file_extension_has_a_parser_available_it_returns_a_parser_configuration() {
+        assert_eq!(
+            "java",
+            name_by_file_path("/path/for/java/file/Example.java").unwrap()
+        )
+    }
+
+    #[test]
+    fn php_files_are_detected_by_their_extension() {
+        assert_eq!(
+            "php",
+            name_by_file_path("/path/for/php/file/UserService.php").unwrap()
+        )
+    }
+
+    #[test]
+    fn scala_files_are_detected_by_their_extension() {
+        assert_eq!(
+            "scala",
+            name_by_file_path("/path/for/scala/file/Job.scala").unwrap()
+        )
+    }
+
+    #[test]
+    fn protobuf_files_are_detected_by_their_extension() {
+        assert_eq!(
+            "protobuf",
+            name_by_file_path("/path/for/api/orders.proto").unwrap()
+        )
+    }
+
+    #[test]
+    fn swift_files_are_detected_by_their_extension() {
+        assert_eq!(
+            "swift",
+            name_by_file_path("/path/for/ios/app/ViewController.swift").unwrap()
+        )
+    }
+
+    #[test]
+    fn html_files_are_detected_by_their_extension() {
+        assert_eq!(
+            "html",
+            name_by_file_path("/path/for/site/index.html").unwrap()
+        )
+    }
+
+    #[test]
+    fn javascript_files_are_detected_by_their_extension() {
+        assert_eq!(
+            "javascript",
+            name_by_file_path("/path/for/site/orders.mjs").unwrap()
+        )
+    }
+
+    #[test]
+    fn markdown_files_are_detected_by_their_extension() {
+        assert_eq!(
+            "markdown",
+            name_by_file_path("/path/for/docs/README.md").unwrap()
+        )
+    }
+
+    #[test]
+    fn toml_files_are_detected_by_their_extension() {
+        assert_eq!(
+            "toml",
+            name_by_file_path("/path/for/rust/project/Cargo.toml").unwrap()
+        )
+    }
+}
@@ -0,0 +1,68 @@
+use matching::matching_configuration::MatchingConfiguration;
+use merge::{ImportOrdering, MergedCSTNode, PrinterConfiguration};
+use model::CSTNode;
+use parsing::ParserConfiguration;
+
+/// Everything the tool needs to know about a language to merge its files: the grammar and
+/// parsing tweaks, how nodes are matched and how the merged tree is printed.
+///
+/// Languages are looked up in a [`crate::LanguageRegistry`], so crates using the tool as a
+/// library may support their own languages by registering an implementation of this trait.
+pub trait LanguageSupport {
+    /// Name used to select the language on the command line.
+    fn name(&self) -> &str;
+
+    /// Other names the language may be selected by.
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    /// Extensions, without the leading dot, of the files written in the language.
+    fn extensions(&self) -> Vec<&str>;
+
+    fn parser_configuration(&self) -> &ParserConfiguration;
+
+    /// Languages with parsing handlers expose their configuration, so that the options of the
+    /// handlers may be set, e.g. from the command line.
+    fn parser_configuration_mut(&mut self) -> Option<&mut ParserConfiguration> {
+        None
+    }
+
+    fn matching_configuration(&self) -> &MatchingConfiguration<'static>;
+
+    fn printer_configuration(&self) -> &PrinterConfiguration;
+
+    /// Reports conflicts that can only be noticed with knowledge of the language semantics on
+    /// the already merged tree.
+    fn detect_semantic_conflicts<'a>(
+        &self,
+        _base: &'a CSTNode<'a>,
+        _left: &'a CSTNode<'a>,
+        _right: &'a CSTNode<'a>,
+        merged: MergedCSTNode<'a>,
+    ) -> MergedCSTNode<'a> {
+        merged
+    }
+
+    /// Drops redundant imports from the already merged tree and sorts them, if an ordering other
+    /// than the default one is given or if their merged order contradicts either revision.
+    fn normalize_imports<'a>(
+        &self,
+        _ordering: &ImportOrdering,
+        _left: &'a CSTNode<'a>,
+        _right: &'a CSTNode<'a>,
+        merged: MergedCSTNode<'a>,
+    ) -> MergedCSTNode<'a> {
+        merged
+    }
+
+    /// Drops duplicate imports and the imports the merge left unused, as long as they were
+    /// added by a revision or used in base.
+    fn remove_unused_imports<'a>(
+        &self,
+        _base: &'a CSTNode<'a>,
+        merged: MergedCSTNode<'a>,
+    ) -> MergedCSTNode<'a> {
+        merged
+    }
+}
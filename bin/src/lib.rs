@@ -1,5 +1,18 @@
+mod builtin_languages;
 mod cli_exit_codes;
+mod content_detection;
 mod control;
+mod declared_language;
+mod injections;
+mod language_registry;
+mod language_support;
 
+pub use builtin_languages::{Html, Java, JavaScript, Markdown, Php, Protobuf, Scala, Swift, Toml};
 pub use cli_exit_codes::*;
-pub use control::{run_diff_on_files, run_tool_on_merge_scenario};
+pub use control::{
+    run_diff_on_files, run_tool_on_merge_scenario, ExecutionConfiguration, ExecutionError,
+    ExecutionReport, ExecutionResult, Revision, SyntaxErrorPolicy, TextualFallback,
+};
+pub use declared_language::{load_language_definition, DeclaredLanguage};
+pub use language_registry::LanguageRegistry;
+pub use language_support::LanguageSupport;
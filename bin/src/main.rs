@@ -1,10 +1,21 @@
 use clap::Parser;
 use cli_args::{CliArgs, CliSubCommands, DiffCliArgs, MergeCliArgs};
 
+mod builtin_languages;
 mod cli_args;
 mod cli_exit_codes;
+mod content_detection;
 mod control;
-mod language;
+mod declared_language;
+mod injections;
+mod language_registry;
+mod language_support;
+
+use declared_language::DeclaredLanguage;
+use language_registry::LanguageRegistry;
+use language_support::LanguageSupport;
+use merge::ImportOrdering;
+use parsing::ParseCache;
 
 fn main() {
     let args = CliArgs::parse();
@@ -13,19 +24,127 @@ fn main() {
     log::info!("Starting Generic Merge tool execution");
     log::debug!("Parsed arguments: {:?}", args);
 
+    let mut registry = LanguageRegistry::default();
+    if !args.toml_table_array_keys.is_empty() {
+        let keys: Vec<&str> = args
+            .toml_table_array_keys
+            .iter()
+            .map(String::as_str)
+            .collect();
+        registry.register(builtin_languages::Toml::default().with_table_array_identity_keys(&keys));
+    }
+    for path in &args.language_config {
+        let language = declared_language::load_language_definition(path)
+            .and_then(DeclaredLanguage::try_from)
+            .unwrap_or_else(|error| {
+                log::error!("Error while loading language definitions: {}", error);
+                std::process::exit(cli_exit_codes::INVALID_LANGUAGE_ERROR)
+            });
+        registry.register(language);
+    }
+    for (pattern, language) in &args.language_mapping {
+        registry
+            .map_file_pattern(pattern, language)
+            .unwrap_or_else(|error| {
+                log::error!("Error while mapping {} to a language: {}", pattern, error);
+                std::process::exit(cli_exit_codes::INVALID_LANGUAGE_ERROR)
+            });
+    }
+
+    for (handler, key, value) in &args.handler_option {
+        registry
+            .set_handler_option(handler, key, value)
+            .unwrap_or_else(|error| {
+                log::error!("Error while setting an option of {}: {}", handler, error);
+                std::process::exit(cli_exit_codes::INVALID_LANGUAGE_ERROR)
+            });
+    }
+    let handler_names = registry.handler_names();
+    if let Some(handler) = args
+        .disable_handler
+        .iter()
+        .find(|handler| !handler_names.contains(&handler.as_str()))
+    {
+        log::error!("Can not disable unknown parsing handler {}", handler);
+        std::process::exit(cli_exit_codes::INVALID_LANGUAGE_ERROR)
+    }
+
+    let skip_unknown_languages = args.skip_unknown_languages;
+    let configuration = control::ExecutionConfiguration {
+        syntax_error_policy: args.on_syntax_error,
+        stop_compilation_at: args.stop_compilation_at,
+        disabled_handlers: args.disable_handler,
+        import_ordering: ImportOrdering {
+            always_sort: args.sort_imports
+                || args.static_imports_last
+                || !args.import_groups.is_empty(),
+            static_imports_first: !args.static_imports_last,
+            package_groups: args.import_groups,
+        },
+        remove_unused_imports: args.remove_unused_imports,
+        parse_cache: args.parse_cache.map(ParseCache::new),
+    };
     match args.command {
-        CliSubCommands::Diff(args) => run_diff(args),
-        CliSubCommands::Merge(args) => run_merge(args),
+        CliSubCommands::Diff(args) => {
+            run_diff(args, &registry, &configuration, skip_unknown_languages)
+        }
+        CliSubCommands::Merge(args) => {
+            run_merge(args, &registry, &configuration, skip_unknown_languages)
+        }
+    }
+}
+
+fn exit_with_execution_error(error: control::ExecutionError) -> ! {
+    log::error!("Error while running tool: {}", error);
+    match error {
+        control::ExecutionError::ParsingError {
+            error: parsing::ParsingError::SyntaxError(_),
+            ..
+        } => std::process::exit(cli_exit_codes::SYNTAX_ERROR),
+        _ => std::process::exit(cli_exit_codes::INTERNAL_EXECUTION_ERROR),
     }
 }
 
-fn run_merge(args: MergeCliArgs) {
-    let base_path = args.base_path.unwrap();
+fn get_language<'r>(
+    registry: &'r LanguageRegistry,
+    language: Option<String>,
+    revisions: &[(Option<&std::path::Path>, &str)],
+    skip_unknown_languages: bool,
+) -> &'r dyn LanguageSupport {
+    let language = match language {
+        Some(language) => registry.get_by_name(&language),
+        None => match registry.detect(revisions.iter().copied()) {
+            Ok(language) => Ok(language),
+            Err(error) if skip_unknown_languages => {
+                log::warn!("Skipping file: {}", error);
+                std::process::exit(cli_exit_codes::SKIPPED_UNKNOWN_LANGUAGE)
+            }
+            Err(error) => Err(error),
+        },
+    };
 
-    let base = std::fs::read_to_string(&base_path).unwrap_or_else(|error| {
-        log::error!("Error while reading base file: {}", error);
-        std::process::exit(cli_exit_codes::READING_FILE_ERROR)
-    });
+    language.unwrap_or_else(|error| {
+        log::error!("Error while retrieving language configuration: {}", error);
+        std::process::exit(cli_exit_codes::INVALID_LANGUAGE_ERROR)
+    })
+}
+
+fn run_merge(
+    args: MergeCliArgs,
+    registry: &LanguageRegistry,
+    configuration: &control::ExecutionConfiguration,
+    skip_unknown_languages: bool,
+) {
+    let base = match &args.base_path {
+        Some(base_path) => std::fs::read_to_string(base_path).unwrap_or_else(|error| {
+            log::error!("Error while reading base file: {}", error);
+            std::process::exit(cli_exit_codes::READING_FILE_ERROR)
+        }),
+        None => {
+            log::info!("No base file provided, merging as if both revisions added the file");
+            String::new()
+        }
+    };
     let left = std::fs::read_to_string(&args.left_path).unwrap_or_else(|error| {
         log::error!("Error while reading left file: {}", error);
         std::process::exit(cli_exit_codes::READING_FILE_ERROR)
@@ -35,27 +154,36 @@ fn run_merge(args: MergeCliArgs) {
         std::process::exit(cli_exit_codes::READING_FILE_ERROR)
     });
 
-    let language = match args.language {
-        Some(language) => language::get_language_from_name(&language),
-        None => language::get_language_by_file_path(&base_path),
-    }
-    .unwrap_or_else(|error| {
-        log::error!("Error while retrieving language configuration: {}", error);
-        std::process::exit(cli_exit_codes::INVALID_LANGUAGE_ERROR)
-    });
+    let language = get_language(
+        registry,
+        args.language,
+        &[
+            (args.base_path.as_deref(), &base),
+            (Some(&args.left_path), &left),
+            (Some(&args.right_path), &right),
+        ],
+        skip_unknown_languages,
+    );
 
-    let result = control::run_tool_on_merge_scenario(language, &base, &left, &right)
-        .unwrap_or_else(|error| {
-            log::error!("Error while running tool: {}", error);
-            std::process::exit(cli_exit_codes::INTERNAL_EXECUTION_ERROR)
-        });
+    let report = control::run_tool_on_merge_scenario(
+        registry,
+        language,
+        configuration,
+        &base,
+        &left,
+        &right,
+    )
+    .unwrap_or_else(|error| exit_with_execution_error(error));
 
-    std::fs::write(args.merge_path.unwrap(), result.to_string()).unwrap_or_else(|error| {
+    std::fs::write(args.merge_path.unwrap(), report.to_string()).unwrap_or_else(|error| {
         log::error!("Error while writing output file: {}", error);
         std::process::exit(cli_exit_codes::WRITING_FILE_ERROR)
     });
 
-    match result {
+    for textual_fallback in &report.textual_fallbacks {
+        log::warn!("{}", textual_fallback);
+    }
+    match report.result {
         control::ExecutionResult::WithConflicts(_) => {
             log::info!("Execution finished with conflicts");
             std::process::exit(cli_exit_codes::SUCCESS_WITH_CONFLICTS)
@@ -67,7 +195,12 @@ fn run_merge(args: MergeCliArgs) {
     }
 }
 
-fn run_diff(args: DiffCliArgs) {
+fn run_diff(
+    args: DiffCliArgs,
+    registry: &LanguageRegistry,
+    configuration: &control::ExecutionConfiguration,
+    skip_unknown_languages: bool,
+) {
     let left = std::fs::read_to_string(&args.left_path).unwrap_or_else(|error| {
         log::error!("Error while reading left file: {}", error);
         std::process::exit(cli_exit_codes::READING_FILE_ERROR)
@@ -77,19 +210,18 @@ fn run_diff(args: DiffCliArgs) {
         std::process::exit(cli_exit_codes::READING_FILE_ERROR)
     });
 
-    let language = match args.language {
-        Some(language) => language::get_language_from_name(&language),
-        None => language::get_language_by_file_path(&args.left_path),
-    }
-    .unwrap_or_else(|error| {
-        log::error!("Error while retrieving language configuration: {}", error);
-        std::process::exit(cli_exit_codes::INVALID_LANGUAGE_ERROR)
-    });
+    let language = get_language(
+        registry,
+        args.language,
+        &[
+            (Some(&args.left_path), &left),
+            (Some(&args.right_path), &right),
+        ],
+        skip_unknown_languages,
+    );
 
-    let result = control::run_diff_on_files(language, &left, &right).unwrap_or_else(|error| {
-        log::error!("Error while running tool: {}", error);
-        std::process::exit(cli_exit_codes::INTERNAL_EXECUTION_ERROR)
-    });
+    let result = control::run_diff_on_files(registry, language, configuration, &left, &right)
+        .unwrap_or_else(|error| exit_with_execution_error(error));
 
     log::info!("{:?}", result);
     match result.is_perfect_match {
@@ -49,3 +49,596 @@ fn if_i_am_running_on_diff_mode_and_files_do_not_fully_match_it_returns_one() {
         .assert()
         .code(bin::SUCCESS_FILES_DO_NOT_FULLY_MATCH);
 }
+
+#[test]
+fn languages_can_be_loaded_from_a_definition_file() {
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg("--base-path=tests/scenarios/no_conflicts/base.java")
+        .arg("--left-path=tests/scenarios/no_conflicts/left.java")
+        .arg("--right-path=tests/scenarios/no_conflicts/right.java")
+        .arg("--merge-path=tests/scenarios/no_conflicts/merge.output.declarative.java")
+        .arg("--language-config=tests/languages/java.toml")
+        .arg("--language=java-declarative")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+}
+
+#[test]
+fn invalid_definition_files_are_reported_as_invalid_language() {
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg("--base-path=tests/scenarios/no_conflicts/base.java")
+        .arg("--left-path=tests/scenarios/no_conflicts/left.java")
+        .arg("--right-path=tests/scenarios/no_conflicts/right.java")
+        .arg("--merge-path=tests/scenarios/no_conflicts/merge.output.declarative.java")
+        .arg("--language-config=tests/languages/missing.toml")
+        .assert()
+        .code(bin::INVALID_LANGUAGE_ERROR);
+}
+
+#[test]
+fn labels_can_be_defined_through_tree_sitter_queries() {
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg("--base-path=tests/scenarios/method_overload/base.java")
+        .arg("--left-path=tests/scenarios/method_overload/left.java")
+        .arg("--right-path=tests/scenarios/method_overload/right.java")
+        .arg("--merge-path=tests/scenarios/method_overload/merge.output.queries.java")
+        .arg("--language-config=tests/languages/java-queries.toml")
+        .arg("--language=java-queries")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+
+    let expected = std::fs::read_to_string("tests/scenarios/method_overload/merge.java").unwrap();
+    let result =
+        std::fs::read_to_string("tests/scenarios/method_overload/merge.output.queries.java")
+            .unwrap();
+    assert_eq!(expected.trim(), result.trim());
+}
+
+/// Copies the revisions of a scenario to a temporary directory, renaming them to the given
+/// file name, so they can not be detected by their usual extension.
+fn copy_scenario(scenario: &str, extension: &str, file_name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join("generic-merge-cli").join(format!(
+        "{}-{}",
+        file_name,
+        std::process::id()
+    ));
+    for revision in ["base", "left", "right"] {
+        std::fs::create_dir_all(dir.join(revision)).unwrap();
+        std::fs::copy(
+            format!("tests/scenarios/{}/{}.{}", scenario, revision, extension),
+            dir.join(revision).join(file_name),
+        )
+        .unwrap();
+    }
+    dir
+}
+
+#[test]
+fn files_are_mapped_to_languages_by_glob_patterns() {
+    let dir = copy_scenario("no_conflicts", "java", "Test.java.template");
+
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg(format!(
+            "--base-path={}/base/Test.java.template",
+            dir.display()
+        ))
+        .arg(format!(
+            "--left-path={}/left/Test.java.template",
+            dir.display()
+        ))
+        .arg(format!(
+            "--right-path={}/right/Test.java.template",
+            dir.display()
+        ))
+        .arg(format!("--merge-path={}/Test.java.template", dir.display()))
+        .arg("--language-mapping=*.java.template=java")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+}
+
+#[test]
+fn mappings_to_unknown_languages_are_reported_as_invalid_language() {
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg("--base-path=tests/scenarios/no_conflicts/base.java")
+        .arg("--left-path=tests/scenarios/no_conflicts/left.java")
+        .arg("--right-path=tests/scenarios/no_conflicts/right.java")
+        .arg("--merge-path=tests/scenarios/no_conflicts/merge.output.java")
+        .arg("--language-mapping=*.java=cobol")
+        .assert()
+        .code(bin::INVALID_LANGUAGE_ERROR);
+}
+
+#[test]
+fn the_language_is_detected_from_the_other_revisions_if_base_is_missing() {
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg("--left-path=tests/scenarios/no_conflicts/left.java")
+        .arg("--right-path=tests/scenarios/no_conflicts/right.java")
+        .arg("--merge-path=tests/scenarios/no_conflicts/merge.output.without-base.java")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+}
+
+#[test]
+fn the_language_is_detected_by_the_contents_of_the_files() {
+    let dir = copy_scenario("no_conflicts", "java", "Test");
+    for revision in ["base", "left", "right"] {
+        let path = dir.join(revision).join("Test");
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::write(&path, format!("// vim: set ft=java:\n{}", contents)).unwrap();
+    }
+
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg(format!("--base-path={}/base/Test", dir.display()))
+        .arg(format!("--left-path={}/left/Test", dir.display()))
+        .arg(format!("--right-path={}/right/Test", dir.display()))
+        .arg(format!("--merge-path={}/Test", dir.display()))
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+}
+
+#[test]
+fn files_of_unknown_languages_are_skipped_if_requested() {
+    let dir = copy_scenario("no_conflicts", "java", "Test.txt");
+    let args = [
+        format!("--base-path={}/base/Test.txt", dir.display()),
+        format!("--left-path={}/left/Test.txt", dir.display()),
+        format!("--right-path={}/right/Test.txt", dir.display()),
+        format!("--merge-path={}/Test.txt", dir.display()),
+    ];
+
+    Command::cargo_bin("generic-merge")
+        .unwrap()
+        .arg("merge")
+        .args(&args)
+        .assert()
+        .code(bin::INVALID_LANGUAGE_ERROR);
+
+    Command::cargo_bin("generic-merge")
+        .unwrap()
+        .arg("merge")
+        .args(&args)
+        .arg("--skip-unknown-languages")
+        .assert()
+        .code(bin::SKIPPED_UNKNOWN_LANGUAGE);
+    assert!(!dir.join("Test.txt").exists());
+}
+
+fn copy_scenario_with_syntax_error(file_name: &str) -> std::path::PathBuf {
+    let dir = copy_scenario("no_conflicts", "java", file_name);
+    let path = dir.join("left").join(file_name);
+    let contents = std::fs::read_to_string(&path).unwrap();
+    std::fs::write(&path, contents.replace("return n;", "return n")).unwrap();
+    dir
+}
+
+fn merge_with_syntax_error_policy(
+    file_name: &str,
+    policy: Option<&str>,
+) -> (Command, std::path::PathBuf) {
+    let dir = copy_scenario_with_syntax_error(file_name);
+    let merge_path = dir.join(file_name);
+
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg(format!("--base-path={}/base/{}", dir.display(), file_name))
+        .arg(format!("--left-path={}/left/{}", dir.display(), file_name))
+        .arg(format!(
+            "--right-path={}/right/{}",
+            dir.display(),
+            file_name
+        ))
+        .arg(format!("--merge-path={}", merge_path.display()));
+    if let Some(policy) = policy {
+        cmd.arg(format!("--on-syntax-error={}", policy));
+    }
+    (cmd, merge_path)
+}
+
+#[test]
+fn syntax_errors_are_reported_with_their_revision_and_position() {
+    let (mut cmd, _) = merge_with_syntax_error_policy("Fail.java", Some("fail"));
+    let output = cmd.output().unwrap();
+
+    assert_eq!(Some(bin::SYNTAX_ERROR), output.status.code());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("left file: Syntax error at line 5, column 21: missing \";\""),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn files_with_syntax_errors_can_be_merged_anyway() {
+    let (mut cmd, _) = merge_with_syntax_error_policy("Merge.java", Some("merge"));
+    cmd.assert().code(bin::SUCCESS_WITHOUT_CONFLICTS);
+}
+
+#[test]
+fn files_with_syntax_errors_can_be_merged_textually() {
+    let (mut cmd, merge_path) = merge_with_syntax_error_policy("Textual.java", Some("textual"));
+    cmd.assert().code(bin::SUCCESS_WITH_CONFLICTS);
+
+    let result = std::fs::read_to_string(merge_path).unwrap();
+    assert!(result.contains("<<<<<<<"));
+    assert!(result.contains("            return n\n"));
+}
+
+#[test]
+fn files_with_syntax_errors_are_merged_textually_by_default() {
+    let (mut cmd, _) = merge_with_syntax_error_policy("Default.java", None);
+    let output = cmd.output().unwrap();
+
+    assert_eq!(Some(bin::SUCCESS_WITH_CONFLICTS), output.status.code());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Merged the whole file line by line: Parsing error occurred in left file"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn kinds_to_stop_compilation_at_are_merged_as_opaque_text() {
+    let dir = copy_scenario("no_conflicts", "java", "Semistructured.java");
+    let merge_path = dir.join("Semistructured.java");
+
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg(format!(
+            "--base-path={}/base/Semistructured.java",
+            dir.display()
+        ))
+        .arg(format!(
+            "--left-path={}/left/Semistructured.java",
+            dir.display()
+        ))
+        .arg(format!(
+            "--right-path={}/right/Semistructured.java",
+            dir.display()
+        ))
+        .arg(format!("--merge-path={}", merge_path.display()))
+        .arg("--stop-compilation-at=block,constructor_body")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+
+    let result = std::fs::read_to_string(merge_path).unwrap();
+    assert!(
+        result.contains("        } else {\n            return fibo(n - 1) + fibo(n - 2);\n"),
+        "{}",
+        result
+    );
+}
+
+#[test]
+fn kinds_to_stop_compilation_at_changed_by_both_sides_are_merged_line_by_line() {
+    let dir = copy_scenario(
+        "java_method_body_changed_by_both_sides",
+        "java",
+        "Counter.java",
+    );
+    let merge_path = dir.join("Counter.java");
+
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg(format!("--base-path={}/base/Counter.java", dir.display()))
+        .arg(format!("--left-path={}/left/Counter.java", dir.display()))
+        .arg(format!("--right-path={}/right/Counter.java", dir.display()))
+        .arg(format!("--merge-path={}", merge_path.display()))
+        .arg("--stop-compilation-at=block")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+
+    let result = std::fs::read_to_string(merge_path).unwrap();
+    assert!(
+        result.contains("        int x = 10;\n        int y = 1;\n        int z = 20;\n"),
+        "{}",
+        result
+    );
+}
+
+#[test]
+fn merged_imports_are_sorted_as_configured() {
+    let dir = copy_scenario("java_imports_added_by_both_sides", "java", "Imports.java");
+    let merge_path = dir.join("Imports.java");
+
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg(format!("--base-path={}/base/Imports.java", dir.display()))
+        .arg(format!("--left-path={}/left/Imports.java", dir.display()))
+        .arg(format!("--right-path={}/right/Imports.java", dir.display()))
+        .arg(format!("--merge-path={}", merge_path.display()))
+        .arg("--static-imports-last")
+        .arg("--import-groups=java,javax")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+
+    let result = std::fs::read_to_string(merge_path).unwrap();
+    let position = |name: &str| result.find(name).unwrap();
+    assert!(position("java . io") < position("com . google"));
+    assert!(position("org . junit . Test") < position("import static"));
+    assert!(!result.contains("util . List"), "{}", result);
+}
+
+#[test]
+fn merged_imports_are_sorted_whenever_an_ordering_is_configured() {
+    let dir = copy_scenario("java_imports_added_by_both_sides", "java", "Sorted.java");
+    let merge_path = dir.join("Sorted.java");
+    // Every revision has the same imports, in an order the merge has no reason to change
+    let imports = "import org.junit.Test;\nimport com.google.common.collect.Lists;\n";
+    for (revision, body) in [
+        ("base", ""),
+        ("left", "void a() {}"),
+        ("right", "void b() {}"),
+    ] {
+        std::fs::write(
+            dir.join(revision).join("Sorted.java"),
+            format!("{}\npublic class Test {{\n{}\n}}\n", imports, body),
+        )
+        .unwrap();
+    }
+
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg(format!("--base-path={}/base/Sorted.java", dir.display()))
+        .arg(format!("--left-path={}/left/Sorted.java", dir.display()))
+        .arg(format!("--right-path={}/right/Sorted.java", dir.display()))
+        .arg(format!("--merge-path={}", merge_path.display()))
+        .arg("--import-groups=com")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+
+    let result = std::fs::read_to_string(merge_path).unwrap();
+    let position = |name: &str| result.find(name).unwrap();
+    assert!(
+        position("com . google") < position("org . junit"),
+        "{}",
+        result
+    );
+}
+
+#[test]
+fn merged_imports_are_sorted_on_demand_with_the_default_ordering() {
+    let dir = copy_scenario(
+        "java_imports_added_by_both_sides",
+        "java",
+        "SortImports.java",
+    );
+    let merge_path = dir.join("SortImports.java");
+    // Every revision has the same imports, in an order the merge has no reason to change
+    let imports = "import org.junit.Test;\nimport com.google.common.collect.Lists;\n";
+    for (revision, body) in [
+        ("base", ""),
+        ("left", "void a() {}"),
+        ("right", "void b() {}"),
+    ] {
+        std::fs::write(
+            dir.join(revision).join("SortImports.java"),
+            format!("{}\npublic class Test {{\n{}\n}}\n", imports, body),
+        )
+        .unwrap();
+    }
+
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg(format!(
+            "--base-path={}/base/SortImports.java",
+            dir.display()
+        ))
+        .arg(format!(
+            "--left-path={}/left/SortImports.java",
+            dir.display()
+        ))
+        .arg(format!(
+            "--right-path={}/right/SortImports.java",
+            dir.display()
+        ))
+        .arg(format!("--merge-path={}", merge_path.display()))
+        .arg("--sort-imports")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+
+    let result = std::fs::read_to_string(merge_path).unwrap();
+    let position = |name: &str| result.find(name).unwrap();
+    assert!(
+        position("com . google") < position("org . junit"),
+        "{}",
+        result
+    );
+}
+
+#[test]
+fn imports_left_unused_by_the_merge_are_removed_on_demand() {
+    let dir = copy_scenario(
+        "java_imports_left_unused_by_the_merge",
+        "java",
+        "Report.java",
+    );
+    let merge_path = dir.join("Report.java");
+
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg(format!("--base-path={}/base/Report.java", dir.display()))
+        .arg(format!("--left-path={}/left/Report.java", dir.display()))
+        .arg(format!("--right-path={}/right/Report.java", dir.display()))
+        .arg(format!("--merge-path={}", merge_path.display()))
+        .arg("--remove-unused-imports")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+
+    let result = std::fs::read_to_string(merge_path).unwrap();
+    assert!(!result.contains("java . util . Set"), "{}", result);
+    assert!(result.contains("java . util . Map"), "{}", result);
+    assert!(result.contains("java . util . ArrayList"), "{}", result);
+}
+
+#[test]
+fn comments_between_thrown_types_are_not_merged_as_types() {
+    let dir = copy_scenario("java_type_lists_added_by_both_sides", "java", "Thrown.java");
+    let merge_path = dir.join("Thrown.java");
+    for (revision, types) in [
+        ("base", "IOException /* checked */, TimeoutException"),
+        (
+            "left",
+            "IOException /* checked */, TimeoutException, InterruptedException",
+        ),
+        (
+            "right",
+            "ParseException, IOException /* checked */, TimeoutException",
+        ),
+    ] {
+        std::fs::write(
+            dir.join(revision).join("Thrown.java"),
+            format!("class A {{\n  void a() throws {} {{}}\n}}\n", types),
+        )
+        .unwrap();
+    }
+
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg(format!("--base-path={}/base/Thrown.java", dir.display()))
+        .arg(format!("--left-path={}/left/Thrown.java", dir.display()))
+        .arg(format!("--right-path={}/right/Thrown.java", dir.display()))
+        .arg(format!("--merge-path={}", merge_path.display()))
+        .arg("--handler-option=remove_block_comments.kinds=line_comment")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+
+    let result = std::fs::read_to_string(merge_path).unwrap();
+    assert!(
+        result
+            .contains("IOException , TimeoutException , InterruptedException , ParseException /*"),
+        "{}",
+        result
+    );
+}
+
+fn merge_with_comment(file_name: &str, extra_args: &[&str]) -> String {
+    let dir = copy_scenario("no_conflicts", "java", file_name);
+    let left_path = dir.join("left").join(file_name);
+    let contents = std::fs::read_to_string(&left_path).unwrap();
+    std::fs::write(&left_path, format!("/* Kept comment */\n{}", contents)).unwrap();
+    let merge_path = dir.join(file_name);
+
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg(format!("--base-path={}/base/{}", dir.display(), file_name))
+        .arg(format!("--left-path={}", left_path.display()))
+        .arg(format!(
+            "--right-path={}/right/{}",
+            dir.display(),
+            file_name
+        ))
+        .arg(format!("--merge-path={}", merge_path.display()))
+        .args(extra_args)
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+
+    std::fs::read_to_string(merge_path).unwrap()
+}
+
+#[test]
+fn disabled_parsing_handlers_do_not_run() {
+    let result = merge_with_comment("RemovedComments.java", &[]);
+    assert!(!result.contains("Kept comment"), "{}", result);
+
+    let result = merge_with_comment(
+        "KeptComments.java",
+        &["--disable-handler=remove_block_comments"],
+    );
+    assert!(result.contains("/* Kept comment */"), "{}", result);
+}
+
+#[test]
+fn options_are_passed_to_parsing_handlers() {
+    let result = merge_with_comment(
+        "OnlyLineCommentsRemoved.java",
+        &["--handler-option=remove_block_comments.kinds=line_comment"],
+    );
+    assert!(result.contains("/* Kept comment */"), "{}", result);
+}
+
+#[test]
+fn unknown_parsing_handlers_and_options_are_rejected() {
+    for arg in [
+        "--disable-handler=remove_comments",
+        "--handler-option=remove_comments.kinds=line_comment",
+        "--handler-option=remove_block_comments.kind=line_comment",
+    ] {
+        let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+        cmd.arg("merge")
+            .arg("--base-path=tests/scenarios/no_conflicts/base.java")
+            .arg("--left-path=tests/scenarios/no_conflicts/left.java")
+            .arg("--right-path=tests/scenarios/no_conflicts/right.java")
+            .arg("--merge-path=tests/scenarios/no_conflicts/merge.output.java")
+            .arg(arg)
+            .assert()
+            .code(bin::INVALID_LANGUAGE_ERROR);
+    }
+}
+
+#[test]
+fn parsed_trees_are_cached_across_runs() {
+    let dir = copy_scenario("no_conflicts", "java", "Cached.java");
+    let merge_path = dir.join("Cached.java");
+    let cache_path = dir.join("cache");
+    let expected = std::fs::read_to_string("tests/scenarios/no_conflicts/merge.java").unwrap();
+
+    for _ in 0..2 {
+        let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+        cmd.arg("merge")
+            .arg(format!("--base-path={}/base/Cached.java", dir.display()))
+            .arg(format!("--left-path={}/left/Cached.java", dir.display()))
+            .arg(format!("--right-path={}/right/Cached.java", dir.display()))
+            .arg(format!("--merge-path={}", merge_path.display()))
+            .arg(format!("--parse-cache={}", cache_path.display()))
+            .assert()
+            .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+
+        let result = std::fs::read_to_string(&merge_path).unwrap();
+        assert_eq!(expected.trim(), result.trim());
+        assert_eq!(3, std::fs::read_dir(&cache_path).unwrap().count());
+    }
+}
+
+#[test]
+fn entries_of_toml_arrays_of_tables_are_identified_by_the_configured_keys() {
+    let dir = std::env::temp_dir()
+        .join("generic-merge-cli")
+        .join(format!("toml-table-array-keys-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let revisions = [
+        ("base", "[[plugin]]\nid = \"lint\"\nversion = \"1\"\n"),
+        ("left", "[[plugin]]\nid = \"lint\"\nversion = \"2\"\n"),
+        (
+            "right",
+            "[[plugin]]\nid = \"lint\"\nversion = \"1\"\nenabled = true\n",
+        ),
+    ];
+    for (revision, contents) in revisions {
+        std::fs::write(dir.join(format!("{}.toml", revision)), contents).unwrap();
+    }
+    let merge_path = dir.join("merge.toml");
+
+    let mut cmd = Command::cargo_bin("generic-merge").unwrap();
+    cmd.arg("merge")
+        .arg(format!("--base-path={}/base.toml", dir.display()))
+        .arg(format!("--left-path={}/left.toml", dir.display()))
+        .arg(format!("--right-path={}/right.toml", dir.display()))
+        .arg(format!("--merge-path={}", merge_path.display()))
+        .arg("--toml-table-array-keys=id")
+        .assert()
+        .code(bin::SUCCESS_WITHOUT_CONFLICTS);
+
+    let result = std::fs::read_to_string(merge_path).unwrap();
+    assert_eq!(1, result.matches("[[ plugin ]]").count(), "{}", result);
+    assert!(result.contains("version = \"2\""), "{}", result);
+    assert!(result.contains("enabled = true"), "{}", result);
+}
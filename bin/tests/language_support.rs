@@ -0,0 +1,95 @@
+use bin::{LanguageRegistry, LanguageSupport};
+use matching::matching_configuration::MatchingConfiguration;
+use matching_handlers::MatchingHandlers;
+use merge::PrinterConfiguration;
+use model::CSTNode;
+use parsing::ParserConfiguration;
+
+/// A language defined outside of the tool, reusing the Java grammar but only telling methods
+/// apart by their names.
+struct Jav {
+    parser: ParserConfiguration,
+    matching: MatchingConfiguration<'static>,
+    printer: PrinterConfiguration,
+}
+
+impl Jav {
+    fn new() -> Self {
+        let mut handlers = MatchingHandlers::new();
+        handlers.register("method_declaration", |left, right| {
+            (method_name(left) == method_name(right)).into()
+        });
+
+        Jav {
+            parser: ParserConfiguration::new(tree_sitter_java::language())
+                .with_kinds_with_unordered_children(&["class_body"])
+                .with_block_end_delimiters(&["}"]),
+            matching: MatchingConfiguration::new()
+                .with_delimiters(&["{", "}", ";"])
+                .with_kinds_with_label(&["method_declaration"])
+                .with_handlers(handlers),
+            printer: PrinterConfiguration::default(),
+        }
+    }
+}
+
+fn method_name<'a>(node: &'a CSTNode<'a>) -> Option<&'a str> {
+    match node {
+        CSTNode::NonTerminal(non_terminal) => non_terminal
+            .children
+            .iter()
+            .find(|child| child.kind() == "identifier")
+            .map(|identifier| match identifier {
+                CSTNode::Terminal(terminal) => terminal.value,
+                CSTNode::NonTerminal(_) => "",
+            }),
+        CSTNode::Terminal(_) => None,
+    }
+}
+
+impl LanguageSupport for Jav {
+    fn name(&self) -> &str {
+        "jav"
+    }
+
+    fn extensions(&self) -> Vec<&str> {
+        vec!["jav"]
+    }
+
+    fn parser_configuration(&self) -> &ParserConfiguration {
+        &self.parser
+    }
+
+    fn matching_configuration(&self) -> &MatchingConfiguration<'static> {
+        &self.matching
+    }
+
+    fn printer_configuration(&self) -> &PrinterConfiguration {
+        &self.printer
+    }
+}
+
+#[test]
+fn languages_defined_outside_of_the_tool_can_be_registered() -> Result<(), String> {
+    let mut registry = LanguageRegistry::default();
+    registry.register(Jav::new());
+
+    let language = registry.get_by_file_path(std::path::Path::new("src/Example.jav"))?;
+    assert_eq!("jav", language.name());
+
+    let result = bin::run_tool_on_merge_scenario(
+        &registry,
+        language,
+        &bin::ExecutionConfiguration::default(),
+        "class Example {\n}\n",
+        "class Example {\n  void create() {}\n}\n",
+        "class Example {\n  void delete() {}\n}\n",
+    )
+    .map_err(|error| error.to_string())?;
+
+    assert_eq!(
+        "class Example  {  void create  ( )  { }  void delete  ( )  { } }",
+        result.to_string().trim()
+    );
+    Ok(())
+}
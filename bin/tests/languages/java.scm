@@ -0,0 +1,19 @@
+; Label queries equivalent to the built-in Java matching handlers.
+
+(method_declaration name: (identifier) @name) @node
+(method_declaration
+  parameters: (formal_parameters (formal_parameter type: (_) @param.type))) @node
+(method_declaration
+  parameters: (formal_parameters (spread_parameter (_) @param.type (variable_declarator)))) @node
+
+(constructor_declaration name: (identifier) @name) @node
+(constructor_declaration
+  parameters: (formal_parameters (formal_parameter type: (_) @param.type))) @node
+
+(field_declaration declarator: (variable_declarator name: (identifier) @name)) @node
+
+(class_declaration name: (identifier) @name) @node
+(interface_declaration name: (identifier) @name) @node
+(enum_declaration name: (identifier) @name) @node
+
+(import_declaration) @node
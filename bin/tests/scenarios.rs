@@ -1,15 +1,27 @@
 #[test]
-fn all_java_samples_work_correctly() -> Result<(), Box<dyn std::error::Error>> {
+fn all_samples_work_correctly() -> Result<(), Box<dyn std::error::Error>> {
     let sample_names = get_samples_names()?;
+    let registry = bin::LanguageRegistry::default();
 
     for sample_path in sample_names {
-        let base = std::fs::read_to_string(format!("{}/base.java", sample_path.display()))?;
-        let left = std::fs::read_to_string(format!("{}/left.java", sample_path.display()))?;
-        let right = std::fs::read_to_string(format!("{}/right.java", sample_path.display()))?;
+        let base_path = get_base_file_path(&sample_path)?;
+        let language = registry.get_by_file_path(&base_path)?;
+        let extension = base_path.extension().unwrap().to_string_lossy();
 
-        let expected = std::fs::read_to_string(format!("{}/merge.java", sample_path.display()))?;
-        let result = bin::run_tool_on_merge_scenario(model::Language::Java, &base, &left, &right)
-            .map_err(|err| format!("Failed on {} with error: {}", sample_path.display(), err));
+        let base = std::fs::read_to_string(&base_path)?;
+        let left = std::fs::read_to_string(sample_path.join(format!("left.{}", extension)))?;
+        let right = std::fs::read_to_string(sample_path.join(format!("right.{}", extension)))?;
+
+        let expected = std::fs::read_to_string(sample_path.join(format!("merge.{}", extension)))?;
+        let result = bin::run_tool_on_merge_scenario(
+            &registry,
+            language,
+            &bin::ExecutionConfiguration::default(),
+            &base,
+            &left,
+            &right,
+        )
+        .map_err(|err| format!("Failed on {} with error: {}", sample_path.display(), err));
 
         assert_eq!(
             expected.trim(),
@@ -22,6 +34,14 @@ fn all_java_samples_work_correctly() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+fn get_base_file_path(sample_path: &std::path::Path) -> Result<std::path::PathBuf, String> {
+    std::fs::read_dir(sample_path)
+        .map_err(|err| err.to_string())?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .find(|path| path.file_stem().is_some_and(|stem| stem == "base"))
+        .ok_or(format!("No base file found in {}", sample_path.display()))
+}
+
 fn get_samples_names() -> Result<Vec<std::path::PathBuf>, std::io::Error> {
     std::fs::read_dir("tests/scenarios")?
         .filter(|sample| {
@@ -1 +1 @@
-  package    br . fosd . jdime . stats ;   import   java . text . DecimalFormat ;  import   java . util . HashMap ;  import   java . util . TreeSet ;  import     de . fosd . jdime . common . LangElem ;  import    java . util . logging . Level ;  import    java . util . logging . Logger ;   public class ASTStats  { }
\ No newline at end of file
+  package    br . fosd . jdime . stats ;   import     de . fosd . jdime . common . LangElem ;  import   java . text . DecimalFormat ;  import   java . util . HashMap ;  import   java . util . TreeSet ;  import    java . util . logging . Level ;  import    java . util . logging . Logger ;   public class ASTStats  { }
\ No newline at end of file
@@ -0,0 +1,5 @@
+public class Test {
+    public void run() {
+        System.out.println("run");
+    }
+}
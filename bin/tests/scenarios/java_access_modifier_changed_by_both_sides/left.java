@@ -0,0 +1,5 @@
+public class Test {
+    private void run() {
+        System.out.println("run");
+    }
+}
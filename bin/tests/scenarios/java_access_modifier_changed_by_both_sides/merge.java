@@ -0,0 +1,7 @@
+   public class Test  {   
+<<<<<<<<<
+private
+=========
+protected
+>>>>>>>>>
+ void run  ( )  {    System . out . println  ( "run" ) ; } }
\ No newline at end of file
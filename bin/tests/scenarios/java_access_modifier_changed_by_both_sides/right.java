@@ -0,0 +1,5 @@
+public class Test {
+    protected void run() {
+        System.out.println("run");
+    }
+}
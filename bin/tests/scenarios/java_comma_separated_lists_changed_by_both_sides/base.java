@@ -0,0 +1,8 @@
+public enum Color {
+    RED, GREEN;
+
+    void paint() {
+        draw(RED, 1, false);
+        int[] sizes = {1, 2};
+    }
+}
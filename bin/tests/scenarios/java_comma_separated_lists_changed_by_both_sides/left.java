@@ -0,0 +1,8 @@
+public enum Color {
+    RED, GREEN, BLUE;
+
+    void paint() {
+        draw(RED, 1, false, true);
+        int[] sizes = {1, 2, 3};
+    }
+}
@@ -0,0 +1 @@
+   public enum Color  {  WHITE ,  RED ,  GREEN ,  BLUE  ;    @ Deprecated  (  since = "1" ,  forRemoval = true ) void paint  ( )  {   draw  ( GREEN , 1 , false , true ) ;    int  [ ]  sizes =  { 0 , 1 , 2 , 3 } ; } }
\ No newline at end of file
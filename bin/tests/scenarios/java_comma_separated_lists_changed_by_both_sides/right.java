@@ -0,0 +1,9 @@
+public enum Color {
+    WHITE, RED, GREEN;
+
+    @Deprecated(since = "1", forRemoval = true)
+    void paint() {
+        draw(GREEN, 1, false);
+        int[] sizes = {0, 1, 2};
+    }
+}
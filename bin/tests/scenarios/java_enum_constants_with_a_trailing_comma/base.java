@@ -0,0 +1,16 @@
+public class Palette {
+    enum Color {
+        RED,
+        GREEN,
+    }
+
+    enum Size {
+        SMALL,
+        LARGE,
+        ;
+
+        int width() {
+            return 1;
+        }
+    }
+}
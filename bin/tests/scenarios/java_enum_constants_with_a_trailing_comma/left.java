@@ -0,0 +1,17 @@
+public class Palette {
+    enum Color {
+        RED,
+        GREEN,
+        BLUE,
+    }
+
+    enum Size {
+        SMALL,
+        LARGE,
+        ;
+
+        int width() {
+            return 2;
+        }
+    }
+}
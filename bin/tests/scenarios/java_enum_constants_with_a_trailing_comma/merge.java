@@ -0,0 +1 @@
+   public class Palette  {  enum Color  {  WHITE ,  RED ,  GREEN ,  BLUE , }  enum Size  {  SMALL ,  MEDIUM ,  LARGE ,  ;   int width  ( )  {  return 2 ; } } }
\ No newline at end of file
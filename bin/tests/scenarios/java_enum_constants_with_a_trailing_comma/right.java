@@ -0,0 +1,18 @@
+public class Palette {
+    enum Color {
+        WHITE,
+        RED,
+        GREEN,
+    }
+
+    enum Size {
+        SMALL,
+        MEDIUM,
+        LARGE,
+        ;
+
+        int width() {
+            return 1;
+        }
+    }
+}
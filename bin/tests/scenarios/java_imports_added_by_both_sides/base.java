@@ -0,0 +1,5 @@
+import java.io.File;
+import org.junit.Test;
+
+public class Test {
+}
@@ -0,0 +1,7 @@
+import java.io.File;
+import java.util.List;
+import org.junit.Test;
+import static org.junit.Assert.assertTrue;
+
+public class Test {
+}
@@ -0,0 +1 @@
+   import static    org . junit . Assert . assertTrue ;  import     com . google . common . collect . Lists ;  import   java . io . File ;  import  java . util .  * ;  import   org . junit . Test ;   public class Test  { }
\ No newline at end of file
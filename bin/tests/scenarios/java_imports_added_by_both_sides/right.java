@@ -0,0 +1,7 @@
+import com.google.common.collect.Lists;
+import java.io.File;
+import java.util.*;
+import org.junit.Test;
+
+public class Test {
+}
@@ -0,0 +1,11 @@
+import java.util.List;
+import java.util.Map;
+import java.util.Set;
+
+public class Report {
+    List<String> lines;
+
+    Set<String> tags() {
+        return null;
+    }
+}
@@ -0,0 +1,7 @@
+import java.util.List;
+import java.util.Map;
+import java.util.Set;
+
+public class Report {
+    List<String> lines;
+}
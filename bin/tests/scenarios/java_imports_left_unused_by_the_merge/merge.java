@@ -0,0 +1 @@
+   import   java . util . ArrayList ;  import   java . util . List ;  import   java . util . Map ;  import   java . util . Set ;   public class Report  {   List  < String >  lines ;  void clear  ( )  {   lines =  new  ArrayList  < >  ( ) ; } }
\ No newline at end of file
@@ -0,0 +1,16 @@
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Map;
+import java.util.Set;
+
+public class Report {
+    List<String> lines;
+
+    Set<String> tags() {
+        return null;
+    }
+
+    void clear() {
+        lines = new ArrayList<>();
+    }
+}
@@ -0,0 +1,8 @@
+class Counter {
+    int count(int n) {
+        int x = 10;
+        int y = 1;
+        int z = 2;
+        return x + y + z + n;
+    }
+}
@@ -0,0 +1 @@
+  class Counter  {   int count  (   int n )  {   int  x = 10 ;   int  y = 1 ;   int  z = 20 ;  return    x + y + z + n ; } }
\ No newline at end of file
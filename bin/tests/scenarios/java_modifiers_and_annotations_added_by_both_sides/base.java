@@ -0,0 +1,7 @@
+public class Test {
+    public static final int LIMIT = 10;
+
+    void run() {
+        System.out.println(LIMIT);
+    }
+}
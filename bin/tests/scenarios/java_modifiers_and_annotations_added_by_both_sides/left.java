@@ -0,0 +1,8 @@
+public class Test {
+    static public final int LIMIT = 10;
+
+    @Deprecated
+    void run() {
+        System.out.println(LIMIT);
+    }
+}
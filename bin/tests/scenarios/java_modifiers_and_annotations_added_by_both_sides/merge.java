@@ -0,0 +1 @@
+   public class Test  {   static public final  int  LIMIT = 10 ;    @ Deprecated  @ Override public synchronized void run  ( )  {    System . out . println  ( LIMIT ) ; } }
\ No newline at end of file
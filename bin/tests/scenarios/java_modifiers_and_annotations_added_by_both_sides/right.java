@@ -0,0 +1,8 @@
+public class Test {
+    public static final int LIMIT = 10;
+
+    @Override
+    public synchronized void run() {
+        System.out.println(LIMIT);
+    }
+}
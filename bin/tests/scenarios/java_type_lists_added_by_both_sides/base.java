@@ -0,0 +1,4 @@
+public class Test implements Runnable {
+    public void run() throws IOException {
+    }
+}
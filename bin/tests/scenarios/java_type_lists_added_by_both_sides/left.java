@@ -0,0 +1,4 @@
+public class Test implements Runnable, Serializable {
+    public void run() throws IOException, InterruptedException {
+    }
+}
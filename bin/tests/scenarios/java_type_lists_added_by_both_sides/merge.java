@@ -0,0 +1 @@
+   public class Test  implements  Runnable , Serializable , Closeable  {   public void run  ( )  throws  IOException , InterruptedException , TimeoutException  { } }
\ No newline at end of file
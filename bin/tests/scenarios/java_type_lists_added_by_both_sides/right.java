@@ -0,0 +1,4 @@
+public class Test implements Runnable, Closeable {
+    public void run() throws IOException, TimeoutException {
+    }
+}
@@ -0,0 +1,20 @@
+<?php
+
+namespace App\Services;
+
+use App\Models\User;
+
+class UserService
+{
+    private $repo;
+
+    public function __construct(UserRepository $repo)
+    {
+        $this->repo = $repo;
+    }
+
+    public function find(int $id): ?User
+    {
+        return $this->repo->find($id);
+    }
+}
@@ -0,0 +1,24 @@
+<?php
+
+namespace App\Services;
+
+use App\Models\User;
+use Illuminate\Support\Facades\Log;
+
+class UserService
+{
+    const CACHE_TTL = 60;
+
+    private $repo;
+
+    public function __construct(UserRepository $repo)
+    {
+        $this->repo = $repo;
+    }
+
+    public function find(int $id): ?User
+    {
+        Log::info("Finding user");
+        return $this->repo->find($id);
+    }
+}
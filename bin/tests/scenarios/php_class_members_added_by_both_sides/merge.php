@@ -0,0 +1 @@
+ <?php  namespace  App \ Services ;   use     App \ Models \ User ;  use     Illuminate \ Support \ Facades \ Log ;  use     App \ Models \ Post ;  class UserService  {  const  CACHE_TTL = 60 ;   private   $ repo ;   public function __construct  (   UserRepository  $ repo )  {     $ this -> repo =  $ repo ; }   public function find  (   int  $ id ) :  ?  User  {   Log :: info  (   " Finding user " ) ;  return    $ this -> repo -> find  (   $ id ) ; }   private   $ posts ;   public function postsOf  (   User  $ user ) :  array  {  return    $ this -> posts -> where  (   ' user_id ' ,    $ user -> id ) ; } }
\ No newline at end of file
@@ -0,0 +1,28 @@
+<?php
+
+namespace App\Services;
+
+use App\Models\Post;
+use App\Models\User;
+
+class UserService
+{
+    private $repo;
+
+    private $posts;
+
+    public function find(int $id): ?User
+    {
+        return $this->repo->find($id);
+    }
+
+    public function __construct(UserRepository $repo)
+    {
+        $this->repo = $repo;
+    }
+
+    public function postsOf(User $user): array
+    {
+        return $this->posts->where('user_id', $user->id);
+    }
+}
@@ -0,0 +1,11 @@
+package com.example.pipeline
+
+import org.apache.spark.sql.DataFrame
+
+class Job extends Logging {
+  val name: String = "job"
+
+  def run(df: DataFrame): DataFrame = {
+    df
+  }
+}
@@ -0,0 +1,16 @@
+package com.example.pipeline
+
+import org.apache.spark.sql.DataFrame
+import org.apache.spark.sql.functions.col
+
+class Job extends Logging {
+  val name: String = "job"
+
+  def run(df: DataFrame): DataFrame = {
+    df
+  }
+
+  def run(df: DataFrame, limit: Int): DataFrame = {
+    df.limit(limit)
+  }
+}
@@ -0,0 +1,19 @@
+
+ package  com . example . pipeline
+
+ import org . apache . spark . sql . DataFrame
+ import org . apache . spark . sql . functions . col
+ import scala . util . Try
+ class Job  extends Logging 
+{
+ val name : String = "job"
+ def run  (  df : DataFrame ) : DataFrame = 
+{
+  df . cache  ( )
+}
+ def run  (  df : DataFrame ,  limit : Int ) : DataFrame = 
+{
+  df . limit  ( limit )
+}
+ var retries = 3
+}
\ No newline at end of file
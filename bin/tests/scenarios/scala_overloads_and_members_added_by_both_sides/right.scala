@@ -0,0 +1,14 @@
+package com.example.pipeline
+
+import org.apache.spark.sql.DataFrame
+import scala.util.Try
+
+class Job extends Logging {
+  var retries = 3
+
+  def run(df: DataFrame): DataFrame = {
+    df.cache()
+  }
+
+  val name: String = "job"
+}
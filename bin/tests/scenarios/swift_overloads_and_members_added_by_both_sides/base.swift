@@ -0,0 +1,9 @@
+import Foundation
+
+class ProfileViewController: UIViewController {
+    var user: User?
+
+    func load(id: Int) {
+        print(id)
+    }
+}
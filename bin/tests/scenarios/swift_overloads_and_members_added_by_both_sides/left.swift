@@ -0,0 +1,15 @@
+import Foundation
+import UIKit
+
+class ProfileViewController: UIViewController {
+    var user: User?
+    private let titleLabel = "Profile"
+
+    func load(id: Int) {
+        print(id)
+    }
+
+    func load(named name: String) {
+        print("Loading \(name)")
+    }
+}
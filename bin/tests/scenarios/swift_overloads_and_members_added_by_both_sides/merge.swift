@@ -0,0 +1,17 @@
+
+
+ import  Foundation
+ import  UIKit
+ import  Combine
+ class ProfileViewController :   UIViewController 
+{
+  var  user  : User?
+   private  let  titleLabel = "Profile"
+ func load (  id :  Int )  { 
+ print   (  id )
+ refresh   ( ) }
+ func load (  named name :  String )  { 
+ print   (  "Loading \(name)" ) }
+ func refresh ( )  { 
+ user?.reload   ( ) }
+}
\ No newline at end of file
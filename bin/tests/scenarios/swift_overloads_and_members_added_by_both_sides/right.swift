@@ -0,0 +1,15 @@
+import Combine
+import Foundation
+
+class ProfileViewController: UIViewController {
+    var user: User?
+
+    func load(id: Int) {
+        print(id)
+        refresh()
+    }
+
+    func refresh() {
+        user?.reload()
+    }
+}
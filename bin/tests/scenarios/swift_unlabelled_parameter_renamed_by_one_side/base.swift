@@ -0,0 +1,9 @@
+class Greeter {
+    func greet(_ name: String) {
+        print("Hello, \(name)")
+    }
+
+    func greet(name: String, times: Int) {
+        print(name)
+    }
+}
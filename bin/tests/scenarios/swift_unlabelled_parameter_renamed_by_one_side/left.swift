@@ -0,0 +1,9 @@
+class Greeter {
+    func greet(_ person: String) {
+        print("Hello, \(person)")
+    }
+
+    func greet(name: String, times: Int) {
+        print(name)
+    }
+}
@@ -0,0 +1,8 @@
+
+ class Greeter 
+{
+   public func greet (  _ person :  String )  { 
+ print   (  "Hello, \(person)" ) }
+ func greet (  name :  String ,  times :  Int )  { 
+ print   (  name ) }
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+//! Protocol Buffers grammar for tree-sitter.
+//!
+//! The released crates of this grammar target newer versions of tree-sitter, so the generated
+//! parser of tree-sitter-proto 0.2.0 (the last one using ABI 14) is vendored here.
+
+use tree_sitter::Language;
+
+extern "C" {
+    fn tree_sitter_proto() -> Language;
+}
+
+/// Get the tree-sitter [Language][] for this grammar.
+///
+/// [Language]: https://docs.rs/tree-sitter/*/tree_sitter/struct.Language.html
+pub fn language() -> Language {
+    unsafe { tree_sitter_proto() }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_can_load_grammar() {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(super::language())
+            .expect("Error loading proto language");
+    }
+}
@@ -7,19 +7,47 @@ pub mod unordered;
 
 use matching_configuration::MatchingConfiguration;
 pub use matching_entry::MatchingEntry;
-pub use matchings::Matchings;
-use model::cst_node::Terminal;
+pub use matchings::{MatchingEntries, Matchings};
+use model::{are_structurally_equal, cst_node::Terminal, CSTNode, StructuralHashes};
 use unordered_pair::UnorderedPair;
 
+/// Hashes both trees before matching them, see `calculate_matchings_with_hashes` to match trees
+/// hashed once for several calls, e.g. the revisions of a merge.
 pub fn calculate_matchings<'a>(
     left: &'a model::CSTNode,
     right: &'a model::CSTNode,
     config: &'a MatchingConfiguration<'a>,
+) -> Matchings<'a> {
+    let hashes = get_structural_hashes(left, right);
+    calculate_matchings_with_hashes(left, right, config, &hashes)
+}
+
+/// Hashes both trees once, so that identical subtrees are found without comparing them.
+pub(crate) fn get_structural_hashes<'t>(
+    left: &'t model::CSTNode,
+    right: &'t model::CSTNode,
+) -> StructuralHashes<'t> {
+    let mut hashes = StructuralHashes::new(left);
+    hashes.insert_tree(right);
+    hashes
+}
+
+/// Matches trees whose structural hashes were already computed, once the parsing handlers ran,
+/// so that identical subtrees are found without comparing them.
+pub fn calculate_matchings_with_hashes<'a>(
+    left: &'a model::CSTNode,
+    right: &'a model::CSTNode,
+    config: &'a MatchingConfiguration<'a>,
+    hashes: &StructuralHashes,
 ) -> Matchings<'a> {
     if left.kind() != right.kind() {
         return Matchings::empty();
     }
 
+    if let Some(matchings) = match_identical_subtrees(left, right, config, hashes) {
+        return matchings;
+    }
+
     match (left, right) {
         (
             model::CSTNode::NonTerminal(non_terminal_left),
@@ -27,24 +55,37 @@ pub fn calculate_matchings<'a>(
         ) => {
             if non_terminal_left.are_children_unordered && non_terminal_right.are_children_unordered
             {
-                unordered::calculate_matchings(left, right, config)
+                unordered::calculate_matchings_with_hashes(left, right, config, hashes)
             } else {
-                ordered::calculate_matchings(left, right, config)
+                ordered::calculate_matchings_with_hashes(left, right, config, hashes)
             }
         }
         (
             model::CSTNode::Terminal(Terminal {
                 kind: kind_left,
                 value: value_left,
+                is_opaque: is_opaque_left,
                 ..
             }),
             model::CSTNode::Terminal(Terminal {
                 kind: kind_right,
                 value: value_right,
+                is_opaque: is_opaque_right,
                 ..
             }),
         ) => {
             let is_perfect_match = kind_left == kind_right && value_left == value_right;
+            // Opaque terminals of the same kind are matched by position, so that their text is
+            // merged line by line instead of conflicting as a whole.
+            if !is_perfect_match && *is_opaque_left && *is_opaque_right {
+                return Matchings::from_single(
+                    UnorderedPair(left, right),
+                    MatchingEntry {
+                        score: 1,
+                        is_perfect_match: false,
+                    },
+                );
+            }
             Matchings::from_single(
                 UnorderedPair(left, right),
                 MatchingEntry::new(left, right, is_perfect_match.into()),
@@ -54,10 +95,100 @@ pub fn calculate_matchings<'a>(
     }
 }
 
+/// Identical subtrees match perfectly, each node with its counterpart, which is what matching
+/// them recursively would find, at the cost of a single walk. Subtrees holding nodes of kinds
+/// with a matching handler are matched recursively, so that the handlers give their scores.
+fn match_identical_subtrees<'a>(
+    left: &'a CSTNode,
+    right: &'a CSTNode,
+    config: &MatchingConfiguration,
+    hashes: &StructuralHashes,
+) -> Option<Matchings<'a>> {
+    // Terminals are matched as cheaply without hashes
+    if let CSTNode::Terminal(_) = left {
+        return None;
+    }
+
+    let (Some(left_hash), Some(right_hash)) = (hashes.get(left), hashes.get(right)) else {
+        return None;
+    };
+    if left_hash != right_hash
+        || !are_structurally_equal(left, right)
+        || has_matching_handlers(left, config)
+    {
+        return None;
+    }
+
+    let mut matching_entries = MatchingEntries::default();
+    insert_perfect_matchings(left, right, &mut matching_entries);
+    Some(Matchings::new(matching_entries))
+}
+
+fn has_matching_handlers(node: &CSTNode, config: &MatchingConfiguration) -> bool {
+    config.handlers.has_handler(node.kind())
+        || match node {
+            CSTNode::NonTerminal(non_terminal) => non_terminal
+                .children
+                .iter()
+                .any(|child| has_matching_handlers(child, config)),
+            CSTNode::Terminal(_) => false,
+        }
+}
+
+/// Returns the size of the subtrees, which is the score of their matching.
+fn insert_perfect_matchings<'a>(
+    left: &'a CSTNode,
+    right: &'a CSTNode,
+    matching_entries: &mut MatchingEntries<'a>,
+) -> usize {
+    let size = match (left, right) {
+        (CSTNode::NonTerminal(left), CSTNode::NonTerminal(right)) => {
+            left.children
+                .iter()
+                .zip(right.children.iter())
+                .map(|(left, right)| insert_perfect_matchings(left, right, matching_entries))
+                .sum::<usize>()
+                + 1
+        }
+        (_, _) => 1,
+    };
+    matching_entries.insert(
+        UnorderedPair(left, right),
+        MatchingEntry {
+            score: size,
+            is_perfect_match: true,
+        },
+    );
+    size
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{calculate_matchings, matching_configuration::MatchingConfiguration};
-    use model::{cst_node::Terminal, CSTNode, Point};
+    use crate::{
+        calculate_matchings, calculate_matchings_with_hashes,
+        matching_configuration::MatchingConfiguration,
+    };
+    use matching_handlers::{
+        get_default_java_matching_handlers, MatchingHandlers, DEFAULT_JAVA_KINDS_WITH_LABEL,
+    };
+    use model::{cst_node::Terminal, CSTNode, Point, StructuralHashes};
+    use parsing::ParserConfiguration;
+
+    fn java_parser_configuration() -> ParserConfiguration {
+        ParserConfiguration::new(parsing::get_builtin_grammar("java").unwrap())
+            .with_kinds_with_unordered_children(&[
+                "interface_body",
+                "class_body",
+                "enum_body_declarations",
+            ])
+    }
+
+    fn java_matching_configuration() -> MatchingConfiguration<'static> {
+        MatchingConfiguration::new()
+            .with_delimiters(&["{", "}", ";"])
+            .with_kinds_with_label(&DEFAULT_JAVA_KINDS_WITH_LABEL)
+            .with_handlers(get_default_java_matching_handlers())
+    }
 
     #[test]
     fn two_terminal_nodes_matches_with_a_score_of_one_if_they_have_the_same_kind_and_value() {
@@ -68,6 +199,8 @@ mod tests {
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 5 },
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         });
         let right = CSTNode::Terminal(Terminal {
             id: uuid::Uuid::new_v4(),
@@ -76,6 +209,8 @@ mod tests {
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 5 },
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         });
 
         let matching_configuration = MatchingConfiguration::default();
@@ -95,6 +230,8 @@ mod tests {
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         });
         let right = CSTNode::Terminal(Terminal {
             id: uuid::Uuid::new_v4(),
@@ -103,6 +240,8 @@ mod tests {
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         });
 
         let matching_configuration = MatchingConfiguration::default();
@@ -112,4 +251,115 @@ mod tests {
         assert_eq!(0, left_right_matching.score);
         assert!(!left_right_matching.is_perfect_match);
     }
+
+    #[test]
+    fn identical_subtrees_are_matched_as_if_they_were_matched_recursively() {
+        let config = java_parser_configuration();
+        let src = r#"
+            public class Main {
+                private final int x = 2;
+
+                public static void main(String[] args) {
+                    if (args.length > 0) {
+                        System.out.println(args[0]);
+                    }
+                }
+            }
+        "#;
+        let left = parsing::parse_string(src, &config).unwrap();
+        let right = parsing::parse_string(src, &config).unwrap();
+
+        let matching_configuration = java_matching_configuration();
+        let matchings = calculate_matchings(&left, &right, &matching_configuration);
+        // Without hashes, no subtree is known to be identical
+        let recursive_matchings = calculate_matchings_with_hashes(
+            &left,
+            &right,
+            &matching_configuration,
+            &StructuralHashes::default(),
+        );
+
+        assert_eq!(left.get_tree_size(), matchings.matching_entries.len());
+        assert_eq!(
+            recursive_matchings.matching_entries,
+            matchings.matching_entries
+        );
+        assert!(
+            matchings
+                .get_matching_entry(&left, &right)
+                .unwrap()
+                .is_perfect_match
+        );
+    }
+
+    #[test]
+    fn identical_subtrees_holding_nodes_with_a_matching_handler_are_matched_by_the_handlers() {
+        let config = java_parser_configuration();
+        let src = "class A { void a() {} void b() {} }";
+        let left = parsing::parse_string(src, &config).unwrap();
+        let right = parsing::parse_string(src, &config).unwrap();
+
+        let mut handlers = MatchingHandlers::new();
+        // e.g. methods whose identities differ although their text is the same
+        handlers.register("method_declaration", |_, _| 0);
+        let matching_configuration = MatchingConfiguration::default().with_handlers(handlers);
+        let matchings = calculate_matchings(&left, &right, &matching_configuration);
+        let recursive_matchings = calculate_matchings_with_hashes(
+            &left,
+            &right,
+            &matching_configuration,
+            &StructuralHashes::default(),
+        );
+
+        assert_eq!(
+            recursive_matchings.matching_entries,
+            matchings.matching_entries
+        );
+        assert!(
+            !matchings
+                .get_matching_entry(&left, &right)
+                .unwrap()
+                .is_perfect_match
+        );
+    }
+
+    #[test]
+    fn subtrees_that_differ_are_still_matched_recursively() {
+        let config = java_parser_configuration();
+        let left = parsing::parse_string("class A { void a() { int x = 1; } }", &config).unwrap();
+        let right = parsing::parse_string("class A { void a() { int x = 2; } }", &config).unwrap();
+
+        let matching_configuration = java_matching_configuration();
+        let matchings = calculate_matchings(&left, &right, &matching_configuration);
+
+        let root_matching = matchings.get_matching_entry(&left, &right).unwrap();
+        assert!(!root_matching.is_perfect_match);
+        assert_eq!(left.get_tree_size() - 1, root_matching.score);
+    }
+
+    #[test]
+    fn renamed_methods_are_only_matched_by_their_contents_without_the_java_configuration() {
+        fn find_method<'a>(node: &'a CSTNode<'a>) -> Option<&'a CSTNode<'a>> {
+            match node {
+                CSTNode::NonTerminal(_) if node.kind() == "method_declaration" => Some(node),
+                CSTNode::NonTerminal(non_terminal) => {
+                    non_terminal.children.iter().find_map(find_method)
+                }
+                CSTNode::Terminal(_) => None,
+            }
+        }
+
+        let config = java_parser_configuration();
+        let left = parsing::parse_string("class A { void a() {} }", &config).unwrap();
+        let right = parsing::parse_string("class A { void b() {} }", &config).unwrap();
+        let left_method = find_method(&left).unwrap();
+
+        let java_configuration = java_matching_configuration();
+        let java_matchings = calculate_matchings(&left, &right, &java_configuration);
+        assert!(java_matchings.find_matching_for(left_method).is_none());
+
+        let default_configuration = MatchingConfiguration::default();
+        let default_matchings = calculate_matchings(&left, &right, &default_configuration);
+        assert!(default_matchings.find_matching_for(left_method).is_some());
+    }
 }
@@ -1,5 +1,4 @@
 use matching_handlers::MatchingHandlers;
-use model::Language;
 use std::collections::HashSet;
 
 pub struct MatchingConfiguration<'a> {
@@ -10,27 +9,44 @@ pub struct MatchingConfiguration<'a> {
 
 impl Default for MatchingConfiguration<'_> {
     fn default() -> Self {
-        MatchingConfiguration::from(Language::Java)
+        MatchingConfiguration::new()
     }
 }
 
-impl From<Language> for MatchingConfiguration<'_> {
-    fn from(language: Language) -> Self {
-        match language {
-            Language::Java => MatchingConfiguration {
-                delimiters: ["{", "}", ";"].into(),
-                kinds_with_label: [
-                    "compact_constructor_declaration",
-                    "constructor_declaration",
-                    "field_declaration",
-                    "method_declaration",
-                    "import_declaration",
-                    "class_declaration",
-                    "interface_declaration",
-                ]
-                .into(),
-                handlers: MatchingHandlers::from(Language::Java),
-            },
+impl<'a> MatchingConfiguration<'a> {
+    /// Creates a configuration without delimiters nor labelled kinds, to be built upon by
+    /// languages that are not built into the tool.
+    pub fn new() -> Self {
+        MatchingConfiguration {
+            delimiters: HashSet::new(),
+            kinds_with_label: HashSet::new(),
+            handlers: MatchingHandlers::new(),
         }
     }
+
+    pub fn with_delimiters(mut self, kinds: &[&'static str]) -> Self {
+        self.delimiters.extend(kinds);
+        self
+    }
+
+    /// Nodes of labelled kinds are matched by the score given by the handler registered for them.
+    pub fn with_kinds_with_label(mut self, kinds: &[&'static str]) -> Self {
+        self.kinds_with_label.extend(kinds);
+        self
+    }
+
+    /// Replaces the default matching handlers of the language, e.g. to use different keys to
+    /// identify entries of TOML arrays of tables.
+    pub fn with_handlers(self, handlers: MatchingHandlers<'a>) -> Self {
+        MatchingConfiguration { handlers, ..self }
+    }
+}
+
+impl MatchingConfiguration<'_> {
+    /// Labels nodes of the given kinds, matching them by the identities computed while parsing.
+    pub fn with_identities(mut self, kinds: &[&'static str]) -> Self {
+        self.kinds_with_label.extend(kinds);
+        self.handlers.register_identity_handlers(kinds);
+        self
+    }
 }
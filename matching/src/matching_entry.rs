@@ -13,6 +13,21 @@ impl MatchingEntry {
             is_perfect_match: (2 * score) == (left.get_tree_size() + right.get_tree_size()),
         }
     }
+
+    /// An opaque terminal matched by position scores as much as an identical one, so a match is
+    /// only perfect if the matches of the children it is made of are perfect as well.
+    pub fn with_children(
+        left: &CSTNode,
+        right: &CSTNode,
+        score: usize,
+        are_children_perfect: bool,
+    ) -> Self {
+        let entry = MatchingEntry::new(left, right, score);
+        MatchingEntry {
+            is_perfect_match: entry.is_perfect_match && are_children_perfect,
+            ..entry
+        }
+    }
 }
 
 impl Default for &MatchingEntry {
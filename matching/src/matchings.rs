@@ -1,34 +1,38 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, hash::BuildHasherDefault};
 
-use model::CSTNode;
+use model::{CSTNode, StableHasher};
 use unordered_pair::UnorderedPair;
 
 use crate::matching::Matching;
 use crate::matching_entry::MatchingEntry;
 
+/// Hashed with a stable hasher, so that entries are iterated in the same order on every run.
+pub type MatchingEntries<'a> =
+    HashMap<UnorderedPair<&'a CSTNode<'a>>, MatchingEntry, BuildHasherDefault<StableHasher>>;
+
 #[derive(Debug, Clone)]
 pub struct Matchings<'a> {
-    pub matching_entries: HashMap<UnorderedPair<&'a CSTNode<'a>>, MatchingEntry>,
+    pub matching_entries: MatchingEntries<'a>,
 }
 
 impl<'a> Matchings<'a> {
     pub fn empty() -> Self {
         Matchings {
-            matching_entries: HashMap::new(),
+            matching_entries: MatchingEntries::default(),
         }
     }
 
     pub fn from_single(key: UnorderedPair<&'a CSTNode>, value: MatchingEntry) -> Self {
-        let mut matching_entries = HashMap::new();
+        let mut matching_entries = MatchingEntries::default();
         matching_entries.insert(key, value);
         Matchings { matching_entries }
     }
 
-    pub fn new(matching_entries: HashMap<UnorderedPair<&'a CSTNode<'a>>, MatchingEntry>) -> Self {
+    pub fn new(matching_entries: MatchingEntries<'a>) -> Self {
         Matchings { matching_entries }
     }
 
-    pub fn find_matching_for(&self, a_node: &'a CSTNode) -> Option<Matching> {
+    pub fn find_matching_for(&self, a_node: &'a CSTNode) -> Option<Matching<'_>> {
         self.matching_entries
             .iter()
             .find(|(UnorderedPair(left, right), ..)| {
@@ -52,7 +56,7 @@ impl<'a> Matchings<'a> {
         &'a self,
         left: &'a CSTNode<'a>,
         right: &'a CSTNode<'a>,
-    ) -> Option<&MatchingEntry> {
+    ) -> Option<&'a MatchingEntry> {
         self.matching_entries.get(&UnorderedPair(left, right))
     }
 
@@ -101,6 +105,8 @@ mod tests {
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 5 },
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         });
 
         assert_eq!(None, Matchings::empty().find_matching_for(&a_node))
@@ -115,9 +121,11 @@ mod tests {
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 5 },
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         });
 
-        let mut matchings = HashMap::new();
+        let mut matchings = MatchingEntries::default();
         matchings.insert(
             UnorderedPair(&a_node, &a_node),
             MatchingEntry::new(&a_node, &a_node, 1),
@@ -1,7 +1,7 @@
 use crate::{
     matching_configuration::MatchingConfiguration, matching_entry::MatchingEntry, Matchings,
 };
-use model::{cst_node::NonTerminal, CSTNode};
+use model::{cst_node::NonTerminal, CSTNode, StructuralHashes};
 use unordered_pair::UnorderedPair;
 
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -24,6 +24,16 @@ pub fn calculate_matchings<'a>(
     left: &'a CSTNode,
     right: &'a CSTNode,
     config: &'a MatchingConfiguration<'a>,
+) -> Matchings<'a> {
+    let hashes = crate::get_structural_hashes(left, right);
+    calculate_matchings_with_hashes(left, right, config, &hashes)
+}
+
+pub(crate) fn calculate_matchings_with_hashes<'a>(
+    left: &'a CSTNode,
+    right: &'a CSTNode,
+    config: &'a MatchingConfiguration<'a>,
+    hashes: &StructuralHashes,
 ) -> Matchings<'a> {
     match (left, right) {
         (
@@ -52,7 +62,12 @@ pub fn calculate_matchings<'a>(
                     let left_child = children_left.get(i - 1).unwrap();
                     let right_child = children_right.get(j - 1).unwrap();
 
-                    let w = crate::calculate_matchings(left_child, right_child, config);
+                    let w = crate::calculate_matchings_with_hashes(
+                        left_child,
+                        right_child,
+                        config,
+                        hashes,
+                    );
                     let matching = w
                         .get_matching_entry(left_child, right_child)
                         .unwrap_or_default();
@@ -78,10 +93,8 @@ pub fn calculate_matchings<'a>(
             let mut i = m;
             let mut j = n;
 
-            let mut matchings = Matchings::from_single(
-                UnorderedPair(left, right),
-                MatchingEntry::new(left, right, matrix_m[m][n] + root_matching),
-            );
+            let mut matchings = Matchings::empty();
+            let mut are_children_perfect = true;
 
             while i >= 1 && j >= 1 {
                 match matrix_t.get(i).unwrap().get(j).unwrap().0 {
@@ -89,7 +102,11 @@ pub fn calculate_matchings<'a>(
                     Direction::Left => j -= 1,
                     Direction::Diag => {
                         if matrix_m[i][j] > matrix_m[i - 1][j - 1] {
-                            matchings.extend(matrix_t[i][j].1.clone());
+                            let children_matchings = &matrix_t[i][j].1;
+                            are_children_perfect &= children_matchings
+                                .get_matching_entry(&children_left[i - 1], &children_right[j - 1])
+                                .is_some_and(|entry| entry.is_perfect_match);
+                            matchings.extend(children_matchings.clone());
                         }
                         i -= 1;
                         j -= 1;
@@ -97,6 +114,15 @@ pub fn calculate_matchings<'a>(
                 }
             }
 
+            matchings.extend(Matchings::from_single(
+                UnorderedPair(left, right),
+                MatchingEntry::with_children(
+                    left,
+                    right,
+                    matrix_m[m][n] + root_matching,
+                    are_children_perfect,
+                ),
+            ));
             matchings
         }
         (_, _) => Matchings::empty(),
@@ -106,11 +132,19 @@ pub fn calculate_matchings<'a>(
 #[cfg(test)]
 mod tests {
     use crate::MatchingConfiguration;
+    use matching_handlers::{get_default_java_matching_handlers, DEFAULT_JAVA_KINDS_WITH_LABEL};
     use model::{
         cst_node::{NonTerminal, Terminal},
-        language, CSTNode, Language, Point,
+        CSTNode, Point,
     };
 
+    fn java_matching_configuration() -> MatchingConfiguration<'static> {
+        MatchingConfiguration::new()
+            .with_delimiters(&["{", "}", ";"])
+            .with_kinds_with_label(&DEFAULT_JAVA_KINDS_WITH_LABEL)
+            .with_handlers(get_default_java_matching_handlers())
+    }
+
     #[test]
     fn it_matches_deep_nodes_as_well() {
         let child = CSTNode::Terminal(Terminal {
@@ -120,11 +154,15 @@ mod tests {
             start_position: Point { row: 1, column: 0 },
             end_position: Point { row: 1, column: 7 },
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         });
         let left = CSTNode::NonTerminal(NonTerminal {
             id: uuid::Uuid::new_v4(),
             kind: "kind_a",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 1, column: 7 },
             children: vec![child.clone()],
@@ -133,6 +171,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind_a",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 1, column: 7 },
             children: vec![child.clone()],
@@ -156,6 +196,8 @@ mod tests {
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         });
         let right_child = CSTNode::Terminal(Terminal {
             id: uuid::Uuid::new_v4(),
@@ -164,12 +206,16 @@ mod tests {
             start_position: Point { row: 1, column: 0 },
             end_position: Point { row: 1, column: 7 },
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         });
 
         let left = CSTNode::NonTerminal(NonTerminal {
             id: uuid::Uuid::new_v4(),
             kind: "kind_a",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             children: vec![left_child.clone()],
             start_position: Point { row: 1, column: 0 },
             end_position: Point { row: 0, column: 7 },
@@ -178,12 +224,14 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind_a",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             children: vec![right_child.clone()],
             start_position: Point { row: 1, column: 0 },
             end_position: Point { row: 0, column: 7 },
         });
 
-        let matching_configuration = MatchingConfiguration::from(Language::Java);
+        let matching_configuration = java_matching_configuration();
         let matchings = super::calculate_matchings(&left, &right, &matching_configuration);
         assert!(matchings
             .get_matching_entry(&left_child, &right_child)
@@ -199,6 +247,8 @@ mod tests {
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         });
         let unique_right_child = CSTNode::Terminal(Terminal {
             id: uuid::Uuid::new_v4(),
@@ -207,12 +257,16 @@ mod tests {
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         });
 
         let left = CSTNode::NonTerminal(NonTerminal {
             id: uuid::Uuid::new_v4(),
             kind: "kind_a",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![common_child.clone()],
@@ -221,12 +275,14 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind_a",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![common_child.clone(), unique_right_child],
         });
 
-        let matching_configuration = MatchingConfiguration::from(language::Language::Java);
+        let matching_configuration = java_matching_configuration();
         let matchings = super::calculate_matchings(&left, &right, &matching_configuration);
 
         let left_right_matchings = matchings.get_matching_entry(&left, &right).unwrap();
@@ -243,12 +299,16 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             value: "value_b",
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         });
 
         let left = CSTNode::NonTerminal(NonTerminal {
             id: uuid::Uuid::new_v4(),
             kind: "kind_a",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![common_child.clone()],
@@ -257,12 +317,14 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind_a",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![common_child.clone()],
         });
 
-        let matching_configuration = MatchingConfiguration::from(language::Language::Java);
+        let matching_configuration = java_matching_configuration();
         let matchings = super::calculate_matchings(&left, &right, &matching_configuration);
 
         let left_right_matchings = matchings.get_matching_entry(&left, &right).unwrap();
@@ -279,12 +341,16 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             value: "value_b",
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         });
 
         let intermediate = CSTNode::NonTerminal(NonTerminal {
             id: uuid::Uuid::new_v4(),
             kind: "intermediate",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![leaf],
@@ -294,6 +360,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind_a",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![intermediate.clone()],
@@ -302,6 +370,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind_a",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![intermediate.clone()],
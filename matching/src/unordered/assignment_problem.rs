@@ -1,6 +1,6 @@
 use std::cmp::max;
 
-use model::{cst_node::NonTerminal, CSTNode};
+use model::{cst_node::NonTerminal, CSTNode, StructuralHashes};
 use pathfinding::{kuhn_munkres::Weights, matrix};
 use unordered_pair::UnorderedPair;
 
@@ -10,6 +10,7 @@ pub fn calculate_matchings<'a>(
     left: &'a CSTNode<'a>,
     right: &'a CSTNode<'a>,
     config: &'a MatchingConfiguration<'a>,
+    hashes: &StructuralHashes,
 ) -> crate::Matchings<'a> {
     match (left, right) {
         (
@@ -34,11 +35,16 @@ pub fn calculate_matchings<'a>(
                     children_right
                         .iter()
                         .map(|right_child| {
-                            let w = crate::calculate_matchings(left_child, right_child, config);
+                            let w = crate::calculate_matchings_with_hashes(
+                                left_child,
+                                right_child,
+                                config,
+                                hashes,
+                            );
                             let matching = w
                                 .get_matching_entry(left_child, right_child)
                                 .unwrap_or_default();
-                            (matching.score, w)
+                            (matching.score, matching.is_perfect_match, w)
                         })
                         .collect()
                 })
@@ -55,7 +61,7 @@ pub fn calculate_matchings<'a>(
 fn solve_assignment_problem<'a>(
     left: &'a CSTNode,
     right: &'a CSTNode,
-    children_matchings: Vec<Vec<(usize, Matchings<'a>)>>,
+    children_matchings: Vec<Vec<(usize, bool, Matchings<'a>)>>,
 ) -> Matchings<'a> {
     let m = children_matchings.len();
     let n = children_matchings[0].len();
@@ -73,18 +79,20 @@ fn solve_assignment_problem<'a>(
     let (max_matching, best_matches) = pathfinding::kuhn_munkres::kuhn_munkres(&weights_matrix);
 
     let mut result = Matchings::empty();
+    let mut are_children_perfect = true;
 
     for i in 0..best_matches.len() {
         let j = best_matches[i];
         let cur_matching = weights_matrix.at(i, j);
         if cur_matching > 0 {
-            result.extend(children_matchings[i][j].1.clone());
+            are_children_perfect &= children_matchings[i][j].1;
+            result.extend(children_matchings[i][j].2.clone());
         }
     }
 
     result.extend(Matchings::from_single(
         UnorderedPair(left, right),
-        MatchingEntry::new(left, right, max_matching as usize + 1),
+        MatchingEntry::with_children(left, right, max_matching as usize + 1, are_children_perfect),
     ));
 
     result
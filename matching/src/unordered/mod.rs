@@ -1,5 +1,5 @@
 use crate::matching_configuration::MatchingConfiguration;
-use model::cst_node::NonTerminal;
+use model::{cst_node::NonTerminal, StructuralHashes};
 
 mod assignment_problem;
 mod unique_label;
@@ -8,6 +8,16 @@ pub fn calculate_matchings<'a>(
     left: &'a model::CSTNode<'a>,
     right: &'a model::CSTNode<'a>,
     config: &'a MatchingConfiguration<'a>,
+) -> crate::Matchings<'a> {
+    let hashes = crate::get_structural_hashes(left, right);
+    calculate_matchings_with_hashes(left, right, config, &hashes)
+}
+
+pub(crate) fn calculate_matchings_with_hashes<'a>(
+    left: &'a model::CSTNode<'a>,
+    right: &'a model::CSTNode<'a>,
+    config: &'a MatchingConfiguration<'a>,
+    hashes: &StructuralHashes,
 ) -> crate::Matchings<'a> {
     match (left, right) {
         (model::CSTNode::NonTerminal(left_nt), model::CSTNode::NonTerminal(right_nt)) => {
@@ -17,14 +27,14 @@ pub fn calculate_matchings<'a>(
                     left.kind(),
                     right.kind()
                 );
-                unique_label::calculate_matchings(left, right, config)
+                unique_label::calculate_matchings(left, right, config, hashes)
             } else {
                 log::debug!(
                     "Matching children of \"{}\" with \"{}\" using assignment problem matching.",
                     left.kind(),
                     right.kind()
                 );
-                assignment_problem::calculate_matchings(left, right, config)
+                assignment_problem::calculate_matchings(left, right, config, hashes)
             }
         }
         _ => unreachable!("Unordered matching is only supported for non-terminals."),
@@ -1,4 +1,4 @@
-use model::{cst_node::NonTerminal, CSTNode};
+use model::{cst_node::NonTerminal, CSTNode, StructuralHashes};
 use unordered_pair::UnorderedPair;
 
 use crate::{matching_configuration::MatchingConfiguration, MatchingEntry, Matchings};
@@ -7,6 +7,7 @@ pub fn calculate_matchings<'a>(
     left: &'a CSTNode,
     right: &'a CSTNode,
     config: &'a MatchingConfiguration<'a>,
+    hashes: &StructuralHashes,
 ) -> crate::Matchings<'a> {
     match (left, right) {
         (
@@ -24,6 +25,7 @@ pub fn calculate_matchings<'a>(
             let root_matching: usize = (kind_left == kind_right).into();
 
             let mut sum = 0;
+            let mut are_children_perfect = true;
             let mut result = Matchings::empty();
 
             for child_left in children_left {
@@ -34,14 +36,19 @@ pub fn calculate_matchings<'a>(
                         .unwrap_or_else(|| (child_left.kind() == child_right.kind()).into());
 
                     if is_same_identifier == 1 {
-                        let child_matchings =
-                            crate::calculate_matchings(child_left, child_right, config);
+                        let child_matchings = crate::calculate_matchings_with_hashes(
+                            child_left,
+                            child_right,
+                            config,
+                            hashes,
+                        );
 
                         if let Some(matching_entry) =
                             child_matchings.get_matching_entry(child_left, child_right)
                         {
                             if matching_entry.score >= 1 {
                                 sum += matching_entry.score;
+                                are_children_perfect &= matching_entry.is_perfect_match;
                                 result.extend(child_matchings);
                             }
                         }
@@ -51,7 +58,12 @@ pub fn calculate_matchings<'a>(
 
             result.extend(Matchings::from_single(
                 UnorderedPair(left, right),
-                MatchingEntry::new(left, right, sum + root_matching),
+                MatchingEntry::with_children(
+                    left,
+                    right,
+                    sum + root_matching,
+                    are_children_perfect,
+                ),
             ));
 
             result
@@ -1,12 +1,17 @@
 use matching::matching_configuration::MatchingConfiguration;
-use model::language::Language;
+use matching_handlers::{get_default_java_matching_handlers, DEFAULT_JAVA_KINDS_WITH_LABEL};
 use parsing::ParserConfiguration;
 
 #[test]
 fn the_perfect_matching_calculation_is_correct() -> Result<(), Box<dyn std::error::Error>> {
-    let config = ParserConfiguration::from(Language::Java);
+    let config = ParserConfiguration::new(parsing::get_builtin_grammar("java").unwrap())
+        .with_kinds_with_unordered_children(&[
+            "interface_body",
+            "class_body",
+            "enum_body_declarations",
+        ]);
     let left = parsing::parse_string(
-        r#"""
+        r#"
             public class Main {
                 static {
                     int x = 2;
@@ -20,12 +25,12 @@ fn the_perfect_matching_calculation_is_correct() -> Result<(), Box<dyn std::erro
                     
                 }
             }
-        """#,
+        "#,
         &config,
     )?;
 
     let right = parsing::parse_string(
-        r#"""
+        r#"
             public class Main {
                 public static void teste() {
                     
@@ -39,11 +44,14 @@ fn the_perfect_matching_calculation_is_correct() -> Result<(), Box<dyn std::erro
 
                 }
             }
-        """#,
+        "#,
         &config,
     )?;
 
-    let matching_configuration = MatchingConfiguration::from(Language::Java);
+    let matching_configuration = MatchingConfiguration::new()
+        .with_delimiters(&["{", "}", ";"])
+        .with_kinds_with_label(&DEFAULT_JAVA_KINDS_WITH_LABEL)
+        .with_handlers(get_default_java_matching_handlers());
     let matchings = matching::calculate_matchings(&left, &right, &matching_configuration);
     assert!(
         matchings
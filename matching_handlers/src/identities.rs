@@ -0,0 +1,69 @@
+use model::CSTNode;
+
+use crate::MatchingHandlers;
+
+impl MatchingHandlers<'_> {
+    /// Registers handlers matching nodes of the given kinds by the identities computed while
+    /// parsing, e.g. by tree-sitter label queries.
+    pub fn register_identity_handlers(&mut self, kinds: &[&'static str]) {
+        for kind in kinds {
+            self.register(kind, compute_matching_score_for_identities);
+        }
+    }
+}
+
+fn compute_matching_score_for_identities(left: &CSTNode, right: &CSTNode) -> usize {
+    // Nodes without captures are identified by their whole contents
+    if left.identity().is_empty() && right.identity().is_empty() {
+        return (left.contents() == right.contents()).into();
+    }
+
+    (left.identity() == right.identity()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{cst_node::Terminal, CSTNode, Identity};
+
+    use crate::MatchingHandlers;
+
+    fn make_node(value: &str, identity: Identity) -> CSTNode<'_> {
+        CSTNode::Terminal(Terminal {
+            id: uuid::Uuid::new_v4(),
+            kind: "method_declaration",
+            value,
+            identity,
+            ..Default::default()
+        })
+    }
+
+    fn identity(name: &str) -> Identity {
+        vec![("name".to_string(), name.to_string())]
+    }
+
+    #[test]
+    fn nodes_with_the_same_identity_match() {
+        let mut handlers = MatchingHandlers::new();
+        handlers.register_identity_handlers(&["method_declaration"]);
+
+        let left = make_node("void sum() {}", identity("sum"));
+        let right = make_node("void sum() { return; }", identity("sum"));
+        let other = make_node("void sub() {}", identity("sub"));
+
+        assert_eq!(Some(1), handlers.compute_matching_score(&left, &right));
+        assert_eq!(Some(0), handlers.compute_matching_score(&left, &other));
+    }
+
+    #[test]
+    fn nodes_without_identity_are_compared_by_contents() {
+        let mut handlers = MatchingHandlers::new();
+        handlers.register_identity_handlers(&["method_declaration"]);
+
+        let left = make_node("void sum() {}", vec![]);
+        let right = make_node("void sum() {}", vec![]);
+        let other = make_node("void sub() {}", vec![]);
+
+        assert_eq!(Some(1), handlers.compute_matching_score(&left, &right));
+        assert_eq!(Some(0), handlers.compute_matching_score(&left, &other));
+    }
+}
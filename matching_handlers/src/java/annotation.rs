@@ -0,0 +1,73 @@
+use model::{cst_node::NonTerminal, CSTNode};
+
+/// Annotations are identified by their name, e.g. `Deprecated` or `java.lang.Override`,
+/// regardless of their arguments.
+pub fn compute_matching_score_for_annotation<'a>(
+    left: &'a CSTNode<'a>,
+    right: &'a CSTNode<'a>,
+) -> usize {
+    let name_left = find_annotation_name(left);
+    let name_right = find_annotation_name(right);
+
+    (name_left.is_some() && name_left == name_right).into()
+}
+
+fn find_annotation_name(node: &CSTNode) -> Option<String> {
+    match node {
+        CSTNode::NonTerminal(NonTerminal { children, .. }) => children
+            .iter()
+            .find(|child| child.kind() == "identifier" || child.kind() == "scoped_identifier")
+            .map(|name| name.contents()),
+        CSTNode::Terminal(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    #[test]
+    fn annotations_with_the_same_name_match_regardless_of_their_arguments() {
+        let result = super::compute_matching_score_for_annotation(
+            &make_annotation("SuppressWarnings", "(\"unchecked\")"),
+            &make_annotation("SuppressWarnings", "(\"rawtypes\")"),
+        );
+        assert_eq!(1, result);
+    }
+
+    #[test]
+    fn annotations_with_different_names_do_not_match() {
+        let result = super::compute_matching_score_for_annotation(
+            &make_annotation("Deprecated", ""),
+            &make_annotation("Override", ""),
+        );
+        assert_eq!(0, result);
+    }
+
+    fn make_annotation<'a>(name: &'a str, arguments: &'a str) -> CSTNode<'a> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "annotation",
+            children: vec![
+                CSTNode::Terminal(Terminal {
+                    kind: "@",
+                    value: "@",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "identifier",
+                    value: name,
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "annotation_argument_list",
+                    value: arguments,
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use super::utils::find_child_of_kind;
+use crate::utils::find_child_of_kind;
 use model::{cst_node::NonTerminal, CSTNode};
 
 pub fn compute_matching_score_for_class_like_declaration<'a>(
@@ -47,7 +47,7 @@ mod tests {
         assert_eq!(0, result);
     }
 
-    fn make_class_like_declaration(identifier: &str) -> model::CSTNode {
+    fn make_class_like_declaration(identifier: &str) -> model::CSTNode<'_> {
         model::CSTNode::NonTerminal(model::cst_node::NonTerminal {
             kind: "class_declaration",
             children: vec![model::CSTNode::Terminal(model::cst_node::Terminal {
@@ -1,4 +1,4 @@
-use super::utils::find_identifier;
+use crate::utils::find_identifier;
 use model::{cst_node::NonTerminal, CSTNode};
 
 fn find_variable_declarator<'a>(node_children: &'a [CSTNode<'a>]) -> Option<&'a NonTerminal<'a>> {
@@ -65,8 +65,8 @@ mod tests {
         assert_eq!(0, matching_score);
     }
 
-    fn make_field_declarator_node_with_identifier(identifier: &str) -> CSTNode {
-        return CSTNode::NonTerminal(NonTerminal {
+    fn make_field_declarator_node_with_identifier(identifier: &str) -> CSTNode<'_> {
+        CSTNode::NonTerminal(NonTerminal {
             kind: "field_declaration",
             children: vec![
                 CSTNode::NonTerminal(NonTerminal {
@@ -99,6 +99,6 @@ mod tests {
                 }),
             ],
             ..Default::default()
-        });
+        })
     }
 }
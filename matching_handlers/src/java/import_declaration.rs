@@ -56,7 +56,7 @@ mod tests {
         assert_eq!(1, result);
     }
 
-    fn make_import_of_resource(resource: &str) -> model::CSTNode {
+    fn make_import_of_resource(resource: &str) -> model::CSTNode<'_> {
         model::CSTNode::NonTerminal(model::cst_node::NonTerminal {
             kind: "import_declaration",
             children: vec![model::CSTNode::NonTerminal(model::cst_node::NonTerminal {
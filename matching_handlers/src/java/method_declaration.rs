@@ -1,4 +1,4 @@
-use super::utils::find_child_of_kind;
+use crate::utils::find_child_of_kind;
 use model::{cst_node::NonTerminal, CSTNode};
 
 pub fn compute_matching_score_for_method_declaration<'a>(
@@ -1,18 +1,47 @@
+mod annotation;
 mod class_like_declaration;
 mod field_declaration;
 mod import_declaration;
 mod method_declaration;
-mod utils;
 
 use crate::MatchingHandlers;
 
 use self::{
+    annotation::compute_matching_score_for_annotation,
     class_like_declaration::compute_matching_score_for_class_like_declaration,
     field_declaration::compute_matching_score_for_field_declaration,
     import_declaration::compute_matching_score_for_import_declaration,
     method_declaration::compute_matching_score_for_method_declaration,
 };
 
+/// Kinds matched by the score of their handler rather than by their contents, e.g. methods by
+/// their signature. Modifiers are unordered and identified by their keyword.
+pub const DEFAULT_JAVA_KINDS_WITH_LABEL: [&str; 23] = [
+    "compact_constructor_declaration",
+    "constructor_declaration",
+    "field_declaration",
+    "method_declaration",
+    "import_declaration",
+    "class_declaration",
+    "interface_declaration",
+    "annotation",
+    "marker_annotation",
+    "public",
+    "protected",
+    "private",
+    "abstract",
+    "static",
+    "final",
+    "strictfp",
+    "default",
+    "synchronized",
+    "native",
+    "transient",
+    "volatile",
+    "sealed",
+    "non-sealed",
+];
+
 pub fn get_default_java_matching_handlers<'a>() -> MatchingHandlers<'a> {
     let mut matching_handlers: MatchingHandlers<'a> = MatchingHandlers::new();
     matching_handlers.register(
@@ -43,5 +72,7 @@ pub fn get_default_java_matching_handlers<'a>() -> MatchingHandlers<'a> {
         "interface_declaration",
         compute_matching_score_for_class_like_declaration,
     );
+    matching_handlers.register("annotation", compute_matching_score_for_annotation);
+    matching_handlers.register("marker_annotation", compute_matching_score_for_annotation);
     matching_handlers
 }
@@ -0,0 +1,19 @@
+mod named_declaration;
+
+use crate::MatchingHandlers;
+
+use self::named_declaration::compute_matching_score_for_named_declaration;
+
+pub fn get_default_javascript_matching_handlers<'a>() -> MatchingHandlers<'a> {
+    let mut matching_handlers: MatchingHandlers<'a> = MatchingHandlers::new();
+    for kind in [
+        "function_declaration",
+        "generator_function_declaration",
+        "class_declaration",
+        "method_definition",
+        "field_definition",
+    ] {
+        matching_handlers.register(kind, compute_matching_score_for_named_declaration);
+    }
+    matching_handlers
+}
@@ -0,0 +1,118 @@
+use model::{cst_node::NonTerminal, CSTNode};
+
+const NAME_KINDS: [&str; 6] = [
+    "identifier",
+    "property_identifier",
+    "private_property_identifier",
+    "computed_property_name",
+    "string",
+    "number",
+];
+
+/// Declarations are identified by their name along with the keywords preceding it, so that a
+/// getter and a setter, or a static and an instance method, with the same name are told apart.
+pub fn compute_matching_score_for_named_declaration<'a>(
+    left: &'a CSTNode,
+    right: &'a CSTNode,
+) -> usize {
+    match (left, right) {
+        (
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_left,
+                ..
+            }),
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_right,
+                ..
+            }),
+        ) => {
+            let key_left = find_key(children_left);
+            let key_right = find_key(children_right);
+
+            (key_left.is_some() && key_left == key_right).into()
+        }
+        (_, _) => 0,
+    }
+}
+
+fn find_key(node_children: &[CSTNode]) -> Option<Vec<String>> {
+    let name_position = node_children
+        .iter()
+        .position(|node| NAME_KINDS.contains(&node.kind()))?;
+
+    Some(
+        node_children[..=name_position]
+            .iter()
+            .map(CSTNode::contents)
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode, Point,
+    };
+
+    use super::compute_matching_score_for_named_declaration;
+
+    fn make_method<'a>(keywords: &[&'a str], name: &'a str, body: &'a str) -> CSTNode<'a> {
+        let make_terminal = |kind, value| {
+            CSTNode::Terminal(Terminal {
+                id: uuid::Uuid::new_v4(),
+                kind,
+                value,
+                start_position: Point { row: 0, column: 0 },
+                end_position: Point { row: 0, column: 1 },
+                is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
+            })
+        };
+
+        let mut children: Vec<CSTNode> = keywords
+            .iter()
+            .map(|keyword| make_terminal(*keyword, *keyword))
+            .collect();
+        children.push(make_terminal("property_identifier", name));
+        children.push(make_terminal("statement_block", body));
+
+        CSTNode::NonTerminal(NonTerminal {
+            id: uuid::Uuid::new_v4(),
+            kind: "method_definition",
+            children,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn methods_with_the_same_name_match_regardless_of_their_body() {
+        let left = make_method(&[], "load", "{ return 1; }");
+        let right = make_method(&[], "load", "{ return 2; }");
+        assert_eq!(
+            1,
+            compute_matching_score_for_named_declaration(&left, &right)
+        );
+    }
+
+    #[test]
+    fn getters_and_setters_with_the_same_name_do_not_match() {
+        let left = make_method(&["get"], "size", "{ return 1; }");
+        let right = make_method(&["set"], "size", "{ return 1; }");
+        assert_eq!(
+            0,
+            compute_matching_score_for_named_declaration(&left, &right)
+        );
+    }
+
+    #[test]
+    fn static_and_instance_methods_with_the_same_name_do_not_match() {
+        let left = make_method(&["static"], "load", "{}");
+        let right = make_method(&[], "load", "{}");
+        assert_eq!(
+            0,
+            compute_matching_score_for_named_declaration(&left, &right)
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use model::{CSTNode, LabelRule};
+
+use crate::MatchingHandlers;
+
+/// Builds the matching handlers of a declarative language definition out of its label rules,
+/// given along with the kind of the grammar each of them applies to.
+pub fn get_matching_handlers_from_label_rules<'a>(
+    rules: impl IntoIterator<Item = (&'static str, LabelRule)>,
+) -> MatchingHandlers<'a> {
+    let mut matching_handlers: MatchingHandlers<'a> = MatchingHandlers::new();
+    for (kind, rule) in rules {
+        matching_handlers.register(kind, move |left, right| {
+            compute_matching_score_for_label_rule(&rule, left, right)
+        });
+    }
+    matching_handlers
+}
+
+fn compute_matching_score_for_label_rule(
+    rule: &LabelRule,
+    left: &CSTNode,
+    right: &CSTNode,
+) -> usize {
+    // Without identity paths, nodes are identified by their whole contents
+    if rule.identity.is_empty() {
+        return (left.contents() == right.contents()).into();
+    }
+
+    let identity_left = extract_identity(left, &rule.identity);
+    let identity_right = extract_identity(right, &rule.identity);
+
+    let has_identity = identity_left.iter().any(|values| !values.is_empty());
+    (has_identity && identity_left == identity_right).into()
+}
+
+fn extract_identity(node: &CSTNode, paths: &[String]) -> Vec<Vec<String>> {
+    paths
+        .iter()
+        .map(|path| {
+            path.split('/')
+                .fold(vec![node], |nodes, kind| {
+                    nodes
+                        .into_iter()
+                        .flat_map(|node| match node {
+                            CSTNode::Terminal(_) => vec![],
+                            CSTNode::NonTerminal(non_terminal) => non_terminal
+                                .children
+                                .iter()
+                                .filter(|child| matches_kind_pattern(child.kind(), kind))
+                                .collect(),
+                        })
+                        .collect()
+                })
+                .into_iter()
+                .map(|node| node.contents())
+                .collect()
+        })
+        .collect()
+}
+
+/// Patterns may list alternatives separated by `|`, each of them containing at most one `*`.
+fn matches_kind_pattern(kind: &str, pattern: &str) -> bool {
+    pattern
+        .split('|')
+        .any(|alternative| match alternative.split_once('*') {
+            Some((prefix, suffix)) => {
+                kind.len() >= prefix.len() + suffix.len()
+                    && kind.starts_with(prefix)
+                    && kind.ends_with(suffix)
+            }
+            None => kind == alternative,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode, LabelRule,
+    };
+
+    use super::compute_matching_score_for_label_rule;
+
+    fn method_rule() -> LabelRule {
+        LabelRule {
+            kind: "method_declaration".into(),
+            identity: vec![
+                "identifier".into(),
+                "formal_parameters/formal_parameter/*".into(),
+            ],
+        }
+    }
+
+    #[test]
+    fn nodes_with_the_same_identity_match() {
+        let rule = LabelRule {
+            kind: "method_declaration".into(),
+            identity: vec![
+                "identifier".into(),
+                "formal_parameters/formal_parameter/integral_type".into(),
+            ],
+        };
+        let left = make_method("sum", vec![("int", "a")]);
+        let right = make_method("sum", vec![("int", "b")]);
+        assert_eq!(
+            1,
+            compute_matching_score_for_label_rule(&rule, &left, &right)
+        );
+    }
+
+    #[test]
+    fn wildcards_reach_children_of_any_kind() {
+        let left = make_method("sum", vec![("int", "a")]);
+        let right = make_method("sum", vec![("int", "b")]);
+        assert_eq!(
+            0,
+            compute_matching_score_for_label_rule(&method_rule(), &left, &right)
+        );
+    }
+
+    #[test]
+    fn kind_patterns_support_wildcards_and_alternatives() {
+        assert!(super::matches_kind_pattern("integral_type", "*"));
+        assert!(super::matches_kind_pattern("integral_type", "*_type"));
+        assert!(!super::matches_kind_pattern("type_identifier", "*_type"));
+        assert!(super::matches_kind_pattern(
+            "type_identifier",
+            "*_type|type_identifier"
+        ));
+    }
+
+    #[test]
+    fn nodes_with_different_identities_do_not_match() {
+        let left = make_method("sum", vec![("int", "a")]);
+        let right = make_method("sum", vec![("int", "a"), ("int", "b")]);
+        assert_eq!(
+            0,
+            compute_matching_score_for_label_rule(&method_rule(), &left, &right)
+        );
+    }
+
+    #[test]
+    fn nodes_without_any_identity_value_do_not_match() {
+        let rule = LabelRule {
+            kind: "method_declaration".into(),
+            identity: vec!["name".into()],
+        };
+        let node = make_method("sum", vec![]);
+        assert_eq!(
+            0,
+            compute_matching_score_for_label_rule(&rule, &node, &node)
+        );
+    }
+
+    #[test]
+    fn rules_without_identity_paths_compare_the_whole_contents() {
+        let rule = LabelRule {
+            kind: "method_declaration".into(),
+            identity: vec![],
+        };
+        let left = make_method("sum", vec![("int", "a")]);
+        let right = make_method("sum", vec![("int", "b")]);
+        assert_eq!(
+            1,
+            compute_matching_score_for_label_rule(&rule, &left, &left)
+        );
+        assert_eq!(
+            0,
+            compute_matching_score_for_label_rule(&rule, &left, &right)
+        );
+    }
+
+    fn make_method<'a>(name: &'a str, parameters: Vec<(&'a str, &'a str)>) -> CSTNode<'a> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "method_declaration",
+            children: vec![
+                CSTNode::Terminal(Terminal {
+                    kind: "identifier",
+                    value: name,
+                    ..Default::default()
+                }),
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "formal_parameters",
+                    children: parameters
+                        .into_iter()
+                        .map(|(parameter_type, parameter_name)| {
+                            CSTNode::NonTerminal(NonTerminal {
+                                kind: "formal_parameter",
+                                children: vec![
+                                    CSTNode::Terminal(Terminal {
+                                        kind: "integral_type",
+                                        value: parameter_type,
+                                        ..Default::default()
+                                    }),
+                                    CSTNode::Terminal(Terminal {
+                                        kind: "identifier",
+                                        value: parameter_name,
+                                        ..Default::default()
+                                    }),
+                                ],
+                                ..Default::default()
+                            })
+                        })
+                        .collect(),
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -1,14 +1,35 @@
+mod identities;
 mod java;
+mod javascript;
+mod label_rules;
+mod php;
+mod protobuf;
+mod scala;
+mod swift;
+mod toml;
+mod utils;
 
-use std::collections::HashMap;
+use std::{collections::HashMap, marker::PhantomData};
 
-use java::get_default_java_matching_handlers;
-use model::{CSTNode, Language};
+use model::CSTNode;
 
-type MatchingHandler<'a> = fn(left: &'a CSTNode<'a>, right: &'a CSTNode<'a>) -> usize;
+pub use java::{get_default_java_matching_handlers, DEFAULT_JAVA_KINDS_WITH_LABEL};
+pub use javascript::get_default_javascript_matching_handlers;
+pub use label_rules::get_matching_handlers_from_label_rules;
+pub use php::get_default_php_matching_handlers;
+pub use protobuf::get_default_protobuf_matching_handlers;
+pub use scala::get_default_scala_matching_handlers;
+pub use swift::get_default_swift_matching_handlers;
+pub use toml::{
+    get_default_toml_matching_handlers, get_toml_matching_handlers,
+    DEFAULT_TABLE_ARRAY_IDENTITY_KEYS,
+};
+
+type MatchingHandler = Box<dyn for<'b> Fn(&'b CSTNode<'b>, &'b CSTNode<'b>) -> usize>;
 
 pub struct MatchingHandlers<'a> {
-    matching_handlers: HashMap<&'static str, MatchingHandler<'a>>,
+    matching_handlers: HashMap<&'static str, MatchingHandler>,
+    phantom: PhantomData<&'a ()>,
 }
 
 impl<'a> Default for MatchingHandlers<'a> {
@@ -21,11 +42,20 @@ impl<'a> MatchingHandlers<'a> {
     pub fn new() -> Self {
         Self {
             matching_handlers: HashMap::new(),
+            phantom: PhantomData,
         }
     }
 
-    pub fn register(&mut self, key: &'static str, value: MatchingHandler<'a>) {
-        self.matching_handlers.insert(key, value);
+    pub fn register(
+        &mut self,
+        key: &'static str,
+        value: impl for<'b> Fn(&'b CSTNode<'b>, &'b CSTNode<'b>) -> usize + 'static,
+    ) {
+        self.matching_handlers.insert(key, Box::new(value));
+    }
+
+    pub fn has_handler(&self, kind: &str) -> bool {
+        self.matching_handlers.contains_key(kind)
     }
 
     pub fn compute_matching_score(
@@ -41,11 +71,3 @@ impl<'a> MatchingHandlers<'a> {
         }
     }
 }
-
-impl From<Language> for MatchingHandlers<'_> {
-    fn from(language: Language) -> Self {
-        match language {
-            Language::Java => get_default_java_matching_handlers(),
-        }
-    }
-}
@@ -0,0 +1,96 @@
+use crate::utils::find_child_of_kind;
+use model::{cst_node::NonTerminal, CSTNode};
+
+fn find_const_name<'a>(node_children: &'a [CSTNode<'a>]) -> Option<String> {
+    match find_child_of_kind(node_children, "const_element")? {
+        CSTNode::NonTerminal(const_element) => {
+            find_child_of_kind(&const_element.children, "name").map(|node| node.contents())
+        }
+        CSTNode::Terminal(_) => None,
+    }
+}
+
+pub fn compute_matching_score_for_const_declaration<'a>(
+    left: &'a CSTNode,
+    right: &'a CSTNode,
+) -> usize {
+    match (left, right) {
+        (
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_left,
+                ..
+            }),
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_right,
+                ..
+            }),
+        ) => {
+            let identifier_left = find_const_name(children_left);
+            let identifier_right = find_const_name(children_right);
+
+            (identifier_left.is_some() && identifier_left == identifier_right).into()
+        }
+        (_, _) => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    #[test]
+    fn it_returns_one_if_constants_have_the_same_name() {
+        let result = super::compute_matching_score_for_const_declaration(
+            &make_const_declaration("MAX", "10"),
+            &make_const_declaration("MAX", "20"),
+        );
+        assert_eq!(1, result);
+    }
+
+    #[test]
+    fn it_returns_zero_if_constants_have_different_names() {
+        let result = super::compute_matching_score_for_const_declaration(
+            &make_const_declaration("MAX", "10"),
+            &make_const_declaration("MIN", "10"),
+        );
+        assert_eq!(0, result);
+    }
+
+    fn make_const_declaration<'a>(name: &'a str, value: &'a str) -> CSTNode<'a> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "const_declaration",
+            children: vec![
+                CSTNode::Terminal(Terminal {
+                    kind: "const",
+                    value: "const",
+                    ..Default::default()
+                }),
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "const_element",
+                    children: vec![
+                        CSTNode::Terminal(Terminal {
+                            kind: "name",
+                            value: name,
+                            ..Default::default()
+                        }),
+                        CSTNode::Terminal(Terminal {
+                            kind: "=",
+                            value: "=",
+                            ..Default::default()
+                        }),
+                        CSTNode::Terminal(Terminal {
+                            kind: "integer",
+                            value,
+                            ..Default::default()
+                        }),
+                    ],
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        })
+    }
+}
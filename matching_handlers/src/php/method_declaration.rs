@@ -0,0 +1,94 @@
+use crate::utils::find_child_of_kind;
+use model::{cst_node::NonTerminal, CSTNode};
+
+pub fn compute_matching_score_for_method_declaration<'a>(
+    left: &'a CSTNode,
+    right: &'a CSTNode,
+) -> usize {
+    match (left, right) {
+        (
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_left,
+                ..
+            }),
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_right,
+                ..
+            }),
+        ) => {
+            // PHP has no method overloading, so the name alone identifies a method
+            let identifier_left =
+                find_child_of_kind(children_left, "name").map(|node| node.contents());
+            let identifier_right =
+                find_child_of_kind(children_right, "name").map(|node| node.contents());
+
+            (identifier_left.is_some() && identifier_left == identifier_right).into()
+        }
+        (_, _) => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    #[test]
+    fn it_returns_one_if_methods_have_the_same_name() {
+        let result = super::compute_matching_score_for_method_declaration(
+            &make_method_declaration("find", "int"),
+            &make_method_declaration("find", "string"),
+        );
+        assert_eq!(1, result);
+    }
+
+    #[test]
+    fn it_returns_zero_if_methods_have_different_names() {
+        let result = super::compute_matching_score_for_method_declaration(
+            &make_method_declaration("find", "int"),
+            &make_method_declaration("findAll", "int"),
+        );
+        assert_eq!(0, result);
+    }
+
+    fn make_method_declaration<'a>(name: &'a str, parameter_type: &'a str) -> CSTNode<'a> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "method_declaration",
+            children: vec![
+                CSTNode::Terminal(Terminal {
+                    kind: "function",
+                    value: "function",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "name",
+                    value: name,
+                    ..Default::default()
+                }),
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "formal_parameters",
+                    children: vec![CSTNode::NonTerminal(NonTerminal {
+                        kind: "simple_parameter",
+                        children: vec![
+                            CSTNode::Terminal(Terminal {
+                                kind: "primitive_type",
+                                value: parameter_type,
+                                ..Default::default()
+                            }),
+                            CSTNode::Terminal(Terminal {
+                                kind: "variable_name",
+                                value: "$id",
+                                ..Default::default()
+                            }),
+                        ],
+                        ..Default::default()
+                    })],
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        })
+    }
+}
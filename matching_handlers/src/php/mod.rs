@@ -0,0 +1,56 @@
+mod class_like_declaration;
+mod const_declaration;
+mod method_declaration;
+mod namespace_use_declaration;
+mod property_declaration;
+
+use crate::MatchingHandlers;
+
+use self::{
+    class_like_declaration::compute_matching_score_for_class_like_declaration,
+    const_declaration::compute_matching_score_for_const_declaration,
+    method_declaration::compute_matching_score_for_method_declaration,
+    namespace_use_declaration::compute_matching_score_for_namespace_use_declaration,
+    property_declaration::compute_matching_score_for_property_declaration,
+};
+
+pub fn get_default_php_matching_handlers<'a>() -> MatchingHandlers<'a> {
+    let mut matching_handlers: MatchingHandlers<'a> = MatchingHandlers::new();
+    matching_handlers.register(
+        "method_declaration",
+        compute_matching_score_for_method_declaration,
+    );
+    matching_handlers.register(
+        "property_declaration",
+        compute_matching_score_for_property_declaration,
+    );
+    matching_handlers.register(
+        "const_declaration",
+        compute_matching_score_for_const_declaration,
+    );
+    matching_handlers.register(
+        "namespace_use_declaration",
+        compute_matching_score_for_namespace_use_declaration,
+    );
+    matching_handlers.register(
+        "use_declaration",
+        compute_matching_score_for_namespace_use_declaration,
+    );
+    matching_handlers.register(
+        "class_declaration",
+        compute_matching_score_for_class_like_declaration,
+    );
+    matching_handlers.register(
+        "interface_declaration",
+        compute_matching_score_for_class_like_declaration,
+    );
+    matching_handlers.register(
+        "trait_declaration",
+        compute_matching_score_for_class_like_declaration,
+    );
+    matching_handlers.register(
+        "enum_declaration",
+        compute_matching_score_for_class_like_declaration,
+    );
+    matching_handlers
+}
@@ -0,0 +1,48 @@
+use model::CSTNode;
+
+pub fn compute_matching_score_for_namespace_use_declaration<'a>(
+    left: &'a CSTNode<'a>,
+    right: &'a CSTNode<'a>,
+) -> usize {
+    (left.contents() == right.contents()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn uses_of_the_same_name_matches_with_one() {
+        let result = super::compute_matching_score_for_namespace_use_declaration(
+            &make_use_of("App\\Models\\User"),
+            &make_use_of("App\\Models\\User"),
+        );
+        assert_eq!(1, result);
+    }
+
+    #[test]
+    fn uses_of_different_names_matches_with_zero() {
+        let result = super::compute_matching_score_for_namespace_use_declaration(
+            &make_use_of("App\\Models\\User"),
+            &make_use_of("App\\Models\\Post"),
+        );
+        assert_eq!(0, result);
+    }
+
+    fn make_use_of(name: &str) -> model::CSTNode<'_> {
+        model::CSTNode::NonTerminal(model::cst_node::NonTerminal {
+            kind: "namespace_use_declaration",
+            children: vec![
+                model::CSTNode::Terminal(model::cst_node::Terminal {
+                    kind: "use",
+                    value: "use",
+                    ..Default::default()
+                }),
+                model::CSTNode::Terminal(model::cst_node::Terminal {
+                    kind: "qualified_name",
+                    value: name,
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        })
+    }
+}
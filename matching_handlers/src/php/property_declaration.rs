@@ -0,0 +1,101 @@
+use crate::utils::find_child_of_kind;
+use model::{cst_node::NonTerminal, CSTNode};
+
+fn find_property_name<'a>(node_children: &'a [CSTNode<'a>]) -> Option<String> {
+    match find_child_of_kind(node_children, "property_element")? {
+        CSTNode::NonTerminal(property_element) => {
+            find_child_of_kind(&property_element.children, "variable_name")
+                .map(|node| node.contents())
+        }
+        CSTNode::Terminal(_) => None,
+    }
+}
+
+pub fn compute_matching_score_for_property_declaration<'a>(
+    left: &'a CSTNode,
+    right: &'a CSTNode,
+) -> usize {
+    match (left, right) {
+        (
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_left,
+                ..
+            }),
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_right,
+                ..
+            }),
+        ) => {
+            let identifier_left = find_property_name(children_left);
+            let identifier_right = find_property_name(children_right);
+
+            (identifier_left.is_some() && identifier_left == identifier_right).into()
+        }
+        (_, _) => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    #[test]
+    fn it_returns_one_if_properties_have_the_same_name() {
+        let result = super::compute_matching_score_for_property_declaration(
+            &make_property_declaration("private", "repo"),
+            &make_property_declaration("protected", "repo"),
+        );
+        assert_eq!(1, result);
+    }
+
+    #[test]
+    fn it_returns_zero_if_properties_have_different_names() {
+        let result = super::compute_matching_score_for_property_declaration(
+            &make_property_declaration("private", "repo"),
+            &make_property_declaration("private", "cache"),
+        );
+        assert_eq!(0, result);
+    }
+
+    fn make_property_declaration<'a>(visibility: &'a str, name: &'a str) -> CSTNode<'a> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "property_declaration",
+            children: vec![
+                CSTNode::Terminal(Terminal {
+                    kind: "visibility_modifier",
+                    value: visibility,
+                    ..Default::default()
+                }),
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "property_element",
+                    children: vec![CSTNode::NonTerminal(NonTerminal {
+                        kind: "variable_name",
+                        children: vec![
+                            CSTNode::Terminal(Terminal {
+                                kind: "$",
+                                value: "$",
+                                ..Default::default()
+                            }),
+                            CSTNode::Terminal(Terminal {
+                                kind: "name",
+                                value: name,
+                                ..Default::default()
+                            }),
+                        ],
+                        ..Default::default()
+                    })],
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: ";",
+                    value: ";",
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        })
+    }
+}
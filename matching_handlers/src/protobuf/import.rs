@@ -0,0 +1,48 @@
+use model::CSTNode;
+
+pub fn compute_matching_score_for_import<'a>(
+    left: &'a CSTNode<'a>,
+    right: &'a CSTNode<'a>,
+) -> usize {
+    (left.contents() == right.contents()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn imports_of_the_same_resource_matches_with_one() {
+        let result = super::compute_matching_score_for_import(
+            &make_import_of_resource("google/protobuf/timestamp.proto"),
+            &make_import_of_resource("google/protobuf/timestamp.proto"),
+        );
+        assert_eq!(1, result);
+    }
+
+    #[test]
+    fn imports_of_different_resources_matches_with_zero() {
+        let result = super::compute_matching_score_for_import(
+            &make_import_of_resource("google/protobuf/timestamp.proto"),
+            &make_import_of_resource("google/protobuf/duration.proto"),
+        );
+        assert_eq!(0, result);
+    }
+
+    fn make_import_of_resource(resource: &str) -> model::CSTNode<'_> {
+        model::CSTNode::NonTerminal(model::cst_node::NonTerminal {
+            kind: "import",
+            children: vec![
+                model::CSTNode::Terminal(model::cst_node::Terminal {
+                    kind: "import",
+                    value: "import",
+                    ..Default::default()
+                }),
+                model::CSTNode::Terminal(model::cst_node::Terminal {
+                    kind: "string",
+                    value: resource,
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        })
+    }
+}
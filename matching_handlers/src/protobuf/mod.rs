@@ -0,0 +1,28 @@
+mod import;
+mod named_declaration;
+
+use crate::MatchingHandlers;
+
+use self::{
+    import::compute_matching_score_for_import,
+    named_declaration::compute_matching_score_for_named_declaration,
+};
+
+pub fn get_default_protobuf_matching_handlers<'a>() -> MatchingHandlers<'a> {
+    let mut matching_handlers: MatchingHandlers<'a> = MatchingHandlers::new();
+    matching_handlers.register("import", compute_matching_score_for_import);
+    for kind in [
+        "field",
+        "map_field",
+        "oneof",
+        "oneof_field",
+        "enum_field",
+        "message",
+        "enum",
+        "service",
+        "rpc",
+    ] {
+        matching_handlers.register(kind, compute_matching_score_for_named_declaration);
+    }
+    matching_handlers
+}
@@ -0,0 +1,139 @@
+use model::{cst_node::NonTerminal, CSTNode};
+
+const NAME_KINDS: [&str; 5] = [
+    "identifier",
+    "message_name",
+    "enum_name",
+    "service_name",
+    "rpc_name",
+];
+
+/// Fields, messages, enums, services and rpcs are identified by their name only: two fields with
+/// the same name but different numbers must be matched, so that the change of number is noticed.
+pub fn compute_matching_score_for_named_declaration<'a>(
+    left: &'a CSTNode,
+    right: &'a CSTNode,
+) -> usize {
+    match (left, right) {
+        (
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_left,
+                ..
+            }),
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_right,
+                ..
+            }),
+        ) => {
+            let name_left = find_name(children_left);
+            let name_right = find_name(children_right);
+
+            (name_left.is_some() && name_left == name_right).into()
+        }
+        (_, _) => 0,
+    }
+}
+
+fn find_name(node_children: &[CSTNode]) -> Option<String> {
+    node_children
+        .iter()
+        .find(|node| NAME_KINDS.contains(&node.kind()))
+        .map(|node| node.contents())
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    use super::compute_matching_score_for_named_declaration;
+
+    #[test]
+    fn fields_with_the_same_name_match_regardless_of_their_number() {
+        let left = make_field("id", "1");
+        let right = make_field("id", "2");
+        assert_eq!(
+            1,
+            compute_matching_score_for_named_declaration(&left, &right)
+        );
+    }
+
+    #[test]
+    fn fields_with_different_names_do_not_match() {
+        let left = make_field("id", "1");
+        let right = make_field("uuid", "1");
+        assert_eq!(
+            0,
+            compute_matching_score_for_named_declaration(&left, &right)
+        );
+    }
+
+    #[test]
+    fn messages_are_matched_by_their_name() {
+        let left = make_message("Order");
+        let right = make_message("Order");
+        assert_eq!(
+            1,
+            compute_matching_score_for_named_declaration(&left, &right)
+        );
+        let other = make_message("Item");
+        assert_eq!(
+            0,
+            compute_matching_score_for_named_declaration(&left, &other)
+        );
+    }
+
+    fn make_field<'a>(name: &'a str, number: &'a str) -> CSTNode<'a> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "field",
+            children: vec![
+                CSTNode::Terminal(Terminal {
+                    kind: "type",
+                    value: "string",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "identifier",
+                    value: name,
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "=",
+                    value: "=",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "field_number",
+                    value: number,
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        })
+    }
+
+    fn make_message(name: &str) -> CSTNode<'_> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "message",
+            children: vec![
+                CSTNode::Terminal(Terminal {
+                    kind: "message",
+                    value: "message",
+                    ..Default::default()
+                }),
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "message_name",
+                    children: vec![CSTNode::Terminal(Terminal {
+                        kind: "identifier",
+                        value: name,
+                        ..Default::default()
+                    })],
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,189 @@
+use crate::utils::find_child_of_kind;
+use model::{cst_node::NonTerminal, CSTNode};
+
+pub fn compute_matching_score_for_function_definition<'a>(
+    left: &'a CSTNode,
+    right: &'a CSTNode,
+) -> usize {
+    match (left, right) {
+        (
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_left,
+                ..
+            }),
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_right,
+                ..
+            }),
+        ) => {
+            // Try to find an identifier on children, and compare them
+            let identifier_left =
+                find_child_of_kind(children_left, "identifier").map(|node| node.contents());
+            let identifier_right =
+                find_child_of_kind(children_right, "identifier").map(|node| node.contents());
+
+            // We also need to take parameter types into account because of overloading.
+            // Curried functions have one parameter list per clause, so all of them are used.
+            let type_of_left_arguments = extract_argument_types_from_parameter_lists(children_left);
+            let type_of_right_arguments =
+                extract_argument_types_from_parameter_lists(children_right);
+
+            let identifiers_are_equal =
+                identifier_left.is_some() && identifier_left == identifier_right;
+            let arguments_are_equal = type_of_left_arguments == type_of_right_arguments;
+
+            (identifiers_are_equal && arguments_are_equal).into()
+        }
+        (_, _) => 0,
+    }
+}
+
+fn extract_argument_types_from_parameter_lists(node_children: &[CSTNode]) -> Vec<Vec<String>> {
+    node_children
+        .iter()
+        .filter(|node| node.kind() == "parameters")
+        .map(extract_argument_types_from_parameters)
+        .collect()
+}
+
+fn extract_argument_types_from_parameters(node: &CSTNode) -> Vec<String> {
+    match node {
+        CSTNode::Terminal(_) => vec![],
+        CSTNode::NonTerminal(non_terminal) => non_terminal
+            .children
+            .iter()
+            .filter(|inner_node| inner_node.kind() == "parameter")
+            .filter_map(|inner_node| match inner_node {
+                CSTNode::Terminal(_) => None,
+                CSTNode::NonTerminal(non_terminal) => Some(
+                    non_terminal
+                        .children
+                        .iter()
+                        // Default values are not part of the signature
+                        .take_while(|node| node.kind() != "=")
+                        .filter(|node| node.kind() != "modifiers" && node.kind() != "identifier")
+                        .fold(String::new(), |acc, cur| {
+                            format!("{} {}", acc, cur.contents())
+                        }),
+                ),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    use super::compute_matching_score_for_function_definition;
+
+    #[test]
+    fn it_returns_one_if_functions_have_the_same_name_and_parameter_types() {
+        let left = make_function_definition("run", vec![vec![("df", "DataFrame")]]);
+        let right = make_function_definition("run", vec![vec![("input", "DataFrame")]]);
+        assert_eq!(
+            1,
+            compute_matching_score_for_function_definition(&left, &right)
+        );
+    }
+
+    #[test]
+    fn it_returns_zero_if_functions_have_different_names() {
+        let left = make_function_definition("run", vec![vec![("df", "DataFrame")]]);
+        let right = make_function_definition("stop", vec![vec![("df", "DataFrame")]]);
+        assert_eq!(
+            0,
+            compute_matching_score_for_function_definition(&left, &right)
+        );
+    }
+
+    #[test]
+    fn it_returns_zero_for_overloads_with_different_parameter_types() {
+        let left = make_function_definition("run", vec![vec![("df", "DataFrame")]]);
+        let right = make_function_definition("run", vec![vec![("df", "DataFrame"), ("n", "Int")]]);
+        assert_eq!(
+            0,
+            compute_matching_score_for_function_definition(&left, &right)
+        );
+    }
+
+    #[test]
+    fn it_takes_every_parameter_clause_of_curried_functions_into_account() {
+        let left = make_function_definition("fold", vec![vec![("z", "Int")], vec![("f", "Op")]]);
+        let right = make_function_definition("fold", vec![vec![("z", "Int"), ("f", "Op")]]);
+        assert_eq!(
+            0,
+            compute_matching_score_for_function_definition(&left, &right)
+        );
+    }
+
+    #[test]
+    fn parameterless_functions_match_by_name() {
+        let left = make_function_definition("size", vec![]);
+        let right = make_function_definition("size", vec![]);
+        assert_eq!(
+            1,
+            compute_matching_score_for_function_definition(&left, &right)
+        );
+    }
+
+    fn make_function_definition<'a>(
+        identifier: &'a str,
+        parameter_clauses: Vec<Vec<(&'a str, &'a str)>>,
+    ) -> CSTNode<'a> {
+        let mut children = vec![
+            CSTNode::Terminal(Terminal {
+                kind: "def",
+                value: "def",
+                ..Default::default()
+            }),
+            CSTNode::Terminal(Terminal {
+                kind: "identifier",
+                value: identifier,
+                ..Default::default()
+            }),
+        ];
+
+        children.extend(parameter_clauses.into_iter().map(|parameters| {
+            CSTNode::NonTerminal(NonTerminal {
+                kind: "parameters",
+                children: parameters
+                    .into_iter()
+                    .map(|(name, parameter_type)| {
+                        CSTNode::NonTerminal(NonTerminal {
+                            kind: "parameter",
+                            children: vec![
+                                CSTNode::Terminal(Terminal {
+                                    kind: "identifier",
+                                    value: name,
+                                    ..Default::default()
+                                }),
+                                CSTNode::Terminal(Terminal {
+                                    kind: ":",
+                                    value: ":",
+                                    ..Default::default()
+                                }),
+                                CSTNode::Terminal(Terminal {
+                                    kind: "type_identifier",
+                                    value: parameter_type,
+                                    ..Default::default()
+                                }),
+                            ],
+                            ..Default::default()
+                        })
+                    })
+                    .collect(),
+                ..Default::default()
+            })
+        }));
+
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "function_definition",
+            children,
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,46 @@
+use model::CSTNode;
+
+pub fn compute_matching_score_for_import_declaration<'a>(
+    left: &'a CSTNode<'a>,
+    right: &'a CSTNode<'a>,
+) -> usize {
+    (left.contents() == right.contents()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn imports_of_the_same_resource_matches_with_one() {
+        let result = super::compute_matching_score_for_import_declaration(
+            &make_import_of_resource("scala.collection.mutable"),
+            &make_import_of_resource("scala.collection.mutable"),
+        );
+        assert_eq!(1, result);
+    }
+
+    #[test]
+    fn imports_of_different_resources_matches_with_zero() {
+        let result = super::compute_matching_score_for_import_declaration(
+            &make_import_of_resource("scala.collection.mutable"),
+            &make_import_of_resource("scala.collection.immutable"),
+        );
+        assert_eq!(0, result);
+    }
+
+    fn make_import_of_resource(resource: &str) -> model::CSTNode<'_> {
+        model::CSTNode::NonTerminal(model::cst_node::NonTerminal {
+            kind: "import_declaration",
+            children: resource
+                .split('.')
+                .map(|part| {
+                    model::CSTNode::Terminal(model::cst_node::Terminal {
+                        kind: "identifier",
+                        value: part,
+                        ..Default::default()
+                    })
+                })
+                .collect(),
+            ..Default::default()
+        })
+    }
+}
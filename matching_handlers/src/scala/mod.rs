@@ -0,0 +1,58 @@
+mod function_definition;
+mod import_declaration;
+mod template_definition;
+mod value_definition;
+
+use crate::MatchingHandlers;
+
+use self::{
+    function_definition::compute_matching_score_for_function_definition,
+    import_declaration::compute_matching_score_for_import_declaration,
+    template_definition::compute_matching_score_for_template_definition,
+    value_definition::compute_matching_score_for_value_definition,
+};
+
+pub fn get_default_scala_matching_handlers<'a>() -> MatchingHandlers<'a> {
+    let mut matching_handlers: MatchingHandlers<'a> = MatchingHandlers::new();
+    matching_handlers.register(
+        "function_definition",
+        compute_matching_score_for_function_definition,
+    );
+    matching_handlers.register(
+        "function_declaration",
+        compute_matching_score_for_function_definition,
+    );
+    matching_handlers.register(
+        "val_definition",
+        compute_matching_score_for_value_definition,
+    );
+    matching_handlers.register(
+        "var_definition",
+        compute_matching_score_for_value_definition,
+    );
+    matching_handlers.register(
+        "val_declaration",
+        compute_matching_score_for_value_definition,
+    );
+    matching_handlers.register(
+        "var_declaration",
+        compute_matching_score_for_value_definition,
+    );
+    matching_handlers.register(
+        "import_declaration",
+        compute_matching_score_for_import_declaration,
+    );
+    matching_handlers.register(
+        "class_definition",
+        compute_matching_score_for_template_definition,
+    );
+    matching_handlers.register(
+        "object_definition",
+        compute_matching_score_for_template_definition,
+    );
+    matching_handlers.register(
+        "trait_definition",
+        compute_matching_score_for_template_definition,
+    );
+    matching_handlers
+}
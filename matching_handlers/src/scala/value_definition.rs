@@ -0,0 +1,88 @@
+use model::{cst_node::NonTerminal, CSTNode};
+
+fn find_defined_names<'a>(node_children: &'a [CSTNode<'a>]) -> Option<String> {
+    // `val a, b = 1` puts the names under an `identifiers` node
+    node_children
+        .iter()
+        .find(|node| node.kind() == "identifier" || node.kind() == "identifiers")
+        .map(|node| node.contents())
+}
+
+pub fn compute_matching_score_for_value_definition<'a>(
+    left: &'a CSTNode,
+    right: &'a CSTNode,
+) -> usize {
+    match (left, right) {
+        (
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_left,
+                ..
+            }),
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_right,
+                ..
+            }),
+        ) => {
+            let identifier_left = find_defined_names(children_left);
+            let identifier_right = find_defined_names(children_right);
+
+            (identifier_left.is_some() && identifier_left == identifier_right).into()
+        }
+        (_, _) => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    #[test]
+    fn it_returns_one_if_values_have_the_same_name() {
+        let result = super::compute_matching_score_for_value_definition(
+            &make_val_definition("name", "\"a\""),
+            &make_val_definition("name", "\"b\""),
+        );
+        assert_eq!(1, result);
+    }
+
+    #[test]
+    fn it_returns_zero_if_values_have_different_names() {
+        let result = super::compute_matching_score_for_value_definition(
+            &make_val_definition("name", "\"a\""),
+            &make_val_definition("title", "\"a\""),
+        );
+        assert_eq!(0, result);
+    }
+
+    fn make_val_definition<'a>(identifier: &'a str, value: &'a str) -> CSTNode<'a> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "val_definition",
+            children: vec![
+                CSTNode::Terminal(Terminal {
+                    kind: "val",
+                    value: "val",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "identifier",
+                    value: identifier,
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "=",
+                    value: "=",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "string",
+                    value,
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,170 @@
+use crate::utils::find_child_of_kind;
+use model::{cst_node::NonTerminal, CSTNode};
+
+pub fn compute_matching_score_for_function_declaration<'a>(
+    left: &'a CSTNode,
+    right: &'a CSTNode,
+) -> usize {
+    match (left, right) {
+        (
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_left,
+                ..
+            }),
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_right,
+                ..
+            }),
+        ) => {
+            let selector_left = extract_selector(children_left);
+            let selector_right = extract_selector(children_right);
+
+            (selector_left.is_some() && selector_left == selector_right).into()
+        }
+        (_, _) => 0,
+    }
+}
+
+/// Swift functions are identified by their full selector, e.g. `move(from:to:)`,
+/// so overloads that only differ on argument labels are told apart.
+fn extract_selector(node_children: &[CSTNode]) -> Option<String> {
+    // Initializers and subscripts have a keyword instead of a name
+    let name = find_child_of_kind(node_children, "simple_identifier")
+        .or_else(|| find_child_of_kind(node_children, "init"))
+        .or_else(|| find_child_of_kind(node_children, "subscript"))?
+        .contents();
+
+    let argument_labels = node_children
+        .iter()
+        .filter(|node| node.kind() == "parameter")
+        .filter_map(get_argument_label)
+        .map(|label| format!("{}:", label))
+        .collect::<String>();
+
+    Some(format!("{}({})", name, argument_labels))
+}
+
+/// The argument label of a parameter is the first of its identifiers: the external name, which
+/// is `_` for parameters without label, or the name of the parameter if it has a single one.
+/// The internal name of labelled parameters is left out, so renaming it keeps the selector.
+fn get_argument_label(parameter: &CSTNode) -> Option<String> {
+    match parameter {
+        CSTNode::Terminal(_) => None,
+        CSTNode::NonTerminal(non_terminal) => {
+            find_child_of_kind(&non_terminal.children, "simple_identifier").map(CSTNode::contents)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    use super::compute_matching_score_for_function_declaration;
+
+    #[test]
+    fn it_returns_one_if_functions_have_the_same_selector() {
+        let left = make_function_declaration("move", vec![vec!["from", "a"], vec!["to", "b"]]);
+        let right = make_function_declaration("move", vec![vec!["from", "x"], vec!["to", "y"]]);
+        assert_eq!(
+            1,
+            compute_matching_score_for_function_declaration(&left, &right)
+        );
+    }
+
+    #[test]
+    fn it_returns_zero_if_functions_have_different_names() {
+        let left = make_function_declaration("move", vec![vec!["to", "b"]]);
+        let right = make_function_declaration("jump", vec![vec!["to", "b"]]);
+        assert_eq!(
+            0,
+            compute_matching_score_for_function_declaration(&left, &right)
+        );
+    }
+
+    #[test]
+    fn it_returns_zero_for_overloads_with_different_argument_labels() {
+        let left = make_function_declaration("move", vec![vec!["to", "b"]]);
+        let right = make_function_declaration("move", vec![vec!["by", "b"]]);
+        assert_eq!(
+            0,
+            compute_matching_score_for_function_declaration(&left, &right)
+        );
+    }
+
+    #[test]
+    fn parameters_without_argument_label_use_their_name_as_label() {
+        let left = make_function_declaration("greet", vec![vec!["name"]]);
+        let right = make_function_declaration("greet", vec![vec!["_", "name"]]);
+        assert_eq!(
+            0,
+            compute_matching_score_for_function_declaration(&left, &right)
+        );
+    }
+
+    #[test]
+    fn parameters_without_argument_label_are_matched_whatever_their_name() {
+        let left = make_function_declaration("greet", vec![vec!["_", "name"]]);
+        let right = make_function_declaration("greet", vec![vec!["_", "person"]]);
+        assert_eq!(
+            1,
+            compute_matching_score_for_function_declaration(&left, &right)
+        );
+    }
+
+    fn make_function_declaration<'a>(
+        identifier: &'a str,
+        parameters: Vec<Vec<&'a str>>,
+    ) -> CSTNode<'a> {
+        let mut children = vec![
+            CSTNode::Terminal(Terminal {
+                kind: "func",
+                value: "func",
+                ..Default::default()
+            }),
+            CSTNode::Terminal(Terminal {
+                kind: "simple_identifier",
+                value: identifier,
+                ..Default::default()
+            }),
+        ];
+
+        children.extend(parameters.into_iter().map(|identifiers| {
+            let mut parameter_children: Vec<CSTNode> = identifiers
+                .into_iter()
+                .map(|identifier| {
+                    CSTNode::Terminal(Terminal {
+                        kind: "simple_identifier",
+                        value: identifier,
+                        ..Default::default()
+                    })
+                })
+                .collect();
+            parameter_children.push(CSTNode::Terminal(Terminal {
+                kind: ":",
+                value: ":",
+                ..Default::default()
+            }));
+            parameter_children.push(CSTNode::Terminal(Terminal {
+                kind: "type_identifier",
+                value: "Int",
+                ..Default::default()
+            }));
+
+            CSTNode::NonTerminal(NonTerminal {
+                kind: "parameter",
+                children: parameter_children,
+                ..Default::default()
+            })
+        }));
+
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "function_declaration",
+            children,
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,58 @@
+mod function_declaration;
+mod import_declaration;
+mod property_declaration;
+mod type_declaration;
+
+use crate::MatchingHandlers;
+
+use self::{
+    function_declaration::compute_matching_score_for_function_declaration,
+    import_declaration::compute_matching_score_for_import_declaration,
+    property_declaration::compute_matching_score_for_property_declaration,
+    type_declaration::compute_matching_score_for_type_declaration,
+};
+
+pub fn get_default_swift_matching_handlers<'a>() -> MatchingHandlers<'a> {
+    let mut matching_handlers: MatchingHandlers<'a> = MatchingHandlers::new();
+    matching_handlers.register(
+        "function_declaration",
+        compute_matching_score_for_function_declaration,
+    );
+    matching_handlers.register(
+        "protocol_function_declaration",
+        compute_matching_score_for_function_declaration,
+    );
+    matching_handlers.register(
+        "init_declaration",
+        compute_matching_score_for_function_declaration,
+    );
+    matching_handlers.register(
+        "subscript_declaration",
+        compute_matching_score_for_function_declaration,
+    );
+    matching_handlers.register(
+        "property_declaration",
+        compute_matching_score_for_property_declaration,
+    );
+    matching_handlers.register(
+        "protocol_property_declaration",
+        compute_matching_score_for_property_declaration,
+    );
+    matching_handlers.register(
+        "import_declaration",
+        compute_matching_score_for_import_declaration,
+    );
+    matching_handlers.register(
+        "class_declaration",
+        compute_matching_score_for_type_declaration,
+    );
+    matching_handlers.register(
+        "protocol_declaration",
+        compute_matching_score_for_type_declaration,
+    );
+    matching_handlers.register(
+        "typealias_declaration",
+        compute_matching_score_for_type_declaration,
+    );
+    matching_handlers
+}
@@ -0,0 +1,85 @@
+use crate::utils::find_child_of_kind;
+use model::{cst_node::NonTerminal, CSTNode};
+
+pub fn compute_matching_score_for_property_declaration<'a>(
+    left: &'a CSTNode,
+    right: &'a CSTNode,
+) -> usize {
+    match (left, right) {
+        (
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_left,
+                ..
+            }),
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_right,
+                ..
+            }),
+        ) => {
+            let pattern_left =
+                find_child_of_kind(children_left, "pattern").map(|node| node.contents());
+            let pattern_right =
+                find_child_of_kind(children_right, "pattern").map(|node| node.contents());
+
+            (pattern_left.is_some() && pattern_left == pattern_right).into()
+        }
+        (_, _) => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    use super::compute_matching_score_for_property_declaration;
+
+    #[test]
+    fn it_returns_one_if_properties_bind_the_same_name() {
+        let left = make_property_declaration("var", "prefix");
+        let right = make_property_declaration("let", "prefix");
+        assert_eq!(
+            1,
+            compute_matching_score_for_property_declaration(&left, &right)
+        );
+    }
+
+    #[test]
+    fn it_returns_zero_if_properties_bind_different_names() {
+        let left = make_property_declaration("var", "prefix");
+        let right = make_property_declaration("var", "suffix");
+        assert_eq!(
+            0,
+            compute_matching_score_for_property_declaration(&left, &right)
+        );
+    }
+
+    fn make_property_declaration<'a>(mutability: &'a str, name: &'a str) -> CSTNode<'a> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "property_declaration",
+            children: vec![
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "value_binding_pattern",
+                    children: vec![CSTNode::Terminal(Terminal {
+                        kind: mutability,
+                        value: mutability,
+                        ..Default::default()
+                    })],
+                    ..Default::default()
+                }),
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "pattern",
+                    children: vec![CSTNode::Terminal(Terminal {
+                        kind: "simple_identifier",
+                        value: name,
+                        ..Default::default()
+                    })],
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        })
+    }
+}
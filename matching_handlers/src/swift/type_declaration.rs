@@ -0,0 +1,129 @@
+use model::{cst_node::NonTerminal, CSTNode};
+
+pub fn compute_matching_score_for_type_declaration<'a>(
+    left: &'a CSTNode,
+    right: &'a CSTNode,
+) -> usize {
+    match (left, right) {
+        (
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_left,
+                ..
+            }),
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_right,
+                ..
+            }),
+        ) => {
+            let header_left = extract_header(children_left);
+            let header_right = extract_header(children_right);
+
+            (!header_left.is_empty() && header_left == header_right).into()
+        }
+        (_, _) => 0,
+    }
+}
+
+/// Classes, structs, enums, protocols and type aliases are identified by their keyword and name.
+/// A type may be extended several times, so extensions also take their conformances into account.
+fn extract_header(node_children: &[CSTNode]) -> Vec<String> {
+    let is_extension = node_children.iter().any(|node| node.kind() == "extension");
+
+    node_children
+        .iter()
+        .filter(|node| match node.kind() {
+            "class" | "struct" | "enum" | "actor" | "extension" | "protocol" | "typealias"
+            | "type_identifier" | "user_type" => true,
+            "inheritance_specifier" | "type_constraints" => is_extension,
+            _ => false,
+        })
+        .map(|node| node.contents())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    use super::compute_matching_score_for_type_declaration;
+
+    #[test]
+    fn it_returns_one_if_types_have_the_same_keyword_and_name() {
+        let left = make_type_declaration("struct", "Point", vec![]);
+        let right = make_type_declaration("struct", "Point", vec!["Equatable"]);
+        assert_eq!(
+            1,
+            compute_matching_score_for_type_declaration(&left, &right)
+        );
+    }
+
+    #[test]
+    fn it_returns_zero_if_types_have_different_names() {
+        let left = make_type_declaration("struct", "Point", vec![]);
+        let right = make_type_declaration("struct", "Size", vec![]);
+        assert_eq!(
+            0,
+            compute_matching_score_for_type_declaration(&left, &right)
+        );
+    }
+
+    #[test]
+    fn it_returns_zero_if_types_have_different_keywords() {
+        let left = make_type_declaration("struct", "Point", vec![]);
+        let right = make_type_declaration("class", "Point", vec![]);
+        assert_eq!(
+            0,
+            compute_matching_score_for_type_declaration(&left, &right)
+        );
+    }
+
+    #[test]
+    fn extensions_with_different_conformances_do_not_match() {
+        let left = make_type_declaration("extension", "Point", vec!["Equatable"]);
+        let right = make_type_declaration("extension", "Point", vec!["Hashable"]);
+        assert_eq!(
+            0,
+            compute_matching_score_for_type_declaration(&left, &right)
+        );
+    }
+
+    fn make_type_declaration<'a>(
+        keyword: &'a str,
+        name: &'a str,
+        conformances: Vec<&'a str>,
+    ) -> CSTNode<'a> {
+        let mut children = vec![
+            CSTNode::Terminal(Terminal {
+                kind: keyword,
+                value: keyword,
+                ..Default::default()
+            }),
+            CSTNode::Terminal(Terminal {
+                kind: "type_identifier",
+                value: name,
+                ..Default::default()
+            }),
+        ];
+
+        children.extend(conformances.into_iter().map(|conformance| {
+            CSTNode::NonTerminal(NonTerminal {
+                kind: "inheritance_specifier",
+                children: vec![CSTNode::Terminal(Terminal {
+                    kind: "type_identifier",
+                    value: conformance,
+                    ..Default::default()
+                })],
+                ..Default::default()
+            })
+        }));
+
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "class_declaration",
+            children,
+            ..Default::default()
+        })
+    }
+}
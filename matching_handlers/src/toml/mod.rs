@@ -0,0 +1,33 @@
+mod pair;
+mod table;
+
+use crate::MatchingHandlers;
+
+use self::{
+    pair::compute_matching_score_for_pair,
+    table::{compute_matching_score_for_table, compute_matching_score_for_table_array_element},
+};
+
+/// Keys whose values identify an entry of an array of tables, e.g. `name` in `[[bin]]`.
+pub const DEFAULT_TABLE_ARRAY_IDENTITY_KEYS: [&str; 1] = ["name"];
+
+pub fn get_default_toml_matching_handlers<'a>() -> MatchingHandlers<'a> {
+    get_toml_matching_handlers(&DEFAULT_TABLE_ARRAY_IDENTITY_KEYS)
+}
+
+pub fn get_toml_matching_handlers<'a>(table_array_identity_keys: &[&str]) -> MatchingHandlers<'a> {
+    let table_array_identity_keys: Vec<String> = table_array_identity_keys
+        .iter()
+        .map(|key| key.to_string())
+        .collect();
+
+    let mut matching_handlers: MatchingHandlers<'a> = MatchingHandlers::new();
+    matching_handlers.register("pair", compute_matching_score_for_pair);
+    matching_handlers.register("table", compute_matching_score_for_table);
+    matching_handlers.register("table_array_element", move |left, right| {
+        compute_matching_score_for_table_array_element(left, right, &table_array_identity_keys)
+    });
+    // There is at most one group of pairs per table, so they always match each other
+    matching_handlers.register("pairs", |_, _| 1);
+    matching_handlers
+}
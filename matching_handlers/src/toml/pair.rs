@@ -0,0 +1,78 @@
+use model::{cst_node::NonTerminal, CSTNode};
+
+pub(super) fn find_key<'a>(node_children: &'a [CSTNode<'a>]) -> Option<&'a CSTNode<'a>> {
+    node_children.iter().find(|node| {
+        node.kind() == "bare_key" || node.kind() == "dotted_key" || node.kind() == "quoted_key"
+    })
+}
+
+pub fn compute_matching_score_for_pair<'a>(left: &'a CSTNode, right: &'a CSTNode) -> usize {
+    match (left, right) {
+        (
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_left,
+                ..
+            }),
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_right,
+                ..
+            }),
+        ) => {
+            let key_left = find_key(children_left).map(|node| node.contents());
+            let key_right = find_key(children_right).map(|node| node.contents());
+
+            (key_left.is_some() && key_left == key_right).into()
+        }
+        (_, _) => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    #[test]
+    fn pairs_with_the_same_key_match_regardless_of_their_values() {
+        let result = super::compute_matching_score_for_pair(
+            &make_pair("serde", "\"1.0\""),
+            &make_pair("serde", "\"1.1\""),
+        );
+        assert_eq!(1, result);
+    }
+
+    #[test]
+    fn pairs_with_different_keys_do_not_match() {
+        let result = super::compute_matching_score_for_pair(
+            &make_pair("serde", "\"1.0\""),
+            &make_pair("toml", "\"1.0\""),
+        );
+        assert_eq!(0, result);
+    }
+
+    fn make_pair<'a>(key: &'a str, value: &'a str) -> CSTNode<'a> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind: "pair",
+            children: vec![
+                CSTNode::Terminal(Terminal {
+                    kind: "bare_key",
+                    value: key,
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "=",
+                    value: "=",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "string",
+                    value,
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,199 @@
+use crate::utils::find_child_of_kind;
+use model::{cst_node::NonTerminal, CSTNode};
+
+use super::pair::find_key;
+
+pub fn compute_matching_score_for_table<'a>(left: &'a CSTNode, right: &'a CSTNode) -> usize {
+    match (left, right) {
+        (
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_left,
+                ..
+            }),
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_right,
+                ..
+            }),
+        ) => {
+            let key_left = find_key(children_left).map(|node| node.contents());
+            let key_right = find_key(children_right).map(|node| node.contents());
+
+            (key_left.is_some() && key_left == key_right).into()
+        }
+        (_, _) => 0,
+    }
+}
+
+pub fn compute_matching_score_for_table_array_element<'a>(
+    left: &'a CSTNode,
+    right: &'a CSTNode,
+    identity_keys: &[String],
+) -> usize {
+    if compute_matching_score_for_table(left, right) == 0 {
+        return 0;
+    }
+
+    match (left, right) {
+        (
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_left,
+                ..
+            }),
+            CSTNode::NonTerminal(NonTerminal {
+                children: children_right,
+                ..
+            }),
+        ) => {
+            let identity_left = find_identity(children_left, identity_keys);
+            let identity_right = find_identity(children_right, identity_keys);
+
+            // Entries without any identity key can only be told apart by their whole contents
+            if identity_left.is_empty() && identity_right.is_empty() {
+                return (left.contents() == right.contents()).into();
+            }
+
+            (identity_left == identity_right).into()
+        }
+        (_, _) => 0,
+    }
+}
+
+fn find_identity(node_children: &[CSTNode], identity_keys: &[String]) -> Vec<(String, String)> {
+    let pairs = match find_child_of_kind(node_children, "pairs") {
+        Some(CSTNode::NonTerminal(pairs)) => &pairs.children,
+        _ => return vec![],
+    };
+
+    pairs
+        .iter()
+        .filter_map(|pair| match pair {
+            CSTNode::NonTerminal(NonTerminal { children, .. }) => {
+                let key = find_key(children)?.contents();
+                let key = key.trim();
+                if !identity_keys.iter().any(|identity_key| identity_key == key) {
+                    return None;
+                }
+                let value = children.last()?.contents();
+                Some((key.to_string(), value))
+            }
+            CSTNode::Terminal(_) => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    fn identity_keys() -> Vec<String> {
+        vec!["name".to_string()]
+    }
+
+    #[test]
+    fn tables_with_the_same_header_match() {
+        let result = super::compute_matching_score_for_table(
+            &make_table("table", "dependencies", vec![]),
+            &make_table("table", "dependencies", vec![("serde", "\"1.0\"")]),
+        );
+        assert_eq!(1, result);
+    }
+
+    #[test]
+    fn tables_with_different_headers_do_not_match() {
+        let result = super::compute_matching_score_for_table(
+            &make_table("table", "dependencies", vec![]),
+            &make_table("table", "dev-dependencies", vec![]),
+        );
+        assert_eq!(0, result);
+    }
+
+    #[test]
+    fn array_of_tables_entries_are_identified_by_the_identity_key() {
+        let result = super::compute_matching_score_for_table_array_element(
+            &make_table(
+                "table_array_element",
+                "bin",
+                vec![("name", "\"cli\""), ("path", "\"src/main.rs\"")],
+            ),
+            &make_table(
+                "table_array_element",
+                "bin",
+                vec![("name", "\"cli\""), ("path", "\"src/cli.rs\"")],
+            ),
+            &identity_keys(),
+        );
+        assert_eq!(1, result);
+    }
+
+    #[test]
+    fn array_of_tables_entries_with_different_identities_do_not_match() {
+        let result = super::compute_matching_score_for_table_array_element(
+            &make_table("table_array_element", "bin", vec![("name", "\"cli\"")]),
+            &make_table("table_array_element", "bin", vec![("name", "\"server\"")]),
+            &identity_keys(),
+        );
+        assert_eq!(0, result);
+    }
+
+    #[test]
+    fn array_of_tables_entries_without_identity_only_match_if_equal() {
+        let left = make_table("table_array_element", "bin", vec![("path", "\"a.rs\"")]);
+        let right = make_table("table_array_element", "bin", vec![("path", "\"b.rs\"")]);
+
+        assert_eq!(
+            0,
+            super::compute_matching_score_for_table_array_element(&left, &right, &identity_keys())
+        );
+        assert_eq!(
+            1,
+            super::compute_matching_score_for_table_array_element(&left, &left, &identity_keys())
+        );
+    }
+
+    fn make_table<'a>(kind: &'a str, key: &'a str, pairs: Vec<(&'a str, &'a str)>) -> CSTNode<'a> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind,
+            children: vec![
+                CSTNode::Terminal(Terminal {
+                    kind: "bare_key",
+                    value: key,
+                    ..Default::default()
+                }),
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "pairs",
+                    children: pairs
+                        .into_iter()
+                        .map(|(key, value)| {
+                            CSTNode::NonTerminal(NonTerminal {
+                                kind: "pair",
+                                children: vec![
+                                    CSTNode::Terminal(Terminal {
+                                        kind: "bare_key",
+                                        value: key,
+                                        ..Default::default()
+                                    }),
+                                    CSTNode::Terminal(Terminal {
+                                        kind: "=",
+                                        value: "=",
+                                        ..Default::default()
+                                    }),
+                                    CSTNode::Terminal(Terminal {
+                                        kind: "string",
+                                        value,
+                                        ..Default::default()
+                                    }),
+                                ],
+                                ..Default::default()
+                            })
+                        })
+                        .collect(),
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        })
+    }
+}
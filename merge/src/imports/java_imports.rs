@@ -0,0 +1,696 @@
+use std::collections::HashSet;
+
+use model::{CSTNode, VisitorAction};
+
+use crate::{ImportOrdering, MergedCSTNode};
+
+const IMPORTS_KIND: &str = "import_declarations";
+const IMPORT_KIND: &str = "import_declaration";
+const TYPE_DECLARATION_KINDS: [&str; 5] = [
+    "class_declaration",
+    "interface_declaration",
+    "enum_declaration",
+    "record_declaration",
+    "annotation_type_declaration",
+];
+
+/// The types of `java.lang`, which are in scope in every file unless an import shadows them.
+const JAVA_LANG_TYPES: [&str; 104] = [
+    "AbstractMethodError",
+    "Appendable",
+    "ArithmeticException",
+    "ArrayIndexOutOfBoundsException",
+    "ArrayStoreException",
+    "AssertionError",
+    "AutoCloseable",
+    "Boolean",
+    "BootstrapMethodError",
+    "Byte",
+    "CharSequence",
+    "Character",
+    "Class",
+    "ClassCastException",
+    "ClassCircularityError",
+    "ClassFormatError",
+    "ClassLoader",
+    "ClassNotFoundException",
+    "ClassValue",
+    "CloneNotSupportedException",
+    "Cloneable",
+    "Comparable",
+    "Deprecated",
+    "Double",
+    "Enum",
+    "EnumConstantNotPresentException",
+    "Error",
+    "Exception",
+    "ExceptionInInitializerError",
+    "Float",
+    "FunctionalInterface",
+    "IllegalAccessError",
+    "IllegalAccessException",
+    "IllegalArgumentException",
+    "IllegalCallerException",
+    "IllegalMonitorStateException",
+    "IllegalStateException",
+    "IllegalThreadStateException",
+    "IncompatibleClassChangeError",
+    "IndexOutOfBoundsException",
+    "InheritableThreadLocal",
+    "InstantiationError",
+    "InstantiationException",
+    "Integer",
+    "InternalError",
+    "InterruptedException",
+    "Iterable",
+    "LayerInstantiationException",
+    "LinkageError",
+    "Long",
+    "MatchException",
+    "Math",
+    "Module",
+    "ModuleLayer",
+    "NegativeArraySizeException",
+    "NoClassDefFoundError",
+    "NoSuchFieldError",
+    "NoSuchFieldException",
+    "NoSuchMethodError",
+    "NoSuchMethodException",
+    "NullPointerException",
+    "Number",
+    "NumberFormatException",
+    "Object",
+    "OutOfMemoryError",
+    "Override",
+    "Package",
+    "Process",
+    "ProcessBuilder",
+    "ProcessHandle",
+    "Readable",
+    "Record",
+    "ReflectiveOperationException",
+    "Runnable",
+    "Runtime",
+    "RuntimeException",
+    "RuntimePermission",
+    "SafeVarargs",
+    "ScopedValue",
+    "SecurityException",
+    "SecurityManager",
+    "Short",
+    "StackOverflowError",
+    "StackTraceElement",
+    "StackWalker",
+    "StrictMath",
+    "String",
+    "StringBuffer",
+    "StringBuilder",
+    "StringIndexOutOfBoundsException",
+    "SuppressWarnings",
+    "System",
+    "Thread",
+    "ThreadDeath",
+    "ThreadGroup",
+    "ThreadLocal",
+    "Throwable",
+    "TypeNotPresentException",
+    "UnknownError",
+    "UnsatisfiedLinkError",
+    "UnsupportedClassVersionError",
+    "UnsupportedOperationException",
+    "VerifyError",
+    "VirtualMachineError",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Import {
+    /// The imported name, e.g. `java.util.List` or `java.util.*`.
+    name: String,
+    is_static: bool,
+}
+
+impl Import {
+    fn from_values<'b>(values: impl IntoIterator<Item = &'b str>) -> Import {
+        let values: Vec<&str> = values.into_iter().collect();
+        Import {
+            is_static: values.contains(&"static"),
+            name: values
+                .into_iter()
+                .filter(|value| !matches!(*value, "import" | "static" | ";"))
+                .collect(),
+        }
+    }
+
+    fn is_wildcard(&self) -> bool {
+        self.name.ends_with(".*")
+    }
+
+    /// The wildcard import of the package (or class, for static imports) of this import.
+    fn get_wildcard(&self) -> Option<Import> {
+        if self.is_wildcard() {
+            return None;
+        }
+        let (package, _) = self.name.rsplit_once('.')?;
+        Some(Import {
+            name: format!("{}.*", package),
+            is_static: self.is_static,
+        })
+    }
+
+    fn get_top_level_package(&self) -> &str {
+        self.name.split('.').next().unwrap_or_default()
+    }
+
+    /// The name the import is referred to by in the rest of the file.
+    fn get_simple_name(&self) -> &str {
+        self.name.rsplit('.').next().unwrap_or_default()
+    }
+}
+
+/// Tidies the imports of the already merged tree: imports made redundant by a wildcard import
+/// of the other revision are dropped, and the imports are sorted if the ordering says to always
+/// sort them, or if their merged order contradicts the order of either revision.
+pub fn normalize_java_imports<'a>(
+    ordering: &ImportOrdering,
+    left: &'a CSTNode<'a>,
+    right: &'a CSTNode<'a>,
+    merged: MergedCSTNode<'a>,
+) -> MergedCSTNode<'a> {
+    let left_imports = find_imports(left).unwrap_or_default();
+    let right_imports = find_imports(right).unwrap_or_default();
+    let mut declared_types = HashSet::new();
+    collect_declared_types(left, &mut declared_types);
+    collect_declared_types(right, &mut declared_types);
+    normalize_import_blocks(merged, &|children| {
+        normalize_import_block(
+            children,
+            ordering,
+            &left_imports,
+            &right_imports,
+            &declared_types,
+        )
+    })
+}
+
+/// Drops duplicate imports from the already merged tree, as well as the imports that are not
+/// used anymore, e.g. because one revision removed the last use of an import the other
+/// revision relied on. Only imports the merge changed are dropped: the ones added by either
+/// revision or used in base, not the ones already unused in base.
+pub fn remove_unused_java_imports<'a>(
+    base: &'a CSTNode<'a>,
+    merged: MergedCSTNode<'a>,
+) -> MergedCSTNode<'a> {
+    let base_imports = find_imports(base).unwrap_or_default();
+    let mut names_used_in_base = HashSet::new();
+    collect_names_used_in_tree(base, &mut names_used_in_base);
+    let mut used_names = HashSet::new();
+    collect_used_names(&merged, &mut used_names);
+    normalize_import_blocks(merged, &|children| {
+        remove_unused_imports_of_block(children, &base_imports, &names_used_in_base, &used_names)
+    })
+}
+
+fn normalize_import_blocks<'a>(
+    node: MergedCSTNode<'a>,
+    normalize: &dyn Fn(Vec<MergedCSTNode<'a>>) -> Vec<MergedCSTNode<'a>>,
+) -> MergedCSTNode<'a> {
+    match node {
+        MergedCSTNode::NonTerminal { kind, children } if kind == IMPORTS_KIND => {
+            MergedCSTNode::NonTerminal {
+                kind,
+                children: normalize(children),
+            }
+        }
+        MergedCSTNode::NonTerminal { kind, children } => MergedCSTNode::NonTerminal {
+            kind,
+            children: children
+                .into_iter()
+                .map(|child| normalize_import_blocks(child, normalize))
+                .collect(),
+        },
+        node => node,
+    }
+}
+
+fn normalize_import_block<'a>(
+    children: Vec<MergedCSTNode<'a>>,
+    ordering: &ImportOrdering,
+    left_imports: &[Import],
+    right_imports: &[Import],
+    declared_types: &HashSet<String>,
+) -> Vec<MergedCSTNode<'a>> {
+    // Blocks with conflicts are left for the user to sort out
+    let Some(imports) = children
+        .iter()
+        .map(get_merged_import)
+        .collect::<Option<Vec<_>>>()
+    else {
+        return children;
+    };
+
+    let mut entries: Vec<(Import, MergedCSTNode)> = imports
+        .iter()
+        .cloned()
+        .zip(children)
+        .filter(|(import, _)| {
+            !is_subsumed(
+                import,
+                &imports,
+                left_imports,
+                right_imports,
+                declared_types,
+            )
+        })
+        .collect();
+
+    // Unless asked to always sort, the order of the merge is kept as long as it does not
+    // contradict either revision
+    let follows_order_of = |revision_imports: &[Import]| {
+        let positions: Vec<usize> = entries
+            .iter()
+            .filter_map(|(import, _)| revision_imports.iter().position(|other| other == import))
+            .collect();
+        positions.windows(2).all(|pair| pair[0] < pair[1])
+    };
+    if ordering.always_sort || !follows_order_of(left_imports) || !follows_order_of(right_imports) {
+        entries.sort_by_cached_key(|(import, _)| get_sort_key(import, ordering));
+    }
+
+    entries.into_iter().map(|(_, child)| child).collect()
+}
+
+/// Drops duplicate imports, as well as unused imports that were either added by a revision or
+/// used in base. Imports that were already unused in base are left alone, and so are wildcard
+/// imports, as their uses can not be told.
+fn remove_unused_imports_of_block<'a>(
+    children: Vec<MergedCSTNode<'a>>,
+    base_imports: &[Import],
+    names_used_in_base: &HashSet<String>,
+    used_names: &HashSet<String>,
+) -> Vec<MergedCSTNode<'a>> {
+    let mut seen_imports: Vec<Import> = vec![];
+    children
+        .into_iter()
+        .filter(|child| {
+            let Some(import) = get_merged_import(child) else {
+                return true;
+            };
+            if seen_imports.contains(&import) {
+                return false;
+            }
+
+            let name = import.get_simple_name();
+            let is_changed = !base_imports.contains(&import) || names_used_in_base.contains(name);
+            let is_unused = !import.is_wildcard() && !used_names.contains(name);
+            seen_imports.push(import);
+            !(is_changed && is_unused)
+        })
+        .collect()
+}
+
+/// Collects every word of the merged tree, other than its imports and package declaration, as
+/// a name that may refer to an import. Words of conflicts, opaque terminals and subtrees merged
+/// line by line are collected too, so imports are only dropped if they are surely unused.
+fn collect_used_names(node: &MergedCSTNode, names: &mut HashSet<String>) {
+    match node {
+        MergedCSTNode::Terminal { value, .. } | MergedCSTNode::TextualFallback { value, .. } => {
+            names.extend(get_words(value))
+        }
+        MergedCSTNode::NonTerminal { kind, .. }
+            if matches!(*kind, IMPORTS_KIND | IMPORT_KIND | "package_declaration") => {}
+        MergedCSTNode::NonTerminal { children, .. } => children
+            .iter()
+            .for_each(|child| collect_used_names(child, names)),
+        MergedCSTNode::Conflict { left, right } => left
+            .iter()
+            .chain(right.iter())
+            .for_each(|node| collect_used_names(node, names)),
+    }
+}
+
+fn collect_names_used_in_tree(node: &CSTNode, names: &mut HashSet<String>) {
+    node.walk(&mut |node: &CSTNode| match node {
+        CSTNode::Terminal(terminal) => {
+            names.extend(get_words(terminal.value));
+            VisitorAction::Continue
+        }
+        CSTNode::NonTerminal(non_terminal)
+            if matches!(
+                non_terminal.kind,
+                IMPORTS_KIND | IMPORT_KIND | "package_declaration"
+            ) =>
+        {
+            VisitorAction::SkipChildren
+        }
+        CSTNode::NonTerminal(_) => VisitorAction::Continue,
+    });
+}
+
+/// Collects the names of the types declared in the file, which belong to its package.
+fn collect_declared_types(node: &CSTNode, names: &mut HashSet<String>) {
+    node.walk(&mut |node: &CSTNode| {
+        if let CSTNode::NonTerminal(non_terminal) = node {
+            if TYPE_DECLARATION_KINDS.contains(&non_terminal.kind) {
+                names.extend(non_terminal.children.iter().find_map(|child| match child {
+                    CSTNode::Terminal(terminal) if terminal.kind == "identifier" => {
+                        Some(terminal.value.to_string())
+                    }
+                    _ => None,
+                }));
+            }
+        }
+        VisitorAction::Continue
+    });
+}
+
+fn get_words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|character: char| {
+        !character.is_alphanumeric() && character != '_' && character != '$'
+    })
+    .filter(|word| !word.is_empty())
+    .map(str::to_string)
+}
+
+/// An import is redundant if one revision added it while the other one imports the whole
+/// package instead. It is kept if another wildcard import could make its name ambiguous, or if
+/// it shadows a type of `java.lang` or of the package of the file, which wildcards do not. Only
+/// the types declared in the file itself are known of its package.
+fn is_subsumed(
+    import: &Import,
+    merged_imports: &[Import],
+    left_imports: &[Import],
+    right_imports: &[Import],
+    declared_types: &HashSet<String>,
+) -> bool {
+    let Some(wildcard) = import.get_wildcard() else {
+        return false;
+    };
+    let name = import.get_simple_name();
+    if !import.is_static && (JAVA_LANG_TYPES.contains(&name) || declared_types.contains(name)) {
+        return false;
+    }
+    let is_ambiguous = merged_imports.iter().any(|other| {
+        other.is_wildcard() && other.is_static == import.is_static && *other != wildcard
+    });
+    let replaced_by = |ours: &[Import], theirs: &[Import]| {
+        ours.contains(import)
+            && !ours.contains(&wildcard)
+            && theirs.contains(&wildcard)
+            && !theirs.contains(import)
+    };
+
+    merged_imports.contains(&wildcard)
+        && !is_ambiguous
+        && (replaced_by(left_imports, right_imports) || replaced_by(right_imports, left_imports))
+}
+
+fn get_sort_key(import: &Import, ordering: &ImportOrdering) -> (bool, usize, String) {
+    let group = ordering
+        .package_groups
+        .iter()
+        .position(|package| package == import.get_top_level_package())
+        .unwrap_or(ordering.package_groups.len());
+    (
+        import.is_static != ordering.static_imports_first,
+        group,
+        import.name.clone(),
+    )
+}
+
+fn find_imports(node: &CSTNode) -> Option<Vec<Import>> {
+    match node {
+        CSTNode::NonTerminal(non_terminal) if non_terminal.kind == IMPORTS_KIND => Some(
+            non_terminal
+                .children
+                .iter()
+                .map(|child| Import::from_values(get_values(child)))
+                .collect(),
+        ),
+        CSTNode::NonTerminal(non_terminal) => non_terminal.children.iter().find_map(find_imports),
+        CSTNode::Terminal(_) => None,
+    }
+}
+
+fn get_values<'a>(node: &'a CSTNode<'a>) -> Vec<&'a str> {
+    match node {
+        CSTNode::Terminal(terminal) => vec![terminal.value],
+        CSTNode::NonTerminal(non_terminal) => {
+            non_terminal.children.iter().flat_map(get_values).collect()
+        }
+    }
+}
+
+fn get_merged_import(node: &MergedCSTNode) -> Option<Import> {
+    match node {
+        MergedCSTNode::NonTerminal { kind, .. } if *kind == IMPORT_KIND => {
+            get_merged_values(node).map(Import::from_values)
+        }
+        _ => None,
+    }
+}
+
+fn get_merged_values<'b>(node: &'b MergedCSTNode) -> Option<Vec<&'b str>> {
+    match node {
+        MergedCSTNode::Terminal { value, .. } => Some(vec![value]),
+        MergedCSTNode::NonTerminal { children, .. } => children
+            .iter()
+            .map(get_merged_values)
+            .collect::<Option<Vec<_>>>()
+            .map(|values| values.concat()),
+        MergedCSTNode::Conflict { .. } | MergedCSTNode::TextualFallback { .. } => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{ImportOrdering, MergedCSTNode};
+
+    use super::{
+        get_sort_key, get_words, is_subsumed, normalize_import_block,
+        remove_unused_imports_of_block, Import,
+    };
+
+    fn import(name: &str) -> Import {
+        Import::from_values(name.split(' '))
+    }
+
+    fn make_merged_import(name: &str) -> MergedCSTNode<'static> {
+        MergedCSTNode::NonTerminal {
+            kind: "import_declaration",
+            children: vec![
+                MergedCSTNode::Terminal {
+                    kind: "import",
+                    value: "import".into(),
+                },
+                MergedCSTNode::Terminal {
+                    kind: "scoped_identifier",
+                    value: name.into(),
+                },
+                MergedCSTNode::Terminal {
+                    kind: ";",
+                    value: ";".into(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn imports_are_read_from_their_terminals() {
+        let import = Import::from_values(["import", "static", "org", ".", "A", ".", "*", ";"]);
+
+        assert_eq!("org.A.*", import.name);
+        assert!(import.is_static);
+        assert!(import.is_wildcard());
+    }
+
+    #[test]
+    fn imports_added_by_one_side_are_subsumed_by_wildcards_added_by_the_other() {
+        let list = import("java.util.List");
+        let wildcard = import("java.util.*");
+        let merged = [list.clone(), wildcard.clone()];
+        let only_list = [list.clone()];
+        let only_wildcard = [wildcard.clone()];
+
+        assert!(is_subsumed(
+            &list,
+            &merged,
+            &only_list,
+            &only_wildcard,
+            &HashSet::new()
+        ));
+        assert!(!is_subsumed(
+            &list,
+            &merged,
+            &merged,
+            &only_wildcard,
+            &HashSet::new()
+        ));
+        assert!(!is_subsumed(
+            &wildcard,
+            &merged,
+            &only_list,
+            &only_wildcard,
+            &HashSet::new()
+        ));
+    }
+
+    #[test]
+    fn imports_are_not_subsumed_if_other_wildcards_could_make_them_ambiguous() {
+        let list = import("java.util.List");
+        let wildcard = import("java.util.*");
+        let other_wildcard = import("java.awt.*");
+        let merged = [list.clone(), wildcard.clone(), other_wildcard.clone()];
+        let left = [list.clone(), other_wildcard.clone()];
+        let right = [wildcard, other_wildcard];
+
+        assert!(!is_subsumed(&list, &merged, &left, &right, &HashSet::new()));
+    }
+
+    #[test]
+    fn imports_shadowing_types_of_java_lang_or_of_the_file_are_not_subsumed() {
+        let string = import("org.text.String");
+        let parser = import("org.text.Parser");
+        let wildcard = import("org.text.*");
+        let merged = [string.clone(), parser.clone(), wildcard.clone()];
+        let left = [string.clone(), parser.clone()];
+        let right = [wildcard];
+        let declared_types: HashSet<String> = ["Parser".to_string()].into();
+
+        assert!(!is_subsumed(
+            &string,
+            &merged,
+            &left,
+            &right,
+            &declared_types
+        ));
+        assert!(!is_subsumed(
+            &parser,
+            &merged,
+            &left,
+            &right,
+            &declared_types
+        ));
+        assert!(is_subsumed(
+            &parser,
+            &merged,
+            &left,
+            &right,
+            &HashSet::new()
+        ));
+    }
+
+    #[test]
+    fn imports_are_sorted_by_staticness_package_group_and_name() {
+        let ordering = ImportOrdering {
+            always_sort: true,
+            static_imports_first: false,
+            package_groups: vec!["java".into(), "javax".into()],
+        };
+        let mut imports = [
+            import("static org.junit.Assert.*"),
+            import("com.google.Guava"),
+            import("javax.inject.Inject"),
+            import("java.util.Map"),
+            import("java.util.List"),
+        ];
+
+        imports.sort_by_key(|import| get_sort_key(import, &ordering));
+
+        let names: Vec<&str> = imports.iter().map(|import| import.name.as_str()).collect();
+        assert_eq!(
+            vec![
+                "java.util.List",
+                "java.util.Map",
+                "javax.inject.Inject",
+                "com.google.Guava",
+                "org.junit.Assert.*"
+            ],
+            names
+        );
+    }
+
+    #[test]
+    fn imports_are_sorted_when_asked_to_or_when_their_merged_order_contradicts_a_revision() {
+        let make_children = || {
+            vec![
+                make_merged_import("org.junit.Test"),
+                make_merged_import("java.util.List"),
+            ]
+        };
+        let left = [import("org.junit.Test")];
+        let right = [import("java.util.List")];
+        let contradicting_right = [import("java.util.List"), import("org.junit.Test")];
+        let sorted = vec![
+            make_merged_import("java.util.List"),
+            make_merged_import("org.junit.Test"),
+        ];
+        let always_sort = ImportOrdering {
+            always_sort: true,
+            ..Default::default()
+        };
+
+        let kept = normalize_import_block(
+            make_children(),
+            &ImportOrdering::default(),
+            &left,
+            &right,
+            &HashSet::new(),
+        );
+        let sorted_on_demand = normalize_import_block(
+            make_children(),
+            &always_sort,
+            &left,
+            &right,
+            &HashSet::new(),
+        );
+        let sorted_as_contradicting = normalize_import_block(
+            make_children(),
+            &ImportOrdering::default(),
+            &left,
+            &contradicting_right,
+            &HashSet::new(),
+        );
+
+        assert_eq!(make_children(), kept);
+        assert_eq!(sorted, sorted_on_demand);
+        assert_eq!(sorted, sorted_as_contradicting);
+    }
+
+    #[test]
+    fn words_are_split_at_anything_but_java_identifier_characters() {
+        let words: Vec<String> = get_words("Map<String, $Value_1>.of()").collect();
+
+        assert_eq!(vec!["Map", "String", "$Value_1", "of"], words);
+    }
+
+    #[test]
+    fn only_imports_added_by_a_revision_or_used_in_base_are_removed_if_unused() {
+        let children = vec![
+            make_merged_import("java.util.List"),
+            make_merged_import("java.util.Map"),
+            make_merged_import("java.util.Set"),
+            make_merged_import("java.util.Set"),
+            make_merged_import("java.io.*"),
+        ];
+        let base_imports = [import("java.util.Map"), import("java.util.Set")];
+        let names_used_in_base: HashSet<String> = ["Set".to_string()].into();
+        let used_names: HashSet<String> = ["Optional".to_string()].into();
+
+        let result = remove_unused_imports_of_block(
+            children,
+            &base_imports,
+            &names_used_in_base,
+            &used_names,
+        );
+
+        assert_eq!(
+            vec![
+                make_merged_import("java.util.Map"),
+                make_merged_import("java.io.*")
+            ],
+            result
+        );
+    }
+}
@@ -0,0 +1,27 @@
+mod java_imports;
+
+pub use java_imports::{normalize_java_imports, remove_unused_java_imports};
+
+/// The order of the imports of a merged file. Sorting is opt-in: unless `always_sort` is set,
+/// the imports are only sorted when the merge mixed the imports of both revisions in an order
+/// that contradicts one of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportOrdering {
+    /// Whether merged imports are sorted even if their merged order follows both revisions.
+    pub always_sort: bool,
+    /// Whether static imports are placed before the other imports or after them.
+    pub static_imports_first: bool,
+    /// Top-level packages whose imports come first, in this order, e.g. `java` and `javax`.
+    /// Imports of other packages follow them. Imports are sorted by name within each group.
+    pub package_groups: Vec<String>,
+}
+
+impl Default for ImportOrdering {
+    fn default() -> Self {
+        ImportOrdering {
+            always_sort: false,
+            static_imports_first: true,
+            package_groups: vec![],
+        }
+    }
+}
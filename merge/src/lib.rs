@@ -1,10 +1,20 @@
+mod imports;
 mod merge;
 mod merge_error;
 mod merge_terminals;
 mod merged_cst_node;
 mod ordered_merge;
+mod printer_configuration;
+mod semantic_conflicts;
+mod textual_fallback;
 mod unordered_merge;
 
+pub use imports::{normalize_java_imports, remove_unused_java_imports, ImportOrdering};
 pub use merge::merge;
 pub use merge_error::MergeError;
 pub use merged_cst_node::MergedCSTNode;
+pub use printer_configuration::PrinterConfiguration;
+pub use semantic_conflicts::{
+    detect_java_access_modifier_conflicts, detect_protobuf_field_number_conflicts,
+};
+pub use textual_fallback::RevisionSources;
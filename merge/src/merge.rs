@@ -1,6 +1,7 @@
 use crate::merge_error::MergeError;
 use crate::merge_terminals::merge_terminals;
 use crate::ordered_merge::ordered_merge;
+use crate::textual_fallback::{textual_fallback, RevisionSources};
 use crate::unordered_merge::unordered_merge;
 use matching::Matchings;
 use model::CSTNode;
@@ -14,6 +15,7 @@ pub fn merge<'a>(
     base_left_matchings: &'a Matchings<'a>,
     base_right_matchings: &'a Matchings<'a>,
     left_right_matchings: &'a Matchings<'a>,
+    sources: &RevisionSources,
 ) -> Result<MergedCSTNode<'a>, MergeError> {
     if left.kind() != right.kind() {
         log::debug!(
@@ -29,26 +31,43 @@ pub fn merge<'a>(
 
     match (base, left, right) {
         (CSTNode::Terminal(a_base), CSTNode::Terminal(a_left), CSTNode::Terminal(a_right)) => {
+            // Ordered merges pass the left node as base, but opaque terminals are matched by
+            // position and may differ from the base on both sides.
+            let a_base = match base_left_matchings
+                .find_matching_for(left)
+                .map(|matching| matching.matching_node)
+            {
+                Some(CSTNode::Terminal(matching_base)) => matching_base,
+                _ => a_base,
+            };
             merge_terminals(a_base, a_left, a_right)
         }
-        (CSTNode::NonTerminal(_), CSTNode::NonTerminal(a_left), CSTNode::NonTerminal(a_right)) => {
-            if a_left.are_children_unordered && a_right.are_children_unordered {
-                Ok(unordered_merge(
+        // The base only takes part through the matchings, so an empty base, parsed as a
+        // terminal, is merged as if both revisions had added the node.
+        // If the children can not be merged, the subtree is merged line by line instead.
+        (_, CSTNode::NonTerminal(a_left), CSTNode::NonTerminal(a_right)) => {
+            let merged = if a_left.are_children_unordered && a_right.are_children_unordered {
+                unordered_merge(
                     a_left,
                     a_right,
                     base_left_matchings,
                     base_right_matchings,
                     left_right_matchings,
-                )?)
+                    sources,
+                )
             } else {
-                Ok(ordered_merge(
+                ordered_merge(
                     a_left,
                     a_right,
                     base_left_matchings,
                     base_right_matchings,
                     left_right_matchings,
-                )?)
-            }
+                    sources,
+                )
+            };
+            Ok(merged.unwrap_or_else(|error| {
+                textual_fallback(left, right, base_left_matchings, sources, error)
+            }))
         }
         (_, _, _) => {
             log::debug!(
@@ -64,13 +83,14 @@ pub fn merge<'a>(
 #[cfg(test)]
 mod tests {
     use super::merge;
-    use crate::MergeError;
-    use matching::Matchings;
+    use crate::{MergeError, RevisionSources};
+    use matching::{MatchingEntry, Matchings};
     use model::{
         cst_node::{NonTerminal, Terminal},
         CSTNode, Point,
     };
     use std::vec;
+    use unordered_pair::UnorderedPair;
 
     #[test]
     fn test_can_not_merge_terminal_with_non_terminal() -> Result<(), Box<dyn std::error::Error>> {
@@ -82,6 +102,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             }),
             &CSTNode::Terminal(Terminal {
                 id: uuid::Uuid::new_v4(),
@@ -90,11 +112,15 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             }),
             &CSTNode::NonTerminal(NonTerminal {
                 id: uuid::Uuid::new_v4(),
                 kind: "kind",
                 are_children_unordered: false,
+                identity: vec![],
+                injected_language: None,
                 start_position: Point { row: 0, column: 0 },
                 end_position: Point { row: 0, column: 7 },
                 children: vec![],
@@ -102,6 +128,7 @@ mod tests {
             &Matchings::empty(),
             &Matchings::empty(),
             &Matchings::empty(),
+            &RevisionSources::default(),
         )
         .unwrap_err();
 
@@ -109,4 +136,131 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_merges_non_terminals_with_an_empty_base() -> Result<(), Box<dyn std::error::Error>> {
+        let base = CSTNode::Terminal(Terminal {
+            id: uuid::Uuid::new_v4(),
+            kind: "program",
+            start_position: Point { row: 0, column: 0 },
+            end_position: Point { row: 0, column: 0 },
+            value: "",
+            is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
+        });
+        let parent = |value| {
+            CSTNode::NonTerminal(NonTerminal {
+                id: uuid::Uuid::new_v4(),
+                kind: "program",
+                are_children_unordered: false,
+                identity: vec![],
+                injected_language: None,
+                start_position: Point { row: 0, column: 0 },
+                end_position: Point { row: 0, column: 5 },
+                children: vec![CSTNode::Terminal(Terminal {
+                    id: uuid::Uuid::new_v4(),
+                    kind: "value",
+                    start_position: Point { row: 0, column: 0 },
+                    end_position: Point { row: 0, column: 5 },
+                    value,
+                    is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
+                })],
+            })
+        };
+        let left = parent("left");
+        let right = parent("right");
+        let matchings = Matchings::empty();
+
+        let result = merge(
+            &base,
+            &left,
+            &right,
+            &matchings,
+            &matchings,
+            &matchings,
+            &RevisionSources::default(),
+        )?;
+
+        assert!(result.has_conflict());
+
+        Ok(())
+    }
+
+    #[test]
+    fn subtrees_whose_children_can_not_be_merged_are_merged_line_by_line(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let value = |row| {
+            CSTNode::Terminal(Terminal {
+                id: uuid::Uuid::new_v4(),
+                kind: "value",
+                start_position: Point { row, column: 0 },
+                end_position: Point { row, column: 5 },
+                value: "value",
+                is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
+            })
+        };
+        let program = |children| {
+            CSTNode::NonTerminal(NonTerminal {
+                id: uuid::Uuid::new_v4(),
+                kind: "program",
+                are_children_unordered: false,
+                identity: vec![],
+                injected_language: None,
+                start_position: Point { row: 0, column: 0 },
+                end_position: Point { row: 1, column: 5 },
+                children,
+            })
+        };
+        let left = program(vec![value(0)]);
+        let right = program(vec![CSTNode::NonTerminal(NonTerminal {
+            id: uuid::Uuid::new_v4(),
+            kind: "value",
+            are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
+            start_position: Point { row: 0, column: 0 },
+            end_position: Point { row: 0, column: 5 },
+            children: vec![value(0)],
+        })]);
+        let (CSTNode::NonTerminal(left_program), CSTNode::NonTerminal(right_program)) =
+            (&left, &right)
+        else {
+            unreachable!()
+        };
+        let matchings = Matchings::from_single(
+            UnorderedPair(&left_program.children[0], &right_program.children[0]),
+            MatchingEntry {
+                score: 1,
+                is_perfect_match: false,
+            },
+        );
+        let empty_matchings = Matchings::empty();
+
+        let result = merge(
+            &left,
+            &left,
+            &right,
+            &empty_matchings,
+            &empty_matchings,
+            &matchings,
+            &RevisionSources {
+                base: "",
+                left: "value",
+                right: "value",
+            },
+        )?;
+
+        assert_eq!(
+            vec![("program", "Merging terminal with non-terminal")],
+            result.get_textual_fallbacks()
+        );
+        assert_eq!("value", result.to_string());
+
+        Ok(())
+    }
 }
@@ -25,9 +25,9 @@ pub fn merge_terminals<'a>(
                 kind: base.kind,
                 value,
             }),
-            Err(value) => Ok(MergedCSTNode::Terminal {
-                kind: base.kind,
-                value,
+            Err(_) => Ok(MergedCSTNode::Conflict {
+                left: Some(Box::new(left.to_owned().into())),
+                right: Some(Box::new(right.to_owned().into())),
             }),
         }
     // Only left changed
@@ -69,6 +69,8 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             value: "value",
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         };
 
         assert_merge_is_correct_and_idempotent_with_respect_to_parent_side(
@@ -89,6 +91,8 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             value: "\nvalue\n",
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         };
         let left = Terminal {
             id: uuid::Uuid::new_v4(),
@@ -97,6 +101,8 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             value: "left\nvalue\n",
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         };
         let right = Terminal {
             id: uuid::Uuid::new_v4(),
@@ -105,6 +111,8 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             value: "\nvalue\nright",
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         };
 
         assert_merge_is_correct_and_idempotent_with_respect_to_parent_side(
@@ -128,6 +136,8 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             value: "value",
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         };
         let left = Terminal {
             id: uuid::Uuid::new_v4(),
@@ -136,6 +146,8 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             value: "left_value",
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         };
         let right = Terminal {
             id: uuid::Uuid::new_v4(),
@@ -144,13 +156,15 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             value: "right_value",
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         };
 
         assert_eq!(
             merge_terminals(&base, &left, &right)?,
-           MergedCSTNode::Terminal {
-                kind: "kind",
-                value: "<<<<<<< ours\nleft_value||||||| original\nvalue=======\nright_value>>>>>>> theirs\n".to_string()
+            MergedCSTNode::Conflict {
+                left: Some(Box::new(left.clone().into())),
+                right: Some(Box::new(right.clone().into())),
             }
         );
 
@@ -167,6 +181,8 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             value: "value",
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         };
         let changed_parent = Terminal {
             id: uuid::Uuid::new_v4(),
@@ -175,6 +191,8 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             value: "value_right",
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         };
 
         assert_merge_is_correct_and_idempotent_with_respect_to_parent_side(
@@ -194,6 +212,8 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             value: "value",
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         };
         let kind_b = Terminal {
             id: uuid::Uuid::new_v4(),
@@ -202,6 +222,8 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             value: "value_right",
             is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
         };
 
         let result = merge_terminals(&kind_a, &kind_a, &kind_b);
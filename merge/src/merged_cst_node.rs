@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+use crate::PrinterConfiguration;
 use model::{
     cst_node::{NonTerminal, Terminal},
     CSTNode,
@@ -19,6 +20,13 @@ pub enum MergedCSTNode<'a> {
         left: Option<Box<MergedCSTNode<'a>>>,
         right: Option<Box<MergedCSTNode<'a>>>,
     },
+    /// A subtree that could not be merged structurally, merged line by line instead.
+    TextualFallback {
+        kind: &'a str,
+        value: String,
+        has_conflict: bool,
+        reason: String,
+    },
 }
 
 impl<'a> From<CSTNode<'a>> for MergedCSTNode<'a> {
@@ -49,48 +57,78 @@ impl<'a> From<Terminal<'a>> for MergedCSTNode<'a> {
 
 impl Display for MergedCSTNode<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.print(&PrinterConfiguration::default()))
+    }
+}
+
+impl MergedCSTNode<'_> {
+    pub fn print(&self, config: &PrinterConfiguration) -> String {
         match self {
-            MergedCSTNode::Terminal { value, .. } => write!(f, "{}", value),
-            MergedCSTNode::NonTerminal { children, .. } => {
-                let result = children.iter().fold(String::new(), |acc, current| {
-                    let mut result = acc.to_owned();
-                    result.push(' ');
-                    result.push_str(&current.clone().to_string());
-                    result
-                });
-
-                write!(f, "{}", result)
-            }
-            MergedCSTNode::Conflict { left, right } => match (left, right) {
-                (Some(left), Some(right)) => {
-                    writeln!(f)?;
-                    writeln!(f, "<<<<<<<<<")?;
-                    writeln!(f, "{}", left)?;
-                    writeln!(f, "=========")?;
-                    writeln!(f, "{}", right)?;
-                    writeln!(f, ">>>>>>>>>")
-                }
-                (Some(left), None) => {
-                    writeln!(f)?;
-                    writeln!(f, "<<<<<<<<<")?;
-                    writeln!(f, "{}", left)?;
-                    writeln!(f, "=========")?;
-                    writeln!(f, ">>>>>>>>>")
+            MergedCSTNode::Terminal { value, .. }
+            | MergedCSTNode::TextualFallback { value, .. } => value.to_owned(),
+            MergedCSTNode::NonTerminal { kind, children } => {
+                let separator = match config.kinds_with_line_separated_children.contains(kind) {
+                    true => '\n',
+                    false => ' ',
+                };
+                let separating_token = config.kinds_with_separated_children.get(kind);
+
+                let mut result = String::new();
+                let mut previous_is_on_separate_line = false;
+                let mut has_previous_element = false;
+                for child in children {
+                    // Trailing separators are kept while parsing, and printed as they are.
+                    // Delimiters other than brackets, e.g. comments, may sit between elements
+                    let is_element = !child.kind().is_some_and(|kind| {
+                        config.delimiters.contains(kind) || Some(&kind) == separating_token
+                    });
+                    if let Some(token) =
+                        separating_token.filter(|_| has_previous_element && is_element)
+                    {
+                        result.push(separator);
+                        result.push_str(token);
+                    }
+                    has_previous_element |= is_element;
+                    let is_on_separate_line = child
+                        .kind()
+                        .is_some_and(|kind| config.kinds_on_separate_lines.contains(kind));
+                    match is_on_separate_line || previous_is_on_separate_line {
+                        true if !result.ends_with('\n') => result.push('\n'),
+                        true => {}
+                        false => result.push(separator),
+                    }
+                    result.push_str(&child.print(config));
+                    previous_is_on_separate_line = is_on_separate_line;
                 }
-                (None, Some(right)) => {
-                    writeln!(f)?;
-                    writeln!(f, "<<<<<<<<<")?;
-                    writeln!(f, "=========")?;
-                    writeln!(f, "{}", right)?;
-                    writeln!(f, ">>>>>>>>>")
+                result
+            }
+            MergedCSTNode::Conflict { left, right } => {
+                let left = left.as_ref().map(|node| node.print(config));
+                let right = right.as_ref().map(|node| node.print(config));
+
+                match (left, right) {
+                    (Some(left), Some(right)) => {
+                        format!("\n<<<<<<<<<\n{}\n=========\n{}\n>>>>>>>>>\n", left, right)
+                    }
+                    (Some(left), None) => format!("\n<<<<<<<<<\n{}\n=========\n>>>>>>>>>\n", left),
+                    (None, Some(right)) => {
+                        format!("\n<<<<<<<<<\n=========\n{}\n>>>>>>>>>\n", right)
+                    }
+                    (None, None) => unreachable!("Invalid conflict provided"),
                 }
-                (None, None) => unreachable!("Invalid conflict provided"),
-            },
+            }
+        }
+    }
+
+    fn kind(&self) -> Option<&str> {
+        match self {
+            MergedCSTNode::Terminal { kind, .. }
+            | MergedCSTNode::NonTerminal { kind, .. }
+            | MergedCSTNode::TextualFallback { kind, .. } => Some(kind),
+            MergedCSTNode::Conflict { .. } => None,
         }
     }
-}
 
-impl MergedCSTNode<'_> {
     pub fn has_conflict(&self) -> bool {
         match self {
             MergedCSTNode::NonTerminal { children, .. } => {
@@ -98,6 +136,135 @@ impl MergedCSTNode<'_> {
             }
             MergedCSTNode::Terminal { .. } => false,
             MergedCSTNode::Conflict { .. } => true,
+            MergedCSTNode::TextualFallback { has_conflict, .. } => *has_conflict,
+        }
+    }
+
+    /// The kinds of the subtrees that were merged line by line, along with the reason.
+    pub fn get_textual_fallbacks(&self) -> Vec<(&str, &str)> {
+        match self {
+            MergedCSTNode::NonTerminal { children, .. } => children
+                .iter()
+                .flat_map(MergedCSTNode::get_textual_fallbacks)
+                .collect(),
+            MergedCSTNode::Conflict { left, right } => left
+                .iter()
+                .chain(right.iter())
+                .flat_map(|node| node.get_textual_fallbacks())
+                .collect(),
+            MergedCSTNode::Terminal { .. } => vec![],
+            MergedCSTNode::TextualFallback { kind, reason, .. } => vec![(kind, reason)],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{MergedCSTNode, PrinterConfiguration};
+
+    fn make_terminal<'a>(kind: &'a str, value: &str) -> MergedCSTNode<'a> {
+        MergedCSTNode::Terminal {
+            kind,
+            value: value.to_string(),
         }
     }
+
+    #[test]
+    fn kinds_on_separate_lines_are_printed_on_lines_of_their_own() {
+        let config = PrinterConfiguration {
+            kinds_on_separate_lines: ["code_fence_content"].into(),
+            ..Default::default()
+        };
+        let fence = MergedCSTNode::NonTerminal {
+            kind: "fenced_code_block",
+            children: vec![
+                make_terminal("fenced_code_block_delimiter", "```"),
+                make_terminal("info_string", "js"),
+                make_terminal("code_fence_content", "let a = 1;\n"),
+                make_terminal("fenced_code_block_delimiter", "```"),
+            ],
+        };
+
+        assert_eq!(" ``` js\nlet a = 1;\n```", fence.print(&config));
+    }
+
+    #[test]
+    fn separating_tokens_are_printed_back_between_children() {
+        let config = PrinterConfiguration {
+            kinds_with_separated_children: [("type_list", ",")].into(),
+            ..Default::default()
+        };
+        let type_list = MergedCSTNode::NonTerminal {
+            kind: "type_list",
+            children: vec![
+                make_terminal("type_identifier", "A"),
+                make_terminal("type_identifier", "B"),
+                make_terminal("type_identifier", "C"),
+            ],
+        };
+
+        assert_eq!(" A , B , C", type_list.print(&config));
+    }
+
+    #[test]
+    fn separating_tokens_are_not_printed_next_to_delimiters() {
+        let config = PrinterConfiguration {
+            kinds_with_separated_children: [("argument_list", ",")].into(),
+            delimiters: ["(", ")"].into(),
+            ..Default::default()
+        };
+        let argument_list = MergedCSTNode::NonTerminal {
+            kind: "argument_list",
+            children: vec![
+                make_terminal("(", "("),
+                make_terminal("identifier", "a"),
+                make_terminal("identifier", "b"),
+                make_terminal(")", ")"),
+            ],
+        };
+
+        assert_eq!(" ( a , b )", argument_list.print(&config));
+    }
+
+    #[test]
+    fn trailing_separating_tokens_are_printed_as_they_are() {
+        let config = PrinterConfiguration {
+            kinds_with_separated_children: [("enum_body", ",")].into(),
+            delimiters: ["{", "}"].into(),
+            ..Default::default()
+        };
+        let enum_body = MergedCSTNode::NonTerminal {
+            kind: "enum_body",
+            children: vec![
+                make_terminal("{", "{"),
+                make_terminal("enum_constant", "A"),
+                make_terminal("enum_constant", "B"),
+                make_terminal(",", ","),
+                make_terminal("}", "}"),
+            ],
+        };
+
+        assert_eq!(" { A , B , }", enum_body.print(&config));
+    }
+
+    #[test]
+    fn separating_tokens_are_printed_between_elements_around_delimiters() {
+        let config = PrinterConfiguration {
+            kinds_with_separated_children: [("argument_list", ",")].into(),
+            delimiters: ["(", ")", "block_comment"].into(),
+            ..Default::default()
+        };
+        let argument_list = MergedCSTNode::NonTerminal {
+            kind: "argument_list",
+            children: vec![
+                make_terminal("(", "("),
+                make_terminal("identifier", "a"),
+                make_terminal("block_comment", "/* b */"),
+                make_terminal("identifier", "c"),
+                make_terminal(")", ")"),
+            ],
+        };
+
+        assert_eq!(" ( a /* b */ , c )", argument_list.print(&config));
+    }
 }
@@ -1,7 +1,7 @@
 use matching::Matchings;
 use model::cst_node::NonTerminal;
 
-use crate::{MergeError, MergedCSTNode};
+use crate::{MergeError, MergedCSTNode, RevisionSources};
 
 pub fn ordered_merge<'a>(
     left: &'a NonTerminal<'a>,
@@ -9,6 +9,7 @@ pub fn ordered_merge<'a>(
     base_left_matchings: &'a Matchings<'a>,
     base_right_matchings: &'a Matchings<'a>,
     left_right_matchings: &'a Matchings<'a>,
+    sources: &RevisionSources,
 ) -> Result<MergedCSTNode<'a>, MergeError> {
     // Nodes of different kind, early return
     if left.kind != right.kind {
@@ -49,6 +50,7 @@ pub fn ordered_merge<'a>(
                     base_left_matchings,
                     base_right_matchings,
                     left_right_matchings,
+                    sources,
                 )?);
 
                 cur_left_option = children_left_it.next();
@@ -62,6 +64,7 @@ pub fn ordered_merge<'a>(
                     base_left_matchings,
                     base_right_matchings,
                     left_right_matchings,
+                    sources,
                 )?);
 
                 cur_left_option = children_left_it.next();
@@ -212,19 +215,27 @@ mod tests {
     use std::vec;
 
     use matching::{matching_configuration::MatchingConfiguration, ordered, Matchings};
-    use model::{cst_node::NonTerminal, cst_node::Terminal, CSTNode, Language, Point};
+    use matching_handlers::{get_default_java_matching_handlers, DEFAULT_JAVA_KINDS_WITH_LABEL};
+    use model::{cst_node::NonTerminal, cst_node::Terminal, CSTNode, Point};
 
-    use crate::{MergeError, MergedCSTNode};
+    use crate::{MergeError, MergedCSTNode, RevisionSources};
 
     use super::ordered_merge;
 
+    fn java_matching_configuration() -> MatchingConfiguration<'static> {
+        MatchingConfiguration::new()
+            .with_delimiters(&["{", "}", ";"])
+            .with_kinds_with_label(&DEFAULT_JAVA_KINDS_WITH_LABEL)
+            .with_handlers(get_default_java_matching_handlers())
+    }
+
     fn assert_merge_is_correct_and_idempotent_with_respect_to_parent_side<'a>(
         base: &'a CSTNode<'a>,
         parent_a: &'a CSTNode<'a>,
         parent_b: &'a CSTNode<'a>,
         expected_merge: &'a MergedCSTNode<'a>,
     ) -> Result<(), MergeError> {
-        let matching_configuration = MatchingConfiguration::from(Language::Java);
+        let matching_configuration = java_matching_configuration();
 
         let matchings_base_parent_a =
             ordered::calculate_matchings(base, parent_a, &matching_configuration);
@@ -239,6 +250,7 @@ mod tests {
             &matchings_base_parent_a,
             &matchings_base_parent_b,
             &matchings_parents,
+            &RevisionSources::default(),
         )?;
         let merged_tree_swap = ordered_merge(
             parent_b.try_into().unwrap(),
@@ -246,6 +258,7 @@ mod tests {
             &matchings_base_parent_b,
             &matchings_base_parent_a,
             &matchings_parents,
+            &RevisionSources::default(),
         )?;
 
         assert_eq!(expected_merge, &merged_tree);
@@ -260,7 +273,7 @@ mod tests {
         parent_b: &CSTNode,
         expected_merge: &MergedCSTNode,
     ) -> Result<(), MergeError> {
-        let matching_configuration = MatchingConfiguration::from(Language::Java);
+        let matching_configuration = java_matching_configuration();
 
         let matchings_base_parent_a =
             ordered::calculate_matchings(base, parent_a, &matching_configuration);
@@ -275,6 +288,7 @@ mod tests {
             &matchings_base_parent_a,
             &matchings_base_parent_b,
             &matchings_parents,
+            &RevisionSources::default(),
         )?;
 
         assert_eq!(expected_merge, &merged_tree);
@@ -288,6 +302,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![
@@ -298,6 +314,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_a",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -306,6 +324,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_b",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -325,6 +345,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![],
@@ -333,6 +355,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![
@@ -343,6 +367,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_a",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -351,6 +377,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_b",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -370,6 +398,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![],
@@ -379,6 +409,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![],
@@ -388,6 +420,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::Terminal(Terminal {
@@ -397,6 +431,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value_a",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             })],
         });
 
@@ -423,6 +459,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::Terminal(Terminal {
@@ -432,6 +470,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value_a",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             })],
         });
 
@@ -439,6 +479,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::Terminal(Terminal {
@@ -448,6 +490,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value_a",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             })],
         });
 
@@ -455,6 +499,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![
@@ -465,6 +511,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_a",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -473,6 +521,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_b",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -507,6 +557,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::Terminal(Terminal {
@@ -516,6 +568,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value_a",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             })],
         });
 
@@ -523,6 +577,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::Terminal(Terminal {
@@ -532,6 +588,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value_b",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             })],
         });
 
@@ -539,6 +597,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::Terminal(Terminal {
@@ -548,6 +608,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value_a",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             })],
         });
 
@@ -575,12 +637,16 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::NonTerminal(NonTerminal {
                 id: uuid::Uuid::new_v4(),
                 kind: "subtree",
                 are_children_unordered: false,
+                identity: vec![],
+                injected_language: None,
                 start_position: Point { row: 0, column: 0 },
                 end_position: Point { row: 0, column: 7 },
                 children: vec![CSTNode::Terminal(Terminal {
@@ -590,6 +656,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_a",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 })],
             })],
         });
@@ -598,12 +666,16 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::NonTerminal(NonTerminal {
                 id: uuid::Uuid::new_v4(),
                 kind: "another_subtree",
                 are_children_unordered: false,
+                identity: vec![],
+                injected_language: None,
                 start_position: Point { row: 0, column: 0 },
                 end_position: Point { row: 0, column: 7 },
                 children: vec![CSTNode::Terminal(Terminal {
@@ -613,6 +685,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_b",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 })],
             })],
         });
@@ -621,12 +695,16 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::NonTerminal(NonTerminal {
                 id: uuid::Uuid::new_v4(),
                 kind: "subtree",
                 are_children_unordered: false,
+                identity: vec![],
+                injected_language: None,
                 start_position: Point { row: 0, column: 0 },
                 end_position: Point { row: 0, column: 7 },
                 children: vec![CSTNode::Terminal(Terminal {
@@ -636,11 +714,13 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_c",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 })],
             })],
         });
 
-        let matching_configuration = MatchingConfiguration::from(Language::Java);
+        let matching_configuration = java_matching_configuration();
         let matchings_base_parent_a =
             ordered::calculate_matchings(&base, &parent_a, &matching_configuration);
         let matchings_base_parent_b =
@@ -654,6 +734,7 @@ mod tests {
             &matchings_base_parent_a,
             &matchings_base_parent_b,
             &matchings_parents,
+            &RevisionSources::default(),
         )?;
         let merged_tree_swap = ordered_merge(
             (&parent_b).try_into().unwrap(),
@@ -661,6 +742,7 @@ mod tests {
             &matchings_base_parent_b,
             &matchings_base_parent_a,
             &matchings_parents,
+            &RevisionSources::default(),
         )?;
 
         assert_eq!(
@@ -724,6 +806,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![],
@@ -733,6 +817,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::Terminal(Terminal {
@@ -742,6 +828,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value_a",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             })],
         });
 
@@ -749,6 +837,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::Terminal(Terminal {
@@ -758,6 +848,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value_b",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             })],
         });
 
@@ -788,6 +880,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![
@@ -798,6 +892,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_a",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -806,6 +902,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_b",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -814,6 +912,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![
@@ -824,6 +924,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_a",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -832,6 +934,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_b",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -840,6 +944,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::Terminal(Terminal {
@@ -849,6 +955,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value_b",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             })],
         });
 
@@ -871,6 +979,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![
@@ -878,6 +988,8 @@ mod tests {
                     id: uuid::Uuid::new_v4(),
                     kind: "subtree",
                     are_children_unordered: false,
+                    identity: vec![],
+                    injected_language: None,
                     start_position: Point { row: 0, column: 0 },
                     end_position: Point { row: 0, column: 7 },
                     children: vec![CSTNode::Terminal(Terminal {
@@ -887,6 +999,8 @@ mod tests {
                         end_position: Point { row: 0, column: 7 },
                         value: "value_b",
                         is_block_end_delimiter: false,
+                        is_opaque: false,
+                        identity: vec![],
                     })],
                 }),
                 CSTNode::Terminal(Terminal {
@@ -896,6 +1010,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_a",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -904,6 +1020,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![
@@ -911,6 +1029,8 @@ mod tests {
                     id: uuid::Uuid::new_v4(),
                     kind: "subtree",
                     are_children_unordered: false,
+                    identity: vec![],
+                    injected_language: None,
                     start_position: Point { row: 0, column: 0 },
                     end_position: Point { row: 0, column: 7 },
                     children: vec![CSTNode::Terminal(Terminal {
@@ -920,6 +1040,8 @@ mod tests {
                         end_position: Point { row: 0, column: 7 },
                         value: "value_c",
                         is_block_end_delimiter: false,
+                        is_opaque: false,
+                        identity: vec![],
                     })],
                 }),
                 CSTNode::Terminal(Terminal {
@@ -929,6 +1051,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_a",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -937,6 +1061,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::Terminal(Terminal {
@@ -946,6 +1072,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value_a",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             })],
         });
 
@@ -1006,6 +1134,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![
@@ -1016,6 +1146,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_a",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -1024,6 +1156,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_c",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -1032,6 +1166,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![
@@ -1042,6 +1178,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_a",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -1050,6 +1188,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_c",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -1058,6 +1198,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![
@@ -1068,6 +1210,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_a",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -1076,6 +1220,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_b",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -1084,6 +1230,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_c",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -1121,6 +1269,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::Terminal(Terminal {
@@ -1130,6 +1280,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value_b",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             })],
         });
 
@@ -1137,6 +1289,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::Terminal(Terminal {
@@ -1146,6 +1300,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value_a",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             })],
         });
 
@@ -1153,6 +1309,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![
@@ -1163,6 +1321,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_b",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -1171,6 +1331,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_a",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -1198,12 +1360,16 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::NonTerminal(NonTerminal {
                 id: uuid::Uuid::new_v4(),
                 kind: "subtree",
                 are_children_unordered: false,
+                identity: vec![],
+                injected_language: None,
                 start_position: Point { row: 0, column: 0 },
                 end_position: Point { row: 0, column: 7 },
                 children: vec![CSTNode::Terminal(Terminal {
@@ -1213,6 +1379,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_b",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 })],
             })],
         });
@@ -1221,6 +1389,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::Terminal(Terminal {
@@ -1230,6 +1400,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value_a",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             })],
         });
 
@@ -1237,6 +1409,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![
@@ -1244,6 +1418,8 @@ mod tests {
                     id: uuid::Uuid::new_v4(),
                     kind: "subtree",
                     are_children_unordered: false,
+                    identity: vec![],
+                    injected_language: None,
                     start_position: Point { row: 0, column: 0 },
                     end_position: Point { row: 0, column: 7 },
                     children: vec![CSTNode::Terminal(Terminal {
@@ -1253,6 +1429,8 @@ mod tests {
                         end_position: Point { row: 0, column: 7 },
                         value: "value_c",
                         is_block_end_delimiter: false,
+                        is_opaque: false,
+                        identity: vec![],
                     })],
                 }),
                 CSTNode::Terminal(Terminal {
@@ -1262,6 +1440,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_a",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -1322,6 +1502,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![],
@@ -1331,6 +1513,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::Terminal(Terminal {
@@ -1340,6 +1524,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value_a",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             })],
         });
 
@@ -1347,6 +1533,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![
@@ -1357,6 +1545,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_c",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -1365,6 +1555,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_a",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -1399,6 +1591,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![
@@ -1409,6 +1603,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_b",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -1417,6 +1613,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_c",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -1425,6 +1623,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::Terminal(Terminal {
@@ -1434,6 +1634,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value_b",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             })],
         });
 
@@ -1441,6 +1643,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::Terminal(Terminal {
@@ -1450,6 +1654,8 @@ mod tests {
                 end_position: Point { row: 0, column: 7 },
                 value: "value_c",
                 is_block_end_delimiter: false,
+                is_opaque: false,
+                identity: vec![],
             })],
         });
 
@@ -1474,6 +1680,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![
@@ -1481,6 +1689,8 @@ mod tests {
                     id: uuid::Uuid::new_v4(),
                     kind: "subtree_a",
                     are_children_unordered: false,
+                    identity: vec![],
+                    injected_language: None,
                     start_position: Point { row: 0, column: 0 },
                     end_position: Point { row: 0, column: 7 },
                     children: vec![CSTNode::Terminal(Terminal {
@@ -1490,12 +1700,16 @@ mod tests {
                         end_position: Point { row: 0, column: 7 },
                         value: "value_b",
                         is_block_end_delimiter: false,
+                        is_opaque: false,
+                        identity: vec![],
                     })],
                 }),
                 CSTNode::NonTerminal(NonTerminal {
                     id: uuid::Uuid::new_v4(),
                     kind: "subtree_b",
                     are_children_unordered: false,
+                    identity: vec![],
+                    injected_language: None,
                     start_position: Point { row: 0, column: 0 },
                     end_position: Point { row: 0, column: 7 },
                     children: vec![CSTNode::Terminal(Terminal {
@@ -1505,6 +1719,8 @@ mod tests {
                         end_position: Point { row: 0, column: 7 },
                         value: "value_c",
                         is_block_end_delimiter: false,
+                        is_opaque: false,
+                        identity: vec![],
                     })],
                 }),
             ],
@@ -1514,12 +1730,16 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "kind",
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
             children: vec![CSTNode::NonTerminal(NonTerminal {
                 id: uuid::Uuid::new_v4(),
                 kind: "subtree_b",
                 are_children_unordered: false,
+                identity: vec![],
+                injected_language: None,
                 start_position: Point { row: 0, column: 0 },
                 end_position: Point { row: 0, column: 7 },
                 children: vec![CSTNode::Terminal(Terminal {
@@ -1529,6 +1749,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_c",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 })],
             })],
         });
@@ -1536,6 +1758,8 @@ mod tests {
         let parent_b = CSTNode::NonTerminal(NonTerminal {
             id: uuid::Uuid::new_v4(),
             are_children_unordered: false,
+            identity: vec![],
+            injected_language: None,
             kind: "kind",
             start_position: Point { row: 0, column: 0 },
             end_position: Point { row: 0, column: 7 },
@@ -1543,6 +1767,8 @@ mod tests {
                 id: uuid::Uuid::new_v4(),
                 kind: "subtree_a",
                 are_children_unordered: false,
+                identity: vec![],
+                injected_language: None,
                 start_position: Point { row: 0, column: 0 },
                 end_position: Point { row: 0, column: 7 },
                 children: vec![CSTNode::Terminal(Terminal {
@@ -1552,6 +1778,8 @@ mod tests {
                     end_position: Point { row: 0, column: 7 },
                     value: "value_c",
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 })],
             })],
         });
@@ -1603,6 +1831,8 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             children: vec![],
             are_children_unordered: true,
+            identity: vec![],
+            injected_language: None,
         };
         let kind_b = NonTerminal {
             id: uuid::Uuid::new_v4(),
@@ -1611,10 +1841,19 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             children: vec![],
             are_children_unordered: true,
+            identity: vec![],
+            injected_language: None,
         };
 
         let matchings = Matchings::empty();
-        let result = ordered_merge(&kind_a, &kind_b, &matchings, &matchings, &matchings);
+        let result = ordered_merge(
+            &kind_a,
+            &kind_b,
+            &matchings,
+            &matchings,
+            &matchings,
+            &RevisionSources::default(),
+        );
 
         assert!(result.is_err());
         assert_eq!(
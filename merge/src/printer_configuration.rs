@@ -0,0 +1,16 @@
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Default, Clone)]
+pub struct PrinterConfiguration {
+    /// Kinds whose children must be printed on separate lines, as the language relies on
+    /// line breaks to tell statements apart.
+    pub kinds_with_line_separated_children: HashSet<&'static str>,
+    /// Kinds that must be printed on lines of their own, e.g. the contents of code fences.
+    pub kinds_on_separate_lines: HashSet<&'static str>,
+    /// Kinds whose children are separated by a token, e.g. `,`, which is dropped while parsing
+    /// and printed back between them.
+    pub kinds_with_separated_children: HashMap<&'static str, &'static str>,
+    /// Kinds of the children of separated lists that are not elements of the list, e.g. its
+    /// brackets, next to which no separator is printed.
+    pub delimiters: HashSet<&'static str>,
+}
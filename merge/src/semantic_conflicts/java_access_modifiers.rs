@@ -0,0 +1,58 @@
+use crate::MergedCSTNode;
+
+const ACCESS_MODIFIERS: [&str; 3] = ["public", "protected", "private"];
+
+/// Reports a conflict when the revisions change the access modifier of a declaration to
+/// different ones. Modifiers are merged as an unordered set, so both end up in the merged tree.
+pub fn detect_java_access_modifier_conflicts(merged: MergedCSTNode) -> MergedCSTNode {
+    match merged {
+        MergedCSTNode::NonTerminal {
+            kind: "modifiers",
+            children,
+        } => MergedCSTNode::NonTerminal {
+            kind: "modifiers",
+            children: report_conflicting_access_modifiers(children),
+        },
+        MergedCSTNode::NonTerminal { kind, children } => MergedCSTNode::NonTerminal {
+            kind,
+            children: children
+                .into_iter()
+                .map(detect_java_access_modifier_conflicts)
+                .collect(),
+        },
+        _ => merged,
+    }
+}
+
+fn is_access_modifier(node: &MergedCSTNode) -> bool {
+    matches!(node, MergedCSTNode::Terminal { kind, .. } if ACCESS_MODIFIERS.contains(kind))
+}
+
+/// Replaces the access modifiers by a conflict between them. The children kept from left come
+/// before the ones only right added, so the first one is taken as the left one.
+fn report_conflicting_access_modifiers(children: Vec<MergedCSTNode>) -> Vec<MergedCSTNode> {
+    let (access_modifiers, mut others): (Vec<_>, Vec<_>) =
+        children.iter().cloned().partition(is_access_modifier);
+    if access_modifiers.len() < 2 {
+        return children;
+    }
+
+    log::info!(
+        "Access modifiers \"{}\" and \"{}\" are mutually exclusive",
+        access_modifiers[0],
+        access_modifiers[1]
+    );
+    let position = children
+        .iter()
+        .position(is_access_modifier)
+        .expect("There are access modifiers");
+    let mut access_modifiers = access_modifiers.into_iter();
+    others.insert(
+        position,
+        MergedCSTNode::Conflict {
+            left: access_modifiers.next().map(Box::new),
+            right: access_modifiers.next().map(Box::new),
+        },
+    );
+    others
+}
@@ -0,0 +1,5 @@
+mod java_access_modifiers;
+mod protobuf_field_numbers;
+
+pub use java_access_modifiers::detect_java_access_modifier_conflicts;
+pub use protobuf_field_numbers::detect_protobuf_field_number_conflicts;
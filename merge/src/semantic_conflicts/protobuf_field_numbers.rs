@@ -0,0 +1,278 @@
+use std::collections::BTreeMap;
+
+use model::{cst_node::NonTerminal, CSTNode};
+
+use crate::MergedCSTNode;
+
+const FIELD_KINDS: [&str; 3] = ["field", "map_field", "oneof_field"];
+
+/// Names of the enclosing messages, from the outermost one.
+type MessagePath = Vec<String>;
+
+#[derive(Debug)]
+struct Field<'a> {
+    name: String,
+    number: Option<u64>,
+    node: &'a CSTNode<'a>,
+}
+
+impl Field<'_> {
+    fn is_in_base(&self, base_fields: &[Field]) -> bool {
+        base_fields
+            .iter()
+            .any(|field| field.name == self.name && field.number == self.number)
+    }
+
+    /// Two fields clash if they share either the name or the number, but not both.
+    fn clashes_with(&self, other: &Field) -> bool {
+        (self.name == other.name) != (self.number == other.number)
+    }
+}
+
+/// Reports a conflict when both revisions add (or renumber) fields of the same message that end
+/// up with the same field number but different names, or the same name but different numbers.
+pub fn detect_protobuf_field_number_conflicts<'a>(
+    base: &'a CSTNode<'a>,
+    left: &'a CSTNode<'a>,
+    right: &'a CSTNode<'a>,
+    merged: MergedCSTNode<'a>,
+) -> MergedCSTNode<'a> {
+    let base_fields = collect_fields(base);
+    let left_fields = collect_fields(left);
+    let right_fields = collect_fields(right);
+
+    let mut conflicts: BTreeMap<MessagePath, Vec<(&Field, &Field)>> = BTreeMap::new();
+    for (message_path, fields_in_left) in left_fields.iter() {
+        let Some(fields_in_right) = right_fields.get(message_path) else {
+            continue;
+        };
+        let fields_in_base = base_fields
+            .get(message_path)
+            .map_or(&[] as &[Field], |fields| fields.as_slice());
+
+        let added_by_left = fields_in_left
+            .iter()
+            .filter(|field| !field.is_in_base(fields_in_base));
+        let added_by_right: Vec<&Field> = fields_in_right
+            .iter()
+            .filter(|field| !field.is_in_base(fields_in_base))
+            .collect();
+
+        for left_field in added_by_left {
+            if let Some(right_field) = added_by_right
+                .iter()
+                .find(|right_field| left_field.clashes_with(right_field))
+            {
+                log::info!(
+                    "Fields \"{}\" and \"{}\" of message \"{}\" have conflicting numbers",
+                    left_field.name,
+                    right_field.name,
+                    message_path.join(".")
+                );
+                conflicts
+                    .entry(message_path.clone())
+                    .or_default()
+                    .push((left_field, right_field));
+            }
+        }
+    }
+
+    if conflicts.is_empty() {
+        return merged;
+    }
+
+    report_conflicts(merged, &mut vec![], &conflicts)
+}
+
+fn collect_fields<'a>(root: &'a CSTNode<'a>) -> BTreeMap<MessagePath, Vec<Field<'a>>> {
+    let mut fields = BTreeMap::new();
+    collect_fields_of_node(root, &mut vec![], &mut fields);
+    fields
+}
+
+fn collect_fields_of_node<'a>(
+    node: &'a CSTNode<'a>,
+    message_path: &mut MessagePath,
+    fields: &mut BTreeMap<MessagePath, Vec<Field<'a>>>,
+) {
+    let CSTNode::NonTerminal(NonTerminal { kind, children, .. }) = node else {
+        return;
+    };
+
+    if FIELD_KINDS.contains(kind) {
+        let name = find_terminal_value(children, "identifier");
+        let number = children
+            .iter()
+            .find(|child| child.kind() == "field_number")
+            .and_then(|child| parse_field_number(&child.contents()));
+
+        if let Some(name) = name {
+            fields.entry(message_path.clone()).or_default().push(Field {
+                name: name.to_string(),
+                number,
+                node,
+            });
+        }
+        return;
+    }
+
+    let message_name = match *kind {
+        "message" => children
+            .iter()
+            .find(|child| child.kind() == "message_name")
+            .map(|child| child.contents().trim().to_string()),
+        _ => None,
+    };
+
+    if let Some(message_name) = &message_name {
+        message_path.push(message_name.to_owned());
+    }
+    for child in children {
+        collect_fields_of_node(child, message_path, fields);
+    }
+    if message_name.is_some() {
+        message_path.pop();
+    }
+}
+
+fn find_terminal_value<'a>(children: &'a [CSTNode<'a>], kind: &str) -> Option<&'a str> {
+    children.iter().find_map(|child| match child {
+        CSTNode::Terminal(terminal) if terminal.kind == kind => Some(terminal.value),
+        _ => None,
+    })
+}
+
+fn parse_field_number(number: &str) -> Option<u64> {
+    let number = number.trim();
+    if let Some(hexadecimal) = number
+        .strip_prefix("0x")
+        .or_else(|| number.strip_prefix("0X"))
+    {
+        u64::from_str_radix(hexadecimal, 16).ok()
+    } else if number.len() > 1 && number.starts_with('0') {
+        u64::from_str_radix(&number[1..], 8).ok()
+    } else {
+        number.parse().ok()
+    }
+}
+
+fn report_conflicts<'a>(
+    node: MergedCSTNode<'a>,
+    message_path: &mut MessagePath,
+    conflicts: &BTreeMap<MessagePath, Vec<(&Field<'a>, &Field<'a>)>>,
+) -> MergedCSTNode<'a> {
+    let MergedCSTNode::NonTerminal { kind, children } = node else {
+        return node;
+    };
+
+    let message_name = match kind {
+        "message" => children.iter().find_map(|child| match child {
+            MergedCSTNode::NonTerminal {
+                kind: "message_name",
+                children,
+            } => find_merged_terminal_value(children, "identifier"),
+            _ => None,
+        }),
+        _ => None,
+    };
+
+    if let Some(message_name) = &message_name {
+        message_path.push(message_name.to_owned());
+    }
+    let mut children: Vec<MergedCSTNode<'a>> = children
+        .into_iter()
+        .map(|child| report_conflicts(child, message_path, conflicts))
+        .collect();
+    if kind == "message_body" {
+        for (left_field, right_field) in conflicts.get(message_path).into_iter().flatten() {
+            let mut conflict = Some(MergedCSTNode::Conflict {
+                left: Some(Box::new(left_field.node.to_owned().into())),
+                right: Some(Box::new(right_field.node.to_owned().into())),
+            });
+            children = replace_fields(
+                children,
+                &[left_field.name.as_str(), right_field.name.as_str()],
+                &mut conflict,
+            );
+
+            // The clashing fields could not be found, so the conflict goes right before the "}"
+            if let Some(conflict) = conflict {
+                children.insert(children.len().saturating_sub(1), conflict);
+            }
+        }
+    }
+    if message_name.is_some() {
+        message_path.pop();
+    }
+
+    MergedCSTNode::NonTerminal { kind, children }
+}
+
+/// Replaces the first field with one of the given names by the conflict, and drops the others,
+/// as they are already part of the conflict.
+fn replace_fields<'a>(
+    children: Vec<MergedCSTNode<'a>>,
+    names: &[&str],
+    conflict: &mut Option<MergedCSTNode<'a>>,
+) -> Vec<MergedCSTNode<'a>> {
+    children
+        .into_iter()
+        .filter_map(|child| match child {
+            MergedCSTNode::NonTerminal { kind, children } if FIELD_KINDS.contains(&kind) => {
+                match find_merged_terminal_value(&children, "identifier") {
+                    Some(name) if names.contains(&name.as_str()) => conflict.take(),
+                    _ => Some(MergedCSTNode::NonTerminal { kind, children }),
+                }
+            }
+            MergedCSTNode::NonTerminal {
+                kind: "oneof",
+                children,
+            } => Some(MergedCSTNode::NonTerminal {
+                kind: "oneof",
+                children: replace_fields(children, names, conflict),
+            }),
+            _ => Some(child),
+        })
+        .collect()
+}
+
+fn find_merged_terminal_value(children: &[MergedCSTNode], kind: &str) -> Option<String> {
+    children.iter().find_map(|child| match child {
+        MergedCSTNode::Terminal {
+            kind: child_kind,
+            value,
+        } if *child_kind == kind => Some(value.to_owned()),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{cst_node::Terminal, CSTNode};
+
+    use super::{parse_field_number, Field};
+
+    #[test]
+    fn field_numbers_may_be_written_in_decimal_hexadecimal_or_octal() {
+        assert_eq!(Some(10), parse_field_number(" 10"));
+        assert_eq!(Some(10), parse_field_number("0xA"));
+        assert_eq!(Some(10), parse_field_number("012"));
+        assert_eq!(Some(0), parse_field_number("0"));
+        assert_eq!(None, parse_field_number("max"));
+    }
+
+    #[test]
+    fn fields_clash_if_they_share_only_the_name_or_only_the_number() {
+        let node = CSTNode::Terminal(Terminal::default());
+        let field = |name: &str, number: u64| Field {
+            name: name.to_string(),
+            number: Some(number),
+            node: &node,
+        };
+
+        assert!(field("id", 1).clashes_with(&field("uuid", 1)));
+        assert!(field("id", 1).clashes_with(&field("id", 2)));
+        assert!(!field("id", 1).clashes_with(&field("id", 1)));
+        assert!(!field("id", 1).clashes_with(&field("uuid", 2)));
+    }
+}
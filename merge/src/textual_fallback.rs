@@ -0,0 +1,220 @@
+use matching::Matchings;
+use model::{CSTNode, Point};
+
+use crate::{MergeError, MergedCSTNode};
+
+/// The source of each revision, which subtrees that can not be merged structurally are sliced
+/// from to be merged line by line.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RevisionSources<'a> {
+    pub base: &'a str,
+    pub left: &'a str,
+    pub right: &'a str,
+}
+
+/// Merges a subtree that could not be merged structurally line by line, as git would, from the
+/// source of each revision, so that the parts the parsing handlers dropped, e.g. comments, are
+/// kept.
+pub fn textual_fallback<'a>(
+    left: &'a CSTNode<'a>,
+    right: &'a CSTNode<'a>,
+    base_left_matchings: &'a Matchings<'a>,
+    sources: &RevisionSources,
+    error: MergeError,
+) -> MergedCSTNode<'a> {
+    log::debug!(
+        "Merging {} at line {} line by line: {}",
+        left.kind(),
+        left.start_position().row + 1,
+        error
+    );
+
+    let base = base_left_matchings
+        .find_matching_for(left)
+        .map(|matching| get_source_text(sources.base, matching.matching_node))
+        .unwrap_or_default();
+    let (value, has_conflict) = match merge_lines(
+        base,
+        get_source_text(sources.left, left),
+        get_source_text(sources.right, right),
+    ) {
+        Ok(value) => (value, false),
+        Err(value) => (value, true),
+    };
+
+    MergedCSTNode::TextualFallback {
+        kind: left.kind(),
+        value,
+        has_conflict,
+        reason: error.to_string(),
+    }
+}
+
+/// Conflict markers as long as the ones of the conflicts found while merging structurally.
+const CONFLICT_MARKER_LENGTH: usize = 9;
+
+/// The result holds conflict markers if the revisions could not be merged, written the same way
+/// as the ones of the conflicts found while merging structurally.
+fn merge_lines(base: &str, left: &str, right: &str) -> Result<String, String> {
+    let start_marker = "<".repeat(CONFLICT_MARKER_LENGTH);
+    let end_marker = ">".repeat(CONFLICT_MARKER_LENGTH);
+
+    let mut options = diffy::MergeOptions::new();
+    options
+        .set_conflict_marker_length(CONFLICT_MARKER_LENGTH)
+        .set_conflict_style(diffy::ConflictStyle::Merge);
+    // The markers of structured conflicts do not name the sides
+    options.merge(base, left, right).map_err(|conflict| {
+        conflict
+            .replace(
+                &format!("{} ours\n", start_marker),
+                &format!("{}\n", start_marker),
+            )
+            .replace(
+                &format!("{} theirs\n", end_marker),
+                &format!("{}\n", end_marker),
+            )
+    })
+}
+
+/// The text of the node in the source of its revision, from its start to its end position.
+fn get_source_text<'s>(src: &'s str, node: &CSTNode) -> &'s str {
+    let start = get_byte_offset(src, node.start_position());
+    let end = get_byte_offset(src, node.end_position());
+    src.get(start..end.max(start)).unwrap_or_default()
+}
+
+fn get_byte_offset(src: &str, position: Point) -> usize {
+    let line_start: usize = src
+        .split_inclusive('\n')
+        .take(position.row)
+        .map(str::len)
+        .sum();
+    (line_start + position.column).min(src.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use matching::{MatchingEntry, Matchings};
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode, Point,
+    };
+    use unordered_pair::UnorderedPair;
+
+    use super::{get_source_text, textual_fallback, RevisionSources};
+    use crate::{MergeError, MergedCSTNode};
+
+    fn make_terminal<'a>(value: &'a str, row: usize, column: usize) -> CSTNode<'a> {
+        CSTNode::Terminal(Terminal {
+            id: uuid::Uuid::new_v4(),
+            kind: "token",
+            value,
+            start_position: Point { row, column },
+            end_position: Point {
+                row,
+                column: column + value.len(),
+            },
+            ..Default::default()
+        })
+    }
+
+    fn make_block<'a>(children: Vec<CSTNode<'a>>) -> CSTNode<'a> {
+        CSTNode::NonTerminal(NonTerminal {
+            id: uuid::Uuid::new_v4(),
+            kind: "block",
+            start_position: children[0].start_position(),
+            end_position: children[children.len() - 1].end_position(),
+            children,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn the_source_text_is_sliced_from_the_positions_of_the_node() {
+        // The comment was dropped by the parsing handlers, but it is still part of the source
+        let src = "class A {\n    {\n        // Sum\n        a = 1;\n    }\n}\n";
+        let node = make_block(vec![
+            make_terminal("{", 1, 4),
+            make_terminal("a", 3, 8),
+            make_terminal("=", 3, 10),
+            make_terminal("1", 3, 12),
+            make_terminal(";", 3, 13),
+            make_terminal("}", 4, 4),
+        ]);
+
+        assert_eq!(
+            "{\n        // Sum\n        a = 1;\n    }",
+            get_source_text(src, &node)
+        );
+    }
+
+    #[test]
+    fn conflicts_are_marked_as_the_ones_found_while_merging_structurally() {
+        let block = |value| {
+            make_block(vec![
+                make_terminal("{", 0, 0),
+                make_terminal(value, 1, 4),
+                make_terminal("}", 2, 0),
+            ])
+        };
+        let (base, left, right) = (block("a"), block("b"), block("c"));
+        let matchings = Matchings::from_single(
+            UnorderedPair(&base, &left),
+            MatchingEntry {
+                score: 2,
+                is_perfect_match: false,
+            },
+        );
+        let sources = RevisionSources {
+            base: "{\n    a\n}",
+            left: "{\n    b\n}",
+            right: "{\n    c\n}",
+        };
+
+        let result = textual_fallback(
+            &left,
+            &right,
+            &matchings,
+            &sources,
+            MergeError::MergingTerminalWithNonTerminal,
+        );
+
+        assert!(result.has_conflict());
+        assert_eq!(
+            "{\n<<<<<<<<<\n    b\n=========\n    c\n>>>>>>>>>\n}",
+            result.to_string()
+        );
+    }
+
+    #[test]
+    fn subtrees_without_a_base_are_merged_as_if_both_sides_added_them() {
+        let left = make_block(vec![make_terminal("{", 0, 0), make_terminal("}", 1, 0)]);
+        let right = make_block(vec![
+            make_terminal("{", 0, 0),
+            make_terminal("a", 1, 4),
+            make_terminal("}", 2, 0),
+        ]);
+        let matchings = Matchings::empty();
+        let sources = RevisionSources {
+            base: "",
+            left: "{\n}",
+            right: "{\n    a\n}",
+        };
+
+        let result = textual_fallback(
+            &left,
+            &right,
+            &matchings,
+            &sources,
+            MergeError::MergingTerminalWithNonTerminal,
+        );
+
+        assert!(result.has_conflict());
+        assert_eq!(
+            vec![("block", "Merging terminal with non-terminal")],
+            result.get_textual_fallbacks()
+        );
+        assert!(matches!(result, MergedCSTNode::TextualFallback { .. }));
+    }
+}
@@ -6,7 +6,7 @@ use model::{
     CSTNode,
 };
 
-use crate::{merge, MergeError, MergedCSTNode};
+use crate::{merge, MergeError, MergedCSTNode, RevisionSources};
 
 pub fn unordered_merge<'a>(
     left: &'a NonTerminal<'a>,
@@ -14,6 +14,7 @@ pub fn unordered_merge<'a>(
     base_left_matchings: &'a Matchings<'a>,
     base_right_matchings: &'a Matchings<'a>,
     left_right_matchings: &'a Matchings<'a>,
+    sources: &RevisionSources,
 ) -> Result<MergedCSTNode<'a>, MergeError> {
     // Nodes of different kind, early return
     if left.kind != right.kind {
@@ -54,6 +55,7 @@ pub fn unordered_merge<'a>(
                     base_left_matchings,
                     base_right_matchings,
                     left_right_matchings,
+                    sources,
                 )?);
                 processed_nodes.insert(left_child.id());
                 processed_nodes.insert(right_matching.matching_node.id());
@@ -77,6 +79,7 @@ pub fn unordered_merge<'a>(
                     base_left_matchings,
                     base_right_matchings,
                     left_right_matchings,
+                    sources,
                 )?);
                 processed_nodes.insert(left_child.id());
                 processed_nodes.insert(right_matching.matching_node.id());
@@ -105,6 +108,7 @@ pub fn unordered_merge<'a>(
                     base_left_matchings,
                     base_right_matchings,
                     left_right_matchings,
+                    sources,
                 )?);
             }
             // Removed in left
@@ -125,6 +129,7 @@ pub fn unordered_merge<'a>(
                     base_left_matchings,
                     base_right_matchings,
                     left_right_matchings,
+                    sources,
                 )?);
             }
         }
@@ -141,22 +146,30 @@ mod tests {
     use matching::{
         matching_configuration::MatchingConfiguration, unordered::calculate_matchings, Matchings,
     };
+    use matching_handlers::{get_default_java_matching_handlers, DEFAULT_JAVA_KINDS_WITH_LABEL};
     use model::{
         cst_node::{NonTerminal, Terminal},
-        CSTNode, Language, Point,
+        CSTNode, Point,
     };
 
-    use crate::{MergeError, MergedCSTNode};
+    use crate::{MergeError, MergedCSTNode, RevisionSources};
 
     use super::unordered_merge;
 
+    fn java_matching_configuration() -> MatchingConfiguration<'static> {
+        MatchingConfiguration::new()
+            .with_delimiters(&["{", "}", ";"])
+            .with_kinds_with_label(&DEFAULT_JAVA_KINDS_WITH_LABEL)
+            .with_handlers(get_default_java_matching_handlers())
+    }
+
     fn assert_merge_is_correct_and_idempotent_with_respect_to_parent_side(
         base: &CSTNode,
         parent_a: &CSTNode,
         parent_b: &CSTNode,
         expected_merge: &MergedCSTNode,
     ) -> Result<(), MergeError> {
-        let matching_configuration = MatchingConfiguration::from(Language::Java);
+        let matching_configuration = java_matching_configuration();
 
         let matchings_base_parent_a = calculate_matchings(base, parent_a, &matching_configuration);
         let matchings_base_parent_b = calculate_matchings(base, parent_b, &matching_configuration);
@@ -168,6 +181,7 @@ mod tests {
             &matchings_base_parent_a,
             &matchings_base_parent_b,
             &matchings_parents,
+            &RevisionSources::default(),
         )?;
         let merged_tree_swap = unordered_merge(
             parent_b.try_into().unwrap(),
@@ -175,6 +189,7 @@ mod tests {
             &matchings_base_parent_b,
             &matchings_base_parent_a,
             &matchings_parents,
+            &RevisionSources::default(),
         )?;
 
         assert_eq!(expected_merge, &merged_tree);
@@ -189,7 +204,7 @@ mod tests {
         parent_b: &CSTNode,
         expected_merge: &MergedCSTNode,
     ) -> Result<(), MergeError> {
-        let matching_configuration = MatchingConfiguration::from(Language::Java);
+        let matching_configuration = java_matching_configuration();
 
         let matchings_base_parent_a = calculate_matchings(base, parent_a, &matching_configuration);
         let matchings_base_parent_b = calculate_matchings(base, parent_b, &matching_configuration);
@@ -201,6 +216,7 @@ mod tests {
             &matchings_base_parent_a,
             &matchings_base_parent_b,
             &matchings_parents,
+            &RevisionSources::default(),
         )?;
 
         assert_eq!(expected_merge, &merged_tree);
@@ -214,6 +230,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            identity: vec![],
+            injected_language: None,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             children: vec![
@@ -224,6 +242,8 @@ mod tests {
                     start_position: model::Point { row: 0, column: 1 },
                     end_position: model::Point { row: 0, column: 1 },
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -232,6 +252,8 @@ mod tests {
                     start_position: model::Point { row: 1, column: 1 },
                     end_position: model::Point { row: 1, column: 1 },
                     is_block_end_delimiter: true,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -240,6 +262,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            identity: vec![],
+            injected_language: None,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             children: vec![
@@ -250,6 +274,8 @@ mod tests {
                     start_position: model::Point { row: 0, column: 1 },
                     end_position: model::Point { row: 0, column: 1 },
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -258,6 +284,8 @@ mod tests {
                     start_position: model::Point { row: 1, column: 0 },
                     end_position: model::Point { row: 1, column: 4 },
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -266,6 +294,8 @@ mod tests {
                     start_position: model::Point { row: 2, column: 1 },
                     end_position: model::Point { row: 2, column: 1 },
                     is_block_end_delimiter: true,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -274,6 +304,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            identity: vec![],
+            injected_language: None,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             children: vec![
@@ -284,6 +316,8 @@ mod tests {
                     start_position: model::Point { row: 0, column: 1 },
                     end_position: model::Point { row: 0, column: 1 },
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -292,6 +326,8 @@ mod tests {
                     start_position: model::Point { row: 1, column: 1 },
                     end_position: model::Point { row: 1, column: 1 },
                     is_block_end_delimiter: true,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -325,6 +361,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            identity: vec![],
+            injected_language: None,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             children: vec![
@@ -335,6 +373,8 @@ mod tests {
                     start_position: model::Point { row: 0, column: 1 },
                     end_position: model::Point { row: 0, column: 1 },
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -343,6 +383,8 @@ mod tests {
                     start_position: model::Point { row: 1, column: 1 },
                     end_position: model::Point { row: 1, column: 1 },
                     is_block_end_delimiter: true,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -351,6 +393,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            identity: vec![],
+            injected_language: None,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             children: vec![
@@ -361,11 +405,15 @@ mod tests {
                     start_position: model::Point { row: 0, column: 1 },
                     end_position: model::Point { row: 0, column: 1 },
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::NonTerminal(NonTerminal {
                     id: uuid::Uuid::new_v4(),
                     kind: "a_method_declaration",
                     are_children_unordered: false,
+                    identity: vec![],
+                    injected_language: None,
                     start_position: model::Point { row: 1, column: 0 },
                     end_position: model::Point { row: 1, column: 4 },
                     children: vec![CSTNode::Terminal(Terminal {
@@ -375,6 +423,8 @@ mod tests {
                         start_position: model::Point { row: 0, column: 1 },
                         end_position: model::Point { row: 0, column: 1 },
                         is_block_end_delimiter: false,
+                        is_opaque: false,
+                        identity: vec![],
                     })],
                 }),
                 CSTNode::Terminal(Terminal {
@@ -384,6 +434,8 @@ mod tests {
                     start_position: model::Point { row: 2, column: 1 },
                     end_position: model::Point { row: 2, column: 1 },
                     is_block_end_delimiter: true,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -392,6 +444,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            identity: vec![],
+            injected_language: None,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             children: vec![
@@ -402,11 +456,15 @@ mod tests {
                     start_position: model::Point { row: 0, column: 1 },
                     end_position: model::Point { row: 0, column: 1 },
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::NonTerminal(NonTerminal {
                     id: uuid::Uuid::new_v4(),
                     kind: "a_method_declaration",
                     are_children_unordered: false,
+                    identity: vec![],
+                    injected_language: None,
                     start_position: model::Point { row: 1, column: 0 },
                     end_position: model::Point { row: 1, column: 4 },
                     children: vec![CSTNode::Terminal(Terminal {
@@ -416,6 +474,8 @@ mod tests {
                         start_position: model::Point { row: 0, column: 1 },
                         end_position: model::Point { row: 0, column: 1 },
                         is_block_end_delimiter: false,
+                        is_opaque: false,
+                        identity: vec![],
                     })],
                 }),
                 CSTNode::Terminal(Terminal {
@@ -425,6 +485,8 @@ mod tests {
                     start_position: model::Point { row: 2, column: 1 },
                     end_position: model::Point { row: 2, column: 1 },
                     is_block_end_delimiter: true,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -465,6 +527,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            identity: vec![],
+            injected_language: None,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             children: vec![
@@ -475,11 +539,15 @@ mod tests {
                     start_position: model::Point { row: 0, column: 1 },
                     end_position: model::Point { row: 0, column: 1 },
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::NonTerminal(NonTerminal {
                     id: uuid::Uuid::new_v4(),
                     kind: "method_declaration",
                     are_children_unordered: false,
+                    identity: vec![],
+                    injected_language: None,
                     start_position: model::Point { row: 1, column: 0 },
                     end_position: model::Point { row: 1, column: 4 },
                     children: vec![
@@ -490,6 +558,8 @@ mod tests {
                             start_position: model::Point { row: 0, column: 1 },
                             end_position: model::Point { row: 0, column: 1 },
                             is_block_end_delimiter: false,
+                            is_opaque: false,
+                            identity: vec![],
                         }),
                         CSTNode::Terminal(Terminal {
                             id: uuid::Uuid::new_v4(),
@@ -498,6 +568,8 @@ mod tests {
                             start_position: model::Point { row: 0, column: 1 },
                             end_position: model::Point { row: 0, column: 1 },
                             is_block_end_delimiter: false,
+                            is_opaque: false,
+                            identity: vec![],
                         }),
                     ],
                 }),
@@ -508,6 +580,8 @@ mod tests {
                     start_position: model::Point { row: 1, column: 1 },
                     end_position: model::Point { row: 1, column: 1 },
                     is_block_end_delimiter: true,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -516,6 +590,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            identity: vec![],
+            injected_language: None,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             children: vec![
@@ -526,11 +602,15 @@ mod tests {
                     start_position: model::Point { row: 0, column: 1 },
                     end_position: model::Point { row: 0, column: 1 },
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::NonTerminal(NonTerminal {
                     id: uuid::Uuid::new_v4(),
                     kind: "method_declaration",
                     are_children_unordered: false,
+                    identity: vec![],
+                    injected_language: None,
                     start_position: model::Point { row: 1, column: 0 },
                     end_position: model::Point { row: 1, column: 4 },
                     children: vec![
@@ -541,6 +621,8 @@ mod tests {
                             start_position: model::Point { row: 0, column: 1 },
                             end_position: model::Point { row: 0, column: 1 },
                             is_block_end_delimiter: false,
+                            is_opaque: false,
+                            identity: vec![],
                         }),
                         CSTNode::Terminal(Terminal {
                             id: uuid::Uuid::new_v4(),
@@ -549,6 +631,8 @@ mod tests {
                             start_position: model::Point { row: 0, column: 1 },
                             end_position: model::Point { row: 0, column: 1 },
                             is_block_end_delimiter: false,
+                            is_opaque: false,
+                            identity: vec![],
                         }),
                     ],
                 }),
@@ -559,6 +643,8 @@ mod tests {
                     start_position: model::Point { row: 2, column: 1 },
                     end_position: model::Point { row: 2, column: 1 },
                     is_block_end_delimiter: true,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -567,6 +653,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            identity: vec![],
+            injected_language: None,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             children: vec![
@@ -577,6 +665,8 @@ mod tests {
                     start_position: model::Point { row: 0, column: 1 },
                     end_position: model::Point { row: 0, column: 1 },
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -585,6 +675,8 @@ mod tests {
                     start_position: model::Point { row: 2, column: 1 },
                     end_position: model::Point { row: 2, column: 1 },
                     is_block_end_delimiter: true,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -617,6 +709,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            identity: vec![],
+            injected_language: None,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             children: vec![
@@ -627,11 +721,15 @@ mod tests {
                     start_position: model::Point { row: 0, column: 1 },
                     end_position: model::Point { row: 0, column: 1 },
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::NonTerminal(NonTerminal {
                     id: uuid::Uuid::new_v4(),
                     kind: "method_declaration",
                     are_children_unordered: false,
+                    identity: vec![],
+                    injected_language: None,
                     start_position: model::Point { row: 1, column: 0 },
                     end_position: model::Point { row: 1, column: 4 },
                     children: vec![
@@ -642,6 +740,8 @@ mod tests {
                             start_position: model::Point { row: 0, column: 1 },
                             end_position: model::Point { row: 0, column: 1 },
                             is_block_end_delimiter: false,
+                            is_opaque: false,
+                            identity: vec![],
                         }),
                         CSTNode::Terminal(Terminal {
                             id: uuid::Uuid::new_v4(),
@@ -650,6 +750,8 @@ mod tests {
                             start_position: model::Point { row: 0, column: 1 },
                             end_position: model::Point { row: 0, column: 1 },
                             is_block_end_delimiter: false,
+                            is_opaque: false,
+                            identity: vec![],
                         }),
                         CSTNode::Terminal(Terminal {
                             id: uuid::Uuid::new_v4(),
@@ -658,6 +760,8 @@ mod tests {
                             start_position: model::Point { row: 0, column: 1 },
                             end_position: model::Point { row: 0, column: 1 },
                             is_block_end_delimiter: false,
+                            is_opaque: false,
+                            identity: vec![],
                         }),
                         CSTNode::Terminal(Terminal {
                             id: uuid::Uuid::new_v4(),
@@ -666,6 +770,8 @@ mod tests {
                             start_position: model::Point { row: 0, column: 1 },
                             end_position: model::Point { row: 0, column: 1 },
                             is_block_end_delimiter: false,
+                            is_opaque: false,
+                            identity: vec![],
                         }),
                     ],
                 }),
@@ -676,6 +782,8 @@ mod tests {
                     start_position: model::Point { row: 1, column: 1 },
                     end_position: model::Point { row: 1, column: 1 },
                     is_block_end_delimiter: true,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -684,6 +792,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            identity: vec![],
+            injected_language: None,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             children: vec![
@@ -694,11 +804,15 @@ mod tests {
                     start_position: model::Point { row: 0, column: 1 },
                     end_position: model::Point { row: 0, column: 1 },
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::NonTerminal(NonTerminal {
                     id: uuid::Uuid::new_v4(),
                     kind: "method_declaration",
                     are_children_unordered: false,
+                    identity: vec![],
+                    injected_language: None,
                     start_position: model::Point { row: 1, column: 0 },
                     end_position: model::Point { row: 1, column: 4 },
                     children: vec![
@@ -709,6 +823,8 @@ mod tests {
                             start_position: model::Point { row: 0, column: 1 },
                             end_position: model::Point { row: 0, column: 1 },
                             is_block_end_delimiter: false,
+                            is_opaque: false,
+                            identity: vec![],
                         }),
                         CSTNode::Terminal(Terminal {
                             id: uuid::Uuid::new_v4(),
@@ -717,6 +833,8 @@ mod tests {
                             start_position: model::Point { row: 0, column: 1 },
                             end_position: model::Point { row: 0, column: 1 },
                             is_block_end_delimiter: false,
+                            is_opaque: false,
+                            identity: vec![],
                         }),
                         CSTNode::Terminal(Terminal {
                             id: uuid::Uuid::new_v4(),
@@ -725,6 +843,8 @@ mod tests {
                             start_position: model::Point { row: 0, column: 1 },
                             end_position: model::Point { row: 0, column: 1 },
                             is_block_end_delimiter: false,
+                            is_opaque: false,
+                            identity: vec![],
                         }),
                         CSTNode::Terminal(Terminal {
                             id: uuid::Uuid::new_v4(),
@@ -733,6 +853,8 @@ mod tests {
                             start_position: model::Point { row: 0, column: 1 },
                             end_position: model::Point { row: 0, column: 1 },
                             is_block_end_delimiter: false,
+                            is_opaque: false,
+                            identity: vec![],
                         }),
                     ],
                 }),
@@ -743,6 +865,8 @@ mod tests {
                     start_position: model::Point { row: 2, column: 1 },
                     end_position: model::Point { row: 2, column: 1 },
                     is_block_end_delimiter: true,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -751,6 +875,8 @@ mod tests {
             id: uuid::Uuid::new_v4(),
             kind: "interface_body",
             are_children_unordered: true,
+            identity: vec![],
+            injected_language: None,
             start_position: model::Point { row: 0, column: 0 },
             end_position: model::Point { row: 0, column: 0 },
             children: vec![
@@ -761,6 +887,8 @@ mod tests {
                     start_position: model::Point { row: 0, column: 1 },
                     end_position: model::Point { row: 0, column: 1 },
                     is_block_end_delimiter: false,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
                 CSTNode::Terminal(Terminal {
                     id: uuid::Uuid::new_v4(),
@@ -769,6 +897,8 @@ mod tests {
                     start_position: model::Point { row: 2, column: 1 },
                     end_position: model::Point { row: 2, column: 1 },
                     is_block_end_delimiter: true,
+                    is_opaque: false,
+                    identity: vec![],
                 }),
             ],
         });
@@ -868,6 +998,8 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             children: vec![],
             are_children_unordered: true,
+            identity: vec![],
+            injected_language: None,
         };
         let kind_b = NonTerminal {
             id: uuid::Uuid::new_v4(),
@@ -876,10 +1008,19 @@ mod tests {
             end_position: Point { row: 0, column: 7 },
             children: vec![],
             are_children_unordered: true,
+            identity: vec![],
+            injected_language: None,
         };
 
         let matchings = Matchings::empty();
-        let result = unordered_merge(&kind_a, &kind_b, &matchings, &matchings, &matchings);
+        let result = unordered_merge(
+            &kind_a,
+            &kind_b,
+            &matchings,
+            &matchings,
+            &matchings,
+            &RevisionSources::default(),
+        );
 
         assert!(result.is_err());
         assert_eq!(
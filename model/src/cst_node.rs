@@ -1,12 +1,22 @@
 use std::cmp::Ordering;
 use std::hash::{Hash, Hasher};
 
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+use serde::{Deserialize, Serialize};
+
+/// The values that identify a node, as pairs of a name and the captured contents,
+/// e.g. `("param.type", "String")`.
+pub type Identity = Vec<(String, String)>;
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
 pub struct Point {
     pub row: usize,
     pub column: usize,
 }
 
+/// Nodes own their children and have no link to their parent, so trees are walked from the root,
+/// e.g. with a `Visitor`.
 #[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord, Hash)]
 pub enum CSTNode<'a> {
     Terminal(Terminal<'a>),
@@ -64,6 +74,13 @@ impl CSTNode<'_> {
     pub fn get_tree_size(&self) -> usize {
         self.get_subtree_size() + 1
     }
+
+    pub fn identity(&self) -> &Identity {
+        match self {
+            CSTNode::Terminal(node) => &node.identity,
+            CSTNode::NonTerminal(node) => &node.identity,
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -74,6 +91,11 @@ pub struct NonTerminal<'a> {
     pub start_position: Point,
     pub end_position: Point,
     pub are_children_unordered: bool,
+    /// Computed while parsing, e.g. by tree-sitter label queries, and empty if the node has none.
+    pub identity: Identity,
+    /// Language of the region written in another language that the node contains, whose root is
+    /// the only child of the node.
+    pub injected_language: Option<String>,
 }
 
 impl<'a> PartialEq for NonTerminal<'a> {
@@ -129,6 +151,10 @@ pub struct Terminal<'a> {
     pub start_position: Point,
     pub end_position: Point,
     pub is_block_end_delimiter: bool,
+    /// Kept as a terminal although the grammar gives it children, e.g. a method body when
+    /// stopping compilation at `block`, so it is merged line by line.
+    pub is_opaque: bool,
+    pub identity: Identity,
 }
 
 impl<'a> PartialEq for Terminal<'a> {
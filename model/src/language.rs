@@ -1,4 +0,0 @@
-#[derive(Clone, Copy, Eq, PartialEq, Debug)]
-pub enum Language {
-    Java,
-}
@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// Describes a language declaratively, so that it can be loaded at runtime instead of being
+/// built into the tool.
+#[derive(Debug, Default, Clone, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct LanguageDefinition {
+    pub name: String,
+    pub extensions: Vec<String>,
+    /// Name of the tree-sitter grammar used to parse the language.
+    pub grammar: String,
+    /// Shared library exporting `tree_sitter_<grammar>`. If not set, one of the built-in
+    /// grammars is used.
+    pub grammar_path: Option<std::path::PathBuf>,
+    pub stop_compilation_at: Vec<String>,
+    pub kinds_with_unordered_children: Vec<String>,
+    pub block_end_delimiters: Vec<String>,
+    pub delimiters: Vec<String>,
+    /// Separator of the children of each kind, e.g. `argument_list = ","`. Separators are
+    /// dropped while parsing and printed back between the children which are not delimiters.
+    pub kinds_with_separated_children: HashMap<String, String>,
+    pub labels: Vec<LabelRule>,
+    /// Tree-sitter query file identifying labelled nodes, see `parsing::LabelQueries`.
+    pub label_queries: Option<std::path::PathBuf>,
+    /// Tree-sitter query file finding regions written in other languages, see
+    /// `parsing::Injections`.
+    pub injections: Option<std::path::PathBuf>,
+    pub kinds_with_line_separated_children: Vec<String>,
+    pub kinds_on_separate_lines: Vec<String>,
+}
+
+/// Nodes of `kind` are identified by the contents of the descendants reached by each of the
+/// `identity` paths. A path is a `/` separated list of child kinds, each of which may contain a
+/// `*` wildcard and `|` separated alternatives, e.g. `formal_parameters/formal_parameter/*_type`
+/// for the parameter types of a Java method. Without paths, the whole contents are compared.
+#[derive(Debug, Default, Clone, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct LabelRule {
+    pub kind: String,
+    pub identity: Vec<String>,
+}
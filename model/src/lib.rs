@@ -1,6 +1,17 @@
 pub mod cst_node;
-pub mod language;
+pub mod language_definition;
+pub mod node_id;
+pub mod owned_cst_node;
+pub mod stable_hasher;
+pub mod structural_hash;
+pub mod visitor;
 
 pub use cst_node::CSTNode;
+pub use cst_node::Identity;
 pub use cst_node::Point;
-pub use language::Language;
+pub use language_definition::{LabelRule, LanguageDefinition};
+pub use node_id::{derive_node_id, make_node_id, retag_node_id};
+pub use owned_cst_node::OwnedCSTNode;
+pub use stable_hasher::StableHasher;
+pub use structural_hash::{are_structurally_equal, StructuralHashes};
+pub use visitor::{Visitor, VisitorAction};
@@ -0,0 +1,30 @@
+use std::hash::{Hash, Hasher};
+
+use crate::StableHasher;
+
+/// Set in the ids of nodes created by parsing handlers, so they never clash with the ids of the
+/// nodes built by the parser.
+const DERIVED_ID_FLAG: u64 = 1 << 63;
+
+/// Ids are made of the tag of the revision the node belongs to and of the pre-order index of the
+/// node in its tree, so that runs over the same inputs give the same ids. Trees that are matched
+/// against each other must have different tags.
+pub fn make_node_id(revision_tag: u32, index: u64) -> uuid::Uuid {
+    uuid::Uuid::from_u64_pair(revision_tag as u64, index)
+}
+
+/// Id of a node created by a parsing handler, derived from the id of the node it was created
+/// for, e.g. its parent. The `salt` tells apart the nodes derived from the same one.
+pub fn derive_node_id(from: uuid::Uuid, salt: &str) -> uuid::Uuid {
+    let (high, low) = from.as_u64_pair();
+    let mut hasher = StableHasher::default();
+    low.hash(&mut hasher);
+    salt.hash(&mut hasher);
+    uuid::Uuid::from_u64_pair(high | DERIVED_ID_FLAG, hasher.finish())
+}
+
+/// The same id, with the tag of another revision, e.g. for a tree loaded from a cache.
+pub fn retag_node_id(id: uuid::Uuid, revision_tag: u32) -> uuid::Uuid {
+    let (high, low) = id.as_u64_pair();
+    uuid::Uuid::from_u64_pair((high & DERIVED_ID_FLAG) | revision_tag as u64, low)
+}
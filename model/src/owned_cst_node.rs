@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cst_node::{NonTerminal, Terminal},
+    CSTNode, Identity, Point,
+};
+
+/// A tree that owns its kinds and values, so it can outlive the source it was parsed from and
+/// be stored, e.g. in a parse cache. Nodes keep their ids and the identities computed while
+/// parsing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OwnedCSTNode {
+    Terminal(OwnedTerminal),
+    NonTerminal(OwnedNonTerminal),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnedTerminal {
+    pub id: uuid::Uuid,
+    pub kind: String,
+    pub value: String,
+    pub start_position: Point,
+    pub end_position: Point,
+    pub is_block_end_delimiter: bool,
+    pub is_opaque: bool,
+    pub identity: Identity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnedNonTerminal {
+    pub id: uuid::Uuid,
+    pub kind: String,
+    pub children: Vec<OwnedCSTNode>,
+    pub start_position: Point,
+    pub end_position: Point,
+    pub are_children_unordered: bool,
+    pub identity: Identity,
+    pub injected_language: Option<String>,
+}
+
+impl OwnedCSTNode {
+    pub fn id(&self) -> uuid::Uuid {
+        match self {
+            OwnedCSTNode::Terminal(terminal) => terminal.id,
+            OwnedCSTNode::NonTerminal(non_terminal) => non_terminal.id,
+        }
+    }
+
+    /// Borrows the tree back as a `CSTNode`, whose kinds and values point into this one.
+    pub fn as_cst_node(&self) -> CSTNode<'_> {
+        match self {
+            OwnedCSTNode::Terminal(terminal) => CSTNode::Terminal(Terminal {
+                id: terminal.id,
+                kind: &terminal.kind,
+                value: &terminal.value,
+                start_position: terminal.start_position,
+                end_position: terminal.end_position,
+                is_block_end_delimiter: terminal.is_block_end_delimiter,
+                is_opaque: terminal.is_opaque,
+                identity: terminal.identity.clone(),
+            }),
+            OwnedCSTNode::NonTerminal(non_terminal) => CSTNode::NonTerminal(NonTerminal {
+                id: non_terminal.id,
+                kind: &non_terminal.kind,
+                children: non_terminal
+                    .children
+                    .iter()
+                    .map(OwnedCSTNode::as_cst_node)
+                    .collect(),
+                start_position: non_terminal.start_position,
+                end_position: non_terminal.end_position,
+                are_children_unordered: non_terminal.are_children_unordered,
+                identity: non_terminal.identity.clone(),
+                injected_language: non_terminal.injected_language.clone(),
+            }),
+        }
+    }
+}
+
+impl From<&CSTNode<'_>> for OwnedCSTNode {
+    fn from(node: &CSTNode<'_>) -> Self {
+        match node {
+            CSTNode::Terminal(terminal) => OwnedCSTNode::Terminal(OwnedTerminal {
+                id: terminal.id,
+                kind: terminal.kind.to_string(),
+                value: terminal.value.to_string(),
+                start_position: terminal.start_position,
+                end_position: terminal.end_position,
+                is_block_end_delimiter: terminal.is_block_end_delimiter,
+                is_opaque: terminal.is_opaque,
+                identity: terminal.identity.clone(),
+            }),
+            CSTNode::NonTerminal(non_terminal) => OwnedCSTNode::NonTerminal(OwnedNonTerminal {
+                id: non_terminal.id,
+                kind: non_terminal.kind.to_string(),
+                children: non_terminal
+                    .children
+                    .iter()
+                    .map(OwnedCSTNode::from)
+                    .collect(),
+                start_position: non_terminal.start_position,
+                end_position: non_terminal.end_position,
+                are_children_unordered: non_terminal.are_children_unordered,
+                identity: non_terminal.identity.clone(),
+                injected_language: non_terminal.injected_language.clone(),
+            }),
+        }
+    }
+}
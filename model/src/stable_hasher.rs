@@ -0,0 +1,26 @@
+use std::hash::Hasher;
+
+/// FNV-1a, whose values are the same on every run, unlike the ones of the hashers of the
+/// standard library, which are randomly seeded. Maps using it iterate in the same order on every
+/// run.
+#[derive(Debug, Clone, Copy)]
+pub struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        StableHasher(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use std::{
+    collections::HashMap,
+    hash::{BuildHasherDefault, Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::{CSTNode, StableHasher, Visitor};
+
+/// Merkle-style hashes of subtrees: the hash of a node combines its kind, its value and the
+/// hashes of its children, but neither its id nor its position. Subtrees with the same contents
+/// get the same hash wherever they are, e.g. to tell identical subtrees apart without comparing
+/// them, or to find code that was moved.
+///
+/// Hashes are looked up by the address of the node rather than by its id, as the ids of cloned or
+/// hand-built trees are not unique, so the table borrows the trees it was built for.
+#[derive(Debug, Default)]
+pub struct StructuralHashes<'t> {
+    hashes: HashMap<usize, u64, BuildHasherDefault<StableHasher>>,
+    trees: PhantomData<&'t ()>,
+}
+
+impl<'t> StructuralHashes<'t> {
+    pub fn new<'a: 't>(root: &'t CSTNode<'a>) -> Self {
+        let mut hashes = StructuralHashes::default();
+        hashes.insert_tree(root);
+        hashes
+    }
+
+    /// Hashes every node of the tree, e.g. to compare the nodes of several revisions.
+    pub fn insert_tree<'a: 't>(&mut self, root: &'t CSTNode<'a>) {
+        root.walk(&mut HashingVisitor {
+            hashes: &mut self.hashes,
+        });
+    }
+
+    /// The hash of a node of one of the trees the table was built for.
+    pub fn get(&self, node: &CSTNode) -> Option<u64> {
+        self.hashes.get(&get_address(node)).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.hashes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hashes.is_empty()
+    }
+}
+
+struct HashingVisitor<'h> {
+    hashes: &'h mut HashMap<usize, u64, BuildHasherDefault<StableHasher>>,
+}
+
+impl<'t, 'a> Visitor<'t, 'a> for HashingVisitor<'_> {
+    // Nodes are left after their children, so the hashes of the children are already known
+    fn leave(&mut self, node: &'t CSTNode<'a>) {
+        let mut hasher = StableHasher::default();
+        node.kind().hash(&mut hasher);
+        match node {
+            CSTNode::Terminal(terminal) => {
+                0u8.hash(&mut hasher);
+                terminal.value.hash(&mut hasher);
+            }
+            CSTNode::NonTerminal(non_terminal) => {
+                1u8.hash(&mut hasher);
+                non_terminal.are_children_unordered.hash(&mut hasher);
+                non_terminal.children.len().hash(&mut hasher);
+                for child in non_terminal.children.iter() {
+                    self.hashes[&get_address(child)].hash(&mut hasher);
+                }
+            }
+        }
+        self.hashes.insert(get_address(node), hasher.finish());
+    }
+}
+
+fn get_address(node: &CSTNode) -> usize {
+    node as *const CSTNode as usize
+}
+
+/// Whether both subtrees have the same contents, ignoring ids and positions. Used to rule out
+/// collisions of structural hashes.
+pub fn are_structurally_equal(left: &CSTNode, right: &CSTNode) -> bool {
+    let mut pending = vec![(left, right)];
+    while let Some((left, right)) = pending.pop() {
+        match (left, right) {
+            (CSTNode::Terminal(left), CSTNode::Terminal(right)) => {
+                if left.kind != right.kind || left.value != right.value {
+                    return false;
+                }
+            }
+            (CSTNode::NonTerminal(left), CSTNode::NonTerminal(right)) => {
+                if left.kind != right.kind
+                    || left.are_children_unordered != right.are_children_unordered
+                    || left.children.len() != right.children.len()
+                {
+                    return false;
+                }
+                pending.extend(left.children.iter().zip(right.children.iter()));
+            }
+            (_, _) => return false,
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cst_node::{NonTerminal, Point, Terminal},
+        CSTNode,
+    };
+
+    use super::{are_structurally_equal, StructuralHashes};
+
+    fn make_terminal(kind: &'static str, value: &'static str, row: usize) -> CSTNode<'static> {
+        CSTNode::Terminal(Terminal {
+            id: uuid::Uuid::new_v4(),
+            kind,
+            value,
+            start_position: Point { row, column: 0 },
+            ..Default::default()
+        })
+    }
+
+    fn make_method(name: &'static str, row: usize) -> CSTNode<'static> {
+        CSTNode::NonTerminal(NonTerminal {
+            id: uuid::Uuid::new_v4(),
+            kind: "method_declaration",
+            children: vec![
+                make_terminal("identifier", name, row),
+                make_terminal("block", "{}", row),
+            ],
+            start_position: Point { row, column: 0 },
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn identical_subtrees_have_the_same_hash_wherever_they_are() {
+        let left = make_method("a", 0);
+        let right = make_method("a", 10);
+        let mut hashes = StructuralHashes::new(&left);
+        hashes.insert_tree(&right);
+
+        assert_eq!(6, hashes.len());
+        assert_eq!(hashes.get(&left), hashes.get(&right));
+        assert!(are_structurally_equal(&left, &right));
+    }
+
+    #[test]
+    fn subtrees_with_different_contents_have_different_hashes() {
+        let left = make_method("a", 0);
+        let right = make_method("b", 0);
+        let mut hashes = StructuralHashes::new(&left);
+        hashes.insert_tree(&right);
+
+        assert_ne!(hashes.get(&left), hashes.get(&right));
+        assert!(!are_structurally_equal(&left, &right));
+
+        let CSTNode::NonTerminal(method) = &left else {
+            unreachable!()
+        };
+        let terminal = make_terminal("method_declaration", "", 0);
+        assert_eq!(None, hashes.get(&terminal));
+        assert_ne!(hashes.get(&method.children[0]), hashes.get(&left));
+    }
+}
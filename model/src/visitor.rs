@@ -0,0 +1,128 @@
+use crate::CSTNode;
+
+/// What a walk does after entering a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VisitorAction {
+    Continue,
+    /// Leaves the node without walking its children, e.g. to skip comments or import blocks.
+    SkipChildren,
+}
+
+/// Callbacks of a depth-first walk over a tree, see `CSTNode::walk`. Nodes are entered in
+/// pre-order and left in post-order.
+pub trait Visitor<'t, 'a> {
+    fn enter(&mut self, _node: &'t CSTNode<'a>) -> VisitorAction {
+        VisitorAction::Continue
+    }
+
+    fn leave(&mut self, _node: &'t CSTNode<'a>) {}
+}
+
+/// Closures are visitors that only enter nodes.
+impl<'t, 'a: 't, F> Visitor<'t, 'a> for F
+where
+    F: FnMut(&'t CSTNode<'a>) -> VisitorAction,
+{
+    fn enter(&mut self, node: &'t CSTNode<'a>) -> VisitorAction {
+        self(node)
+    }
+}
+
+impl<'a> CSTNode<'a> {
+    /// Walks the subtree rooted at the node, without recursion, so deeply nested trees do not
+    /// overflow the stack.
+    pub fn walk<'t>(&'t self, visitor: &mut impl Visitor<'t, 'a>) {
+        // Nodes are pushed twice: once to be entered, then once more to be left
+        let mut stack: Vec<(&'t CSTNode<'a>, bool)> = vec![(self, false)];
+        while let Some((node, is_leaving)) = stack.pop() {
+            if is_leaving {
+                visitor.leave(node);
+                continue;
+            }
+
+            let action = visitor.enter(node);
+            stack.push((node, true));
+            if let (VisitorAction::Continue, CSTNode::NonTerminal(non_terminal)) = (action, node) {
+                stack.extend(
+                    non_terminal
+                        .children
+                        .iter()
+                        .rev()
+                        .map(|child| (child, false)),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    use super::{Visitor, VisitorAction};
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Vec<String>,
+    }
+
+    impl<'t, 'a> Visitor<'t, 'a> for Recorder {
+        fn enter(&mut self, node: &'t CSTNode<'a>) -> VisitorAction {
+            self.events.push(format!("enter {}", node.kind()));
+            match node.kind() {
+                "skipped" => VisitorAction::SkipChildren,
+                _ => VisitorAction::Continue,
+            }
+        }
+
+        fn leave(&mut self, node: &'t CSTNode<'a>) {
+            self.events.push(format!("leave {}", node.kind()));
+        }
+    }
+
+    fn make_terminal(kind: &'static str) -> CSTNode<'static> {
+        CSTNode::Terminal(Terminal {
+            kind,
+            ..Default::default()
+        })
+    }
+
+    fn make_non_terminal(kind: &'static str, children: Vec<CSTNode<'static>>) -> CSTNode<'static> {
+        CSTNode::NonTerminal(NonTerminal {
+            kind,
+            children,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn nodes_are_entered_in_pre_order_and_left_in_post_order() {
+        let tree = make_non_terminal(
+            "root",
+            vec![
+                make_non_terminal("skipped", vec![make_terminal("hidden")]),
+                make_non_terminal("visited", vec![make_terminal("leaf")]),
+            ],
+        );
+
+        let mut recorder = Recorder::default();
+        tree.walk(&mut recorder);
+
+        assert_eq!(
+            vec![
+                "enter root",
+                "enter skipped",
+                "leave skipped",
+                "enter visited",
+                "enter leaf",
+                "leave leaf",
+                "leave visited",
+                "leave root",
+            ],
+            recorder.events
+        );
+    }
+}
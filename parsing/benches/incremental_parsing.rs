@@ -0,0 +1,36 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use parsing::{parse_file, ParserConfiguration, ParsingOptions};
+
+/// A class with many methods, as left and right are usually small edits of a large base.
+fn make_class(changed_method: Option<usize>) -> String {
+    let methods: String = (0..2000)
+        .map(|index| match Some(index) == changed_method {
+            true => format!("  int m{}(int a) {{\n    return a * 2;\n  }}\n\n", index),
+            false => format!(
+                "  int m{}(int a) {{\n    return a + {};\n  }}\n\n",
+                index, index
+            ),
+        })
+        .collect();
+    format!("class A {{\n{}}}\n", methods)
+}
+
+fn incremental_parsing(c: &mut Criterion) {
+    let config = ParserConfiguration::new(parsing::get_builtin_grammar("java").unwrap());
+    let options = ParsingOptions::default();
+    let base = make_class(None);
+    let left = make_class(Some(1000));
+    let base_file = parse_file(&base, &config, &options).unwrap();
+
+    let mut group = c.benchmark_group("parsing an edited version");
+    group.bench_function("from scratch", |b| {
+        b.iter(|| parse_file(&left, &config, &options).unwrap())
+    });
+    group.bench_function("incrementally from base", |b| {
+        b.iter(|| base_file.parse_edited_version(&left, &options).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, incremental_parsing);
+criterion_main!(benches);
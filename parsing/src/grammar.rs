@@ -0,0 +1,171 @@
+use std::path::Path;
+
+/// The grammars compiled into the tool, by the name language definitions refer to them with.
+pub fn get_builtin_grammar(name: &str) -> Option<tree_sitter::Language> {
+    match name {
+        "css" => Some(tree_sitter_css::language()),
+        "html" => Some(tree_sitter_html::language()),
+        "java" => Some(tree_sitter_java::language()),
+        "javascript" => Some(tree_sitter_javascript::language()),
+        "markdown" => Some(tree_sitter_md::language()),
+        "php" => Some(tree_sitter_php::language_php()),
+        "proto" => Some(tree_sitter_proto::language()),
+        "scala" => Some(tree_sitter_scala::language()),
+        "swift" => Some(tree_sitter_swift::language()),
+        "toml" => Some(tree_sitter_toml::language()),
+        _ => None,
+    }
+}
+
+/// Looks up a node kind in the grammar, trying named kinds before anonymous ones. The returned
+/// name is owned by the grammar, so it lives as long as the grammar is loaded.
+pub fn get_grammar_kind(language: tree_sitter::Language, kind: &str) -> Option<&'static str> {
+    [true, false]
+        .into_iter()
+        .map(|named| language.id_for_node_kind(kind, named))
+        // Unknown kinds get the id of the end of input
+        .find(|kind_id| *kind_id != 0)
+        .and_then(|kind_id| language.node_kind_for_id(kind_id))
+}
+
+/// Loads a compiled tree-sitter grammar from a shared library exporting `tree_sitter_<name>`,
+/// the same way the tree-sitter CLI does. The returned language points to data owned by the
+/// library, so the library must stay loaded as long as the language and the trees parsed with
+/// it are used.
+pub fn load_grammar_from_library(
+    path: &Path,
+    name: &str,
+) -> Result<(tree_sitter::Language, libloading::Library), String> {
+    let symbol_name = format!("tree_sitter_{}", name.replace('-', "_"));
+
+    // Safety: loading a library runs its initialisation routines, and there is no way of
+    // checking that the symbol has the expected signature. Grammars are trusted the same way
+    // the built-in ones are.
+    let library = unsafe { libloading::Library::new(path) }
+        .map_err(|error| format!("Could not load grammar from {}: {}", path.display(), error))?;
+    let language = unsafe {
+        let language_fn: libloading::Symbol<unsafe extern "C" fn() -> tree_sitter::Language> =
+            library.get(symbol_name.as_bytes()).map_err(|error| {
+                format!(
+                    "Could not find {} in {}: {}",
+                    symbol_name,
+                    path.display(),
+                    error
+                )
+            })?;
+        language_fn()
+    };
+
+    // Rejected grammars are unloaded when the library is dropped
+    let version = language.version();
+    if !(tree_sitter::MIN_COMPATIBLE_LANGUAGE_VERSION..=tree_sitter::LANGUAGE_VERSION)
+        .contains(&version)
+    {
+        return Err(format!(
+            "Grammar {} was generated for ABI version {}, but only versions {} to {} are supported",
+            path.display(),
+            version,
+            tree_sitter::MIN_COMPATIBLE_LANGUAGE_VERSION,
+            tree_sitter::LANGUAGE_VERSION
+        ));
+    }
+
+    Ok((language, library))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{get_grammar_kind, load_grammar_from_library};
+
+    /// Building the grammars already requires a C compiler, so one is used to build the
+    /// vendored protobuf grammar as a shared library.
+    fn build_protobuf_grammar_library(test_name: &str) -> PathBuf {
+        let grammar_dir =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("../grammars/tree-sitter-proto");
+        let output = std::env::temp_dir().join(format!(
+            "libtree-sitter-proto-{}-{}.so",
+            test_name,
+            std::process::id()
+        ));
+
+        let status = std::process::Command::new("cc")
+            .args(["-shared", "-fPIC", "-O0", "-I"])
+            .arg(grammar_dir.join("src"))
+            .arg(grammar_dir.join("src/parser.c"))
+            .arg("-o")
+            .arg(&output)
+            .status()
+            .expect("A C compiler is required to build grammars");
+        assert!(status.success());
+
+        output
+    }
+
+    #[test]
+    fn it_loads_grammars_from_shared_libraries() {
+        let library = build_protobuf_grammar_library("loads");
+
+        let (language, _library) = load_grammar_from_library(&library, "proto").unwrap();
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(language).unwrap();
+        let tree = parser
+            .parse("syntax = \"proto3\";\nmessage A { string id = 1; }", None)
+            .unwrap();
+        assert!(!tree.root_node().has_error());
+        assert_eq!("message", tree.root_node().child(1).unwrap().kind());
+
+        std::fs::remove_file(library).unwrap();
+    }
+
+    #[test]
+    fn it_fails_if_the_library_does_not_export_the_grammar() {
+        let library = build_protobuf_grammar_library("missing-symbol");
+
+        assert!(load_grammar_from_library(&library, "java").is_err());
+
+        std::fs::remove_file(library).unwrap();
+    }
+
+    #[test]
+    fn it_rejects_grammars_of_unsupported_abi_versions() {
+        // The ABI version is the first field of a language, so a library exporting a language
+        // made of a version only is enough to be rejected
+        let source = std::env::temp_dir().join(format!("old-abi-{}.c", std::process::id()));
+        let library = source.with_extension("so");
+        std::fs::write(
+            &source,
+            "static const unsigned version[16] = {1};\nconst void *tree_sitter_old(void) { return version; }\n",
+        )
+        .unwrap();
+        let status = std::process::Command::new("cc")
+            .args(["-shared", "-fPIC", "-O0"])
+            .arg(&source)
+            .arg("-o")
+            .arg(&library)
+            .status()
+            .expect("A C compiler is required to build grammars");
+        assert!(status.success());
+
+        let error = load_grammar_from_library(&library, "old").unwrap_err();
+        assert!(error.contains("ABI version 1"), "{}", error);
+
+        std::fs::remove_file(source).unwrap();
+        std::fs::remove_file(library).unwrap();
+    }
+
+    #[test]
+    fn kinds_are_looked_up_among_named_and_anonymous_kinds() {
+        let language = tree_sitter_java::language();
+
+        assert_eq!(Some("class_body"), get_grammar_kind(language, "class_body"));
+        assert_eq!(Some("{"), get_grammar_kind(language, "{"));
+        assert_eq!(None, get_grammar_kind(language, "clas_body"));
+    }
+
+    #[test]
+    fn it_fails_if_the_library_does_not_exist() {
+        assert!(load_grammar_from_library(Path::new("/does/not/exist.so"), "java").is_err());
+    }
+}
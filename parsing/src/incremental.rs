@@ -0,0 +1,110 @@
+use tree_sitter::{InputEdit, Point};
+
+/// Describes the changes from `old` to `new` as tree-sitter edits, based on a line diff. Edits
+/// are sorted from the end of the file to its start, so that each of them can be applied in
+/// turn without shifting the positions of the ones left to apply.
+pub(crate) fn compute_edits(old: &str, new: &str) -> Vec<InputEdit> {
+    let old_lines = get_line_offsets(old);
+    let new_lines = get_line_offsets(new);
+
+    let patch = diffy::DiffOptions::new()
+        .set_context_len(0)
+        .create_patch(old, new);
+    patch
+        .hunks()
+        .iter()
+        .rev()
+        .map(|hunk| {
+            // Empty ranges start right after the line they follow instead of at their first line
+            let old_start = hunk.old_range().start() - usize::from(!hunk.old_range().is_empty());
+            let new_start = hunk.new_range().start() - usize::from(!hunk.new_range().is_empty());
+            let old_end = old_start + hunk.old_range().len();
+            let new_end = new_start + hunk.new_range().len();
+
+            let start_byte = old_lines[old_start];
+            let start_position = get_position(old, start_byte);
+            let inserted = &new[new_lines[new_start]..new_lines[new_end]];
+            InputEdit {
+                start_byte,
+                old_end_byte: old_lines[old_end],
+                new_end_byte: start_byte + inserted.len(),
+                start_position,
+                old_end_position: get_position(old, old_lines[old_end]),
+                new_end_position: advance(start_position, inserted),
+            }
+        })
+        .collect()
+}
+
+/// The byte offset of the start of each line, followed by the length of the text.
+fn get_line_offsets(text: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+        .filter(|offset| *offset < text.len())
+        .chain(std::iter::once(text.len()))
+        .collect()
+}
+
+fn get_position(text: &str, byte: usize) -> Point {
+    advance(Point::new(0, 0), &text[..byte])
+}
+
+fn advance(position: Point, text: &str) -> Point {
+    match text.rfind('\n') {
+        Some(last_line_break) => Point::new(
+            position.row + text.matches('\n').count(),
+            text.len() - last_line_break - 1,
+        ),
+        None => Point::new(position.row, position.column + text.len()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter::Point;
+
+    use super::compute_edits;
+
+    fn apply_edits(old: &str, new: &str) -> String {
+        let mut result = old.to_string();
+        for edit in compute_edits(old, new) {
+            let inserted_length = edit.new_end_byte - edit.start_byte;
+            let inserted = &new[new.len() - (result.len() - edit.old_end_byte) - inserted_length
+                ..new.len() - (result.len() - edit.old_end_byte)];
+            result.replace_range(edit.start_byte..edit.old_end_byte, inserted);
+        }
+        result
+    }
+
+    #[test]
+    fn edits_describe_the_changed_lines() {
+        let edits = compute_edits("a\nb\nc\n", "a\nB\nBB\nc\n");
+
+        assert_eq!(1, edits.len());
+        assert_eq!(2, edits[0].start_byte);
+        assert_eq!(4, edits[0].old_end_byte);
+        assert_eq!(7, edits[0].new_end_byte);
+        assert_eq!(Point::new(1, 0), edits[0].start_position);
+        assert_eq!(Point::new(2, 0), edits[0].old_end_position);
+        assert_eq!(Point::new(3, 0), edits[0].new_end_position);
+    }
+
+    #[test]
+    fn edits_are_sorted_from_the_end_of_the_file() {
+        let old = "a\nb\nc\nd\ne";
+        let new = "a\nc\nd\nD\ne!";
+
+        let edits = compute_edits(old, new);
+
+        assert_eq!(2, edits.len());
+        assert!(edits
+            .windows(2)
+            .all(|edits| edits[0].start_byte > edits[1].start_byte));
+        assert_eq!(new, apply_edits(old, new));
+    }
+
+    #[test]
+    fn identical_files_have_no_edits() {
+        assert!(compute_edits("a\nb\n", "a\nb\n").is_empty());
+    }
+}
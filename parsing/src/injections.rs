@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use tree_sitter::{Node, Query, QueryCursor};
+
+const CONTENT_CAPTURE: &str = "injection.content";
+const LANGUAGE_CAPTURE: &str = "injection.language";
+const LANGUAGE_PROPERTY: &str = "injection.language";
+
+/// Finds regions of a file written in another language, using tree-sitter injection queries.
+///
+/// Every pattern must capture the region as `@injection.content`. Its language is either
+/// captured as `@injection.language` or set as a property of the pattern, e.g.:
+///
+/// ```scm
+/// (script_element (raw_text) @injection.content (#set! injection.language "javascript"))
+/// (fenced_code_block (info_string (language) @injection.language) (code_fence_content) @injection.content)
+/// ```
+pub struct Injections {
+    query: Query,
+    content_capture_index: u32,
+    language_capture_index: Option<u32>,
+}
+
+impl Injections {
+    pub fn new(language: tree_sitter::Language, source: &str) -> Result<Self, String> {
+        let query = Query::new(language, source)
+            .map_err(|error| format!("Invalid injection query: {}", error))?;
+        let content_capture_index =
+            query
+                .capture_index_for_name(CONTENT_CAPTURE)
+                .ok_or(format!(
+                    "Injection queries must capture regions as @{}",
+                    CONTENT_CAPTURE
+                ))?;
+
+        Ok(Injections {
+            content_capture_index,
+            language_capture_index: query.capture_index_for_name(LANGUAGE_CAPTURE),
+            query,
+        })
+    }
+
+    /// Finds the language of every injected region, keyed by tree-sitter node id.
+    pub(crate) fn find_regions(&self, root: Node, src: &str) -> HashMap<usize, String> {
+        let mut regions = HashMap::new();
+
+        let mut cursor = QueryCursor::new();
+        for query_match in cursor.matches(&self.query, root, src.as_bytes()) {
+            let Some(content) = query_match
+                .captures
+                .iter()
+                .find(|capture| capture.index == self.content_capture_index)
+            else {
+                continue;
+            };
+
+            let captured_language = query_match
+                .captures
+                .iter()
+                .find(|capture| Some(capture.index) == self.language_capture_index)
+                .map(|capture| src[capture.node.byte_range()].trim().to_string());
+            let language = captured_language.or_else(|| {
+                self.query
+                    .property_settings(query_match.pattern_index)
+                    .iter()
+                    .find(|property| &*property.key == LANGUAGE_PROPERTY)
+                    .and_then(|property| property.value.as_deref().map(str::to_string))
+            });
+
+            if let Some(language) = language.filter(|language| !language.is_empty()) {
+                regions.insert(content.node.id(), language);
+            }
+        }
+
+        regions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Injections;
+
+    fn find_regions(language: tree_sitter::Language, query: &str, src: &str) -> Vec<String> {
+        let injections = Injections::new(language, query).unwrap();
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(language).unwrap();
+        let tree = parser.parse(src, None).unwrap();
+
+        let mut regions: Vec<String> = injections
+            .find_regions(tree.root_node(), src)
+            .into_values()
+            .collect();
+        regions.sort();
+        regions
+    }
+
+    #[test]
+    fn languages_may_be_set_as_properties_of_the_pattern() {
+        let regions = find_regions(
+            tree_sitter_html::language(),
+            r#"(script_element (raw_text) @injection.content (#set! injection.language "javascript"))"#,
+            "<p>Hi</p><script>let a = 1;</script>",
+        );
+
+        assert_eq!(vec!["javascript"], regions);
+    }
+
+    #[test]
+    fn languages_may_be_captured() {
+        let regions = find_regions(
+            tree_sitter_md::language(),
+            "(fenced_code_block (info_string (language) @injection.language) (code_fence_content) @injection.content)",
+            "```js\nlet a = 1;\n```\n\n```toml\na = 1\n```\n",
+        );
+
+        assert_eq!(vec!["js", "toml"], regions);
+    }
+
+    #[test]
+    fn regions_without_language_are_ignored() {
+        let regions = find_regions(
+            tree_sitter_md::language(),
+            "(fenced_code_block (info_string (language) @injection.language)? (code_fence_content) @injection.content)",
+            "```\nlet a = 1;\n```\n",
+        );
+
+        assert!(regions.is_empty());
+    }
+
+    #[test]
+    fn queries_must_capture_the_injected_content() {
+        assert!(Injections::new(tree_sitter_html::language(), "(raw_text) @content").is_err());
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use model::Identity;
+use tree_sitter::{Node, Query, QueryCursor};
+
+use crate::grammar::get_grammar_kind;
+
+const NODE_CAPTURE: &str = "node";
+
+/// Identifies nodes through tree-sitter queries instead of hand-written matching handlers.
+///
+/// Every pattern must capture the node it identifies as `@node`, and its other captures make up
+/// the identity of that node. Patterns sharing the same `@node` are combined, e.g.:
+///
+/// ```scm
+/// (method_declaration name: (identifier) @name) @node
+/// (method_declaration parameters: (formal_parameters (formal_parameter type: (_) @param.type))) @node
+/// ```
+///
+/// The kinds of the labelled nodes are taken from the root of each pattern.
+pub struct LabelQueries {
+    source: String,
+    query: Query,
+    node_capture_index: u32,
+    labelled_kinds: Vec<&'static str>,
+}
+
+impl LabelQueries {
+    pub fn new(language: tree_sitter::Language, source: &str) -> Result<Self, String> {
+        let query = Query::new(language, source)
+            .map_err(|error| format!("Invalid label query: {}", error))?;
+        let node_capture_index = query.capture_index_for_name(NODE_CAPTURE).ok_or(format!(
+            "Label queries must capture the identified nodes as @{}",
+            NODE_CAPTURE
+        ))?;
+
+        let mut labelled_kinds = vec![];
+        for pattern_index in 0..query.pattern_count() {
+            let pattern = &source[query.start_byte_for_pattern(pattern_index)..];
+            let kind = get_pattern_root_kind(pattern)
+                .and_then(|kind| get_grammar_kind(language, kind))
+                .ok_or(format!(
+                    "Could not find the node kind of the label query pattern \"{}\"",
+                    pattern.lines().next().unwrap_or_default()
+                ))?;
+            if !labelled_kinds.contains(&kind) {
+                labelled_kinds.push(kind);
+            }
+        }
+
+        Ok(LabelQueries {
+            source: source.to_string(),
+            query,
+            node_capture_index,
+            labelled_kinds,
+        })
+    }
+
+    pub(crate) fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn labelled_kinds(&self) -> &[&'static str] {
+        &self.labelled_kinds
+    }
+
+    /// Computes the identity of every node captured as `@node`, keyed by tree-sitter node id.
+    pub(crate) fn compute_identities(&self, root: Node, src: &str) -> HashMap<usize, Identity> {
+        let capture_names = self.query.capture_names();
+        // Captures are sorted by position, and the node id is kept only to remove duplicates
+        let mut captures_by_node: HashMap<usize, Vec<(usize, String, String, usize)>> =
+            HashMap::new();
+
+        let mut cursor = QueryCursor::new();
+        for query_match in cursor.matches(&self.query, root, src.as_bytes()) {
+            let Some(node) = query_match
+                .captures
+                .iter()
+                .find(|capture| capture.index == self.node_capture_index)
+                .map(|capture| capture.node)
+            else {
+                continue;
+            };
+
+            let captures = captures_by_node.entry(node.id()).or_default();
+            for capture in query_match
+                .captures
+                .iter()
+                .filter(|capture| capture.index != self.node_capture_index)
+            {
+                let entry = (
+                    capture.node.start_byte(),
+                    capture_names[capture.index as usize].clone(),
+                    get_tokens(capture.node, src),
+                    capture.node.id(),
+                );
+                // Overlapping patterns may capture the same node more than once
+                if !captures.contains(&entry) {
+                    captures.push(entry);
+                }
+            }
+        }
+
+        captures_by_node
+            .into_iter()
+            .map(|(node_id, mut captures)| {
+                captures.sort();
+                let identity = captures
+                    .into_iter()
+                    .map(|(_, name, contents, _)| (name, contents))
+                    .collect();
+                (node_id, identity)
+            })
+            .collect()
+    }
+}
+
+fn get_pattern_root_kind(pattern: &str) -> Option<&str> {
+    let pattern = pattern.trim_start().strip_prefix('(')?.trim_start();
+    let kind_length = pattern
+        .find(|character: char| !(character.is_alphanumeric() || character == '_'))
+        .unwrap_or(pattern.len());
+
+    match kind_length {
+        0 => None,
+        _ => Some(&pattern[..kind_length]),
+    }
+}
+
+/// Joins the tokens of the node with spaces, the same way `CSTNode::contents` does, so that
+/// differences in formatting do not change identities.
+fn get_tokens(node: Node, src: &str) -> String {
+    if node.child_count() == 0 {
+        return src[node.byte_range()].to_string();
+    }
+
+    let mut cursor = node.walk();
+    node.children(&mut cursor)
+        .map(|child| get_tokens(child, src))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LabelQueries;
+
+    const METHOD_QUERIES: &str = r#"
+        ; Methods are identified by their name and parameter types
+        (method_declaration name: (identifier) @name) @node
+        (method_declaration
+          parameters: (formal_parameters (formal_parameter type: (_) @param.type))) @node
+        (field_declaration declarator: (variable_declarator name: (identifier) @name)) @node
+    "#;
+
+    #[test]
+    fn the_labelled_kinds_are_the_roots_of_the_patterns() {
+        let queries = LabelQueries::new(tree_sitter_java::language(), METHOD_QUERIES).unwrap();
+        assert_eq!(
+            vec!["method_declaration", "field_declaration"],
+            queries.labelled_kinds()
+        );
+    }
+
+    #[test]
+    fn queries_must_capture_the_identified_node() {
+        let result = LabelQueries::new(
+            tree_sitter_java::language(),
+            "(method_declaration name: (identifier) @name)",
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn invalid_queries_are_rejected() {
+        let result = LabelQueries::new(tree_sitter_java::language(), "(method_declaration @node");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn identities_combine_the_captures_of_every_pattern_in_source_order() {
+        let src = "class A { void sum(int a, List<String> b) {} int total; }";
+        let queries = LabelQueries::new(tree_sitter_java::language(), METHOD_QUERIES).unwrap();
+
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(tree_sitter_java::language()).unwrap();
+        let tree = parser.parse(src, None).unwrap();
+
+        let mut identities: Vec<_> = queries
+            .compute_identities(tree.root_node(), src)
+            .into_values()
+            .collect();
+        identities.sort();
+
+        assert_eq!(
+            vec![
+                vec![
+                    ("name".to_string(), "sum".to_string()),
+                    ("param.type".to_string(), "int".to_string()),
+                    ("param.type".to_string(), "List < String >".to_string()),
+                ],
+                vec![("name".to_string(), "total".to_string())],
+            ],
+            identities
+        );
+    }
+}
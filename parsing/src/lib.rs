@@ -1,5 +1,19 @@
+mod grammar;
+mod incremental;
+mod injections;
+mod label_queries;
 mod parse;
+mod parse_cache;
+mod parsing_error;
 mod tree_sitter_parser;
 
-pub use parse::parse_string;
+pub use grammar::{get_builtin_grammar, load_grammar_from_library};
+pub use injections::Injections;
+pub use label_queries::LabelQueries;
+pub use parse::{
+    parse_file, parse_string, parse_string_with_options, InjectedLanguageResolver, ParsedFile,
+    ParsingOptions,
+};
+pub use parse_cache::ParseCache;
+pub use parsing_error::{ParsingError, SyntaxError, SyntaxErrorKind};
 pub use tree_sitter_parser::ParserConfiguration;
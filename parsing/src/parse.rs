@@ -1,60 +1,535 @@
-use crate::tree_sitter_parser::ParserConfiguration;
+use crate::{
+    incremental::compute_edits,
+    parsing_error::{ParsingError, SyntaxError},
+    tree_sitter_parser::ParserConfiguration,
+};
 use model::{
     cst_node::{NonTerminal, Terminal},
-    CSTNode, Point,
+    make_node_id, CSTNode, Identity, Point,
+};
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    sync::atomic::{AtomicU32, Ordering},
 };
 use tree_sitter::Node;
 
-fn explore_node<'a>(node: Node, src: &'a str, config: &'a ParserConfiguration) -> CSTNode<'a> {
-    if node.child_count() == 0 || config.stop_compilation_at.contains(node.kind()) {
+/// Finds the configuration used to parse regions written in another language, by its name.
+pub type InjectedLanguageResolver<'r, 'a> = dyn Fn(&str) -> Option<&'a ParserConfiguration> + 'r;
+
+/// Revision tags handed out to trees parsed without one, above the ones callers usually pick.
+static NEXT_ANONYMOUS_REVISION_TAG: AtomicU32 = AtomicU32::new(1 << 16);
+
+/// Hands out the ids of the nodes of a tree, in pre-order. Injected regions share the ids of the
+/// tree they are part of.
+struct NodeIds {
+    revision_tag: u32,
+    next_index: Cell<u64>,
+}
+
+impl NodeIds {
+    fn new(options: &ParsingOptions) -> Self {
+        NodeIds {
+            revision_tag: options.get_revision_tag(),
+            next_index: Cell::new(0),
+        }
+    }
+
+    fn next(&self) -> uuid::Uuid {
+        let index = self.next_index.get();
+        self.next_index.set(index + 1);
+        make_node_id(self.revision_tag, index)
+    }
+}
+
+struct ParsingContext<'r, 'a> {
+    src: &'a str,
+    ids: &'r NodeIds,
+    config: &'a ParserConfiguration,
+    identities: HashMap<usize, Identity>,
+    injected_regions: HashMap<usize, String>,
+    options: &'r ParsingOptions<'r, 'a>,
+}
+
+fn explore_node<'a>(node: Node, context: &ParsingContext<'_, 'a>) -> CSTNode<'a> {
+    let ParsingContext {
+        src,
+        config,
+        identities,
+        ..
+    } = context;
+
+    let id = context.ids.next();
+    let identity = identities.get(&node.id()).cloned().unwrap_or_default();
+
+    let start_position = Point {
+        row: node.start_position().row,
+        column: node.start_position().column,
+    };
+    let end_position = Point {
+        row: node.end_position().row,
+        column: node.end_position().column,
+    };
+
+    if let Some((injected_root, language)) = explore_injected_region(node, context) {
+        return CSTNode::NonTerminal(NonTerminal {
+            id,
+            kind: node.kind(),
+            start_position,
+            end_position,
+            children: vec![injected_root],
+            are_children_unordered: false,
+            identity,
+            injected_language: Some(language.to_owned()),
+        });
+    }
+
+    let stops_compilation = config.stop_compilation_at.contains(node.kind())
+        || context
+            .options
+            .stop_compilation_at
+            .iter()
+            .any(|kind| kind == node.kind());
+    if node.child_count() == 0 || stops_compilation {
         CSTNode::Terminal(Terminal {
-            id: uuid::Uuid::new_v4(),
+            id,
             kind: node.kind(),
-            start_position: Point {
-                row: node.start_position().row,
-                column: node.start_position().column,
-            },
-            end_position: Point {
-                row: node.end_position().row,
-                column: node.end_position().column,
-            },
+            start_position,
+            end_position,
             value: &src[node.byte_range()],
             is_block_end_delimiter: config.block_end_delimiters.contains(node.kind()),
+            is_opaque: stops_compilation && node.child_count() > 0,
+            identity,
         })
     } else {
+        let separator = config.kinds_with_separated_children.get(node.kind());
         let mut cursor = node.walk();
+        let children: Vec<Node> = node.children(&mut cursor).collect();
+        let is_dropped_separator = |index: usize| {
+            let child = children[index];
+            !child.is_named()
+                && Some(&child.kind()) == separator
+                && is_followed_by_an_element(&children[index + 1..], config)
+        };
         CSTNode::NonTerminal(NonTerminal {
-            id: uuid::Uuid::new_v4(),
+            id,
             kind: node.kind(),
-            start_position: Point {
-                row: node.start_position().row,
-                column: node.start_position().column,
-            },
-            end_position: Point {
-                row: node.end_position().row,
-                column: node.end_position().column,
-            },
-            children: node
-                .children(&mut cursor)
-                .map(|child| explore_node(child, src, config))
+            start_position,
+            end_position,
+            children: (0..children.len())
+                .filter(|index| !is_dropped_separator(*index))
+                .map(|index| explore_node(children[index], context))
                 .collect(),
             are_children_unordered: config.kinds_with_unordered_children.contains(node.kind()),
+            identity,
+            injected_language: None,
         })
     }
 }
 
+/// Whether the next sibling of a separator, comments aside, is an element of the list, so that
+/// the separator can be printed back between them.
+fn is_followed_by_an_element(next_siblings: &[Node], config: &ParserConfiguration) -> bool {
+    next_siblings
+        .iter()
+        .find(|sibling| !sibling.is_extra())
+        .is_some_and(|sibling| {
+            sibling.is_named() && !config.separated_list_delimiters.contains(sibling.kind())
+        })
+}
+
+/// Parses a region written in another language with the configuration of that language,
+/// if it is known.
+fn explore_injected_region<'r, 'a>(
+    node: Node,
+    context: &'r ParsingContext<'_, 'a>,
+) -> Option<(CSTNode<'a>, &'r str)> {
+    let language = context.injected_regions.get(&node.id())?;
+    let Some(injected_config) = (context.options.resolve_injected_language)(language) else {
+        log::debug!("Skipping region written in unknown language {}", language);
+        return None;
+    };
+
+    // The extra kinds to stop at belong to the language of the file, not to the injected one
+    let injected_options = ParsingOptions {
+        stop_compilation_at: &[],
+        ..*context.options
+    };
+    match parse_range(
+        context.src,
+        Some(node.range()),
+        None,
+        injected_config,
+        &injected_options,
+        context.ids,
+    ) {
+        Ok(ParsedFile { root, .. }) => Some((root, language)),
+        Err(error) => {
+            log::warn!("Could not parse region written in {}: {}", language, error);
+            None
+        }
+    }
+}
+
+/// How to parse files, see `parse_string_with_options`.
+#[derive(Clone, Copy)]
+pub struct ParsingOptions<'r, 'a> {
+    pub resolve_injected_language: &'r InjectedLanguageResolver<'r, 'a>,
+    /// Files with syntax errors are parsed anyway, keeping the nodes tree-sitter recovered
+    /// with, instead of failing with the first error.
+    pub allow_syntax_errors: bool,
+    /// Kinds kept as terminals in addition to the ones of the configuration, so that they are
+    /// merged line by line, as in semistructured merge.
+    pub stop_compilation_at: &'r [String],
+    /// Names of the parsing handlers to skip, e.g. `remove_block_comments` to keep comments.
+    pub disabled_handlers: &'r [String],
+    /// Tells apart the ids of the nodes of trees matched against each other, e.g. the revisions
+    /// of a merge, see `model::make_node_id`. Trees parsed without one get a tag of their own.
+    pub revision_tag: Option<u32>,
+}
+
+impl ParsingOptions<'_, '_> {
+    pub(crate) fn get_revision_tag(&self) -> u32 {
+        self.revision_tag
+            .unwrap_or_else(|| NEXT_ANONYMOUS_REVISION_TAG.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl Default for ParsingOptions<'_, '_> {
+    fn default() -> Self {
+        ParsingOptions {
+            resolve_injected_language: &|_| None,
+            allow_syntax_errors: false,
+            stop_compilation_at: &[],
+            disabled_handlers: &[],
+            revision_tag: None,
+        }
+    }
+}
+
 pub fn parse_string<'a>(
     src: &'a str,
     config: &'a ParserConfiguration,
-) -> Result<CSTNode<'a>, &'static str> {
+) -> Result<CSTNode<'a>, ParsingError> {
+    parse_string_with_options(src, config, &ParsingOptions::default())
+}
+
+/// Parses a file whose regions may be written in other languages, as described by the injection
+/// queries of its language. Regions are parsed with the configuration of their own language and
+/// become the only child of the node that contains them.
+pub fn parse_string_with_options<'a>(
+    src: &'a str,
+    config: &'a ParserConfiguration,
+    options: &ParsingOptions<'_, 'a>,
+) -> Result<CSTNode<'a>, ParsingError> {
+    parse_file(src, config, options).map(|parsed_file| parsed_file.root)
+}
+
+/// A parsed file, along with the tree-sitter tree it was built from, which is reused to parse
+/// edited versions of the file incrementally.
+pub struct ParsedFile<'a> {
+    pub root: CSTNode<'a>,
+    src: &'a str,
+    config: &'a ParserConfiguration,
+    tree: tree_sitter::Tree,
+}
+
+impl<'a> ParsedFile<'a> {
+    /// Parses an edited version of the file, reusing the subtrees of the file that the edits,
+    /// found with a line diff, did not touch.
+    pub fn parse_edited_version(
+        &self,
+        src: &'a str,
+        options: &ParsingOptions<'_, 'a>,
+    ) -> Result<ParsedFile<'a>, ParsingError> {
+        let mut tree = self.tree.clone();
+        for edit in compute_edits(self.src, src) {
+            tree.edit(&edit);
+        }
+        parse_range(
+            src,
+            None,
+            Some(&tree),
+            self.config,
+            options,
+            &NodeIds::new(options),
+        )
+    }
+}
+
+pub fn parse_file<'a>(
+    src: &'a str,
+    config: &'a ParserConfiguration,
+    options: &ParsingOptions<'_, 'a>,
+) -> Result<ParsedFile<'a>, ParsingError> {
+    parse_range(src, None, None, config, options, &NodeIds::new(options))
+}
+
+fn parse_range<'a>(
+    src: &'a str,
+    range: Option<tree_sitter::Range>,
+    old_tree: Option<&tree_sitter::Tree>,
+    config: &'a ParserConfiguration,
+    options: &ParsingOptions<'_, 'a>,
+    ids: &NodeIds,
+) -> Result<ParsedFile<'a>, ParsingError> {
     let mut parser = tree_sitter::Parser::new();
     parser
         .set_language(config.language)
-        .map_err(|_| "There was an error while setting the parser language")?;
+        .map_err(|_| ParsingError::InvalidLanguage)?;
+    if let Some(range) = range {
+        parser
+            .set_included_ranges(&[range])
+            .map_err(|_| ParsingError::InvalidRange)?;
+    }
 
     let parsed = parser
-        .parse(src, None)
-        .ok_or("It was not possible to parse the tree.")?;
-    let root = explore_node(parsed.root_node(), src, config);
-    Ok(config.handlers.run(root))
+        .parse(src, old_tree)
+        .ok_or(ParsingError::ParserFailed)?;
+    if let Some(error) = SyntaxError::find(parsed.root_node(), src) {
+        match options.allow_syntax_errors {
+            true => log::warn!("Parsing despite the error: {}", error),
+            false => return Err(ParsingError::SyntaxError(error)),
+        }
+    }
+
+    let identities = config
+        .label_queries
+        .as_ref()
+        .map(|label_queries| label_queries.compute_identities(parsed.root_node(), src))
+        .unwrap_or_default();
+    let injected_regions = config
+        .injections
+        .as_ref()
+        .map(|injections| injections.find_regions(parsed.root_node(), src))
+        .unwrap_or_default();
+
+    let context = ParsingContext {
+        src,
+        ids,
+        config,
+        identities,
+        injected_regions,
+        options,
+    };
+    let root = explore_node(parsed.root_node(), &context);
+    Ok(ParsedFile {
+        root: config.handlers.run(root, options.disabled_handlers),
+        src,
+        config,
+        tree: parsed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use model::CSTNode;
+
+    use super::{parse_file, ParsingOptions};
+    use crate::ParserConfiguration;
+
+    fn describe(node: &CSTNode) -> String {
+        match node {
+            CSTNode::Terminal(terminal) => format!("{}({})", terminal.kind, terminal.value),
+            CSTNode::NonTerminal(non_terminal) => format!(
+                "{}[{}]",
+                non_terminal.kind,
+                non_terminal
+                    .children
+                    .iter()
+                    .map(describe)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ),
+        }
+    }
+
+    #[test]
+    fn edited_versions_parsed_incrementally_match_the_ones_parsed_from_scratch() {
+        let config = ParserConfiguration::new(tree_sitter_java::language());
+        let options = ParsingOptions::default();
+        let base = "class A {\n  void a() {}\n\n  void b() {}\n}\n";
+        let left = "class A {\n  int x;\n  void a() {}\n\n  void b() { return; }\n}\n";
+
+        let base_file = parse_file(base, &config, &options).unwrap();
+        let incremental = base_file.parse_edited_version(left, &options).unwrap();
+        let from_scratch = parse_file(left, &config, &options).unwrap();
+
+        assert_eq!(describe(&from_scratch.root), describe(&incremental.root));
+    }
+
+    #[test]
+    fn kinds_given_in_the_options_are_kept_as_terminals() {
+        let config = ParserConfiguration::new(tree_sitter_java::language());
+        let stop_compilation_at = ["block".to_string()];
+        let options = ParsingOptions {
+            stop_compilation_at: &stop_compilation_at,
+            ..Default::default()
+        };
+
+        let parsed_file = parse_file(
+            "class A {\n  void a() {\n    a();\n  }\n}\n",
+            &config,
+            &options,
+        );
+
+        assert!(describe(&parsed_file.unwrap().root).contains("block({\n    a();\n  })"));
+    }
+
+    #[test]
+    fn separators_of_separated_kinds_are_dropped() {
+        let config = ParserConfiguration::new(tree_sitter_java::language())
+            .with_kinds_with_separated_children(&[("argument_list", ",")]);
+
+        let parsed_file = parse_file(
+            "class A {\n  void a() {\n    a(b, \"c, d\");\n  }\n}\n",
+            &config,
+            &ParsingOptions::default(),
+        );
+
+        assert!(describe(&parsed_file.unwrap().root)
+            .contains("argument_list[((() identifier(b) string_literal(\"c, d\") )())]"));
+    }
+
+    #[test]
+    fn trailing_separators_of_separated_kinds_are_kept() {
+        let config = ParserConfiguration::new(tree_sitter_java::language())
+            .with_kinds_with_separated_children(&[("enum_body", ",")])
+            .with_separated_list_delimiters(&["enum_body_declarations"]);
+        let describe_enums =
+            |src| describe(&parse_file(src, &config, &Default::default()).unwrap().root);
+
+        assert!(describe_enums("enum A { B, C, }").contains(
+            "enum_body[{({) enum_constant[identifier(B)] enum_constant[identifier(C)] ,(,) }(})]"
+        ));
+        assert!(describe_enums("enum A { B, C, ; }")
+            .contains("enum_constant[identifier(C)] ,(,) enum_body_declarations"));
+        assert!(describe_enums("enum A { B, C }").contains(
+            "enum_body[{({) enum_constant[identifier(B)] enum_constant[identifier(C)] }(})]"
+        ));
+    }
+
+    fn collect_ids(node: &CSTNode, ids: &mut Vec<uuid::Uuid>) {
+        ids.push(node.id());
+        if let CSTNode::NonTerminal(non_terminal) = node {
+            non_terminal
+                .children
+                .iter()
+                .for_each(|child| collect_ids(child, ids));
+        }
+    }
+
+    #[test]
+    fn ids_are_made_of_the_revision_tag_and_the_pre_order_index() {
+        let config = ParserConfiguration::new(tree_sitter_java::language());
+        let src = "import a.B;\nclass A { void a() throws B {} }\n";
+        let tagged = |revision_tag| ParsingOptions {
+            revision_tag: Some(revision_tag),
+            ..Default::default()
+        };
+        let parse = |revision_tag| {
+            let mut ids = vec![];
+            collect_ids(
+                &parse_file(src, &config, &tagged(revision_tag))
+                    .unwrap()
+                    .root,
+                &mut ids,
+            );
+            ids
+        };
+
+        let ids = parse(1);
+        assert_eq!(ids, parse(1));
+        assert_eq!(model::make_node_id(1, 0), ids[0]);
+        assert_eq!(
+            ids.len(),
+            ids.iter().collect::<std::collections::HashSet<_>>().len()
+        );
+
+        let other_ids = parse(2);
+        assert!(other_ids.iter().all(|id| !ids.contains(id)));
+    }
+
+    #[test]
+    fn trees_parsed_with_the_same_ids_keep_their_own_identities() {
+        let config = ParserConfiguration::new(tree_sitter_java::language())
+            .with_label_queries("(class_declaration name: (identifier) @name) @node")
+            .unwrap();
+        let options = ParsingOptions {
+            revision_tag: Some(1),
+            ..Default::default()
+        };
+        let class_identity = |root: &CSTNode| {
+            let CSTNode::NonTerminal(program) = root else {
+                panic!("The program is a non terminal");
+            };
+            program.children[0].identity().clone()
+        };
+
+        // e.g. the revisions of two merges running with the same configuration
+        let first = parse_file("class A {}", &config, &options).unwrap();
+        let second = parse_file("class B {}", &config, &options).unwrap();
+
+        assert_eq!(first.root.id(), second.root.id());
+        assert_eq!(
+            vec![("name".to_string(), "A".to_string())],
+            class_identity(&first.root)
+        );
+        assert_eq!(
+            vec![("name".to_string(), "B".to_string())],
+            class_identity(&second.root)
+        );
+    }
+
+    fn find_injected_languages(node: &CSTNode, languages: &mut Vec<String>) {
+        if let CSTNode::NonTerminal(non_terminal) = node {
+            languages.extend(non_terminal.injected_language.clone());
+            non_terminal
+                .children
+                .iter()
+                .for_each(|child| find_injected_languages(child, languages));
+        }
+    }
+
+    #[test]
+    fn trees_parsed_with_the_same_ids_keep_their_own_injected_languages() {
+        let config = ParserConfiguration::new(tree_sitter_md::language())
+            .with_injections("(fenced_code_block (info_string (language) @injection.language) (code_fence_content) @injection.content)")
+            .unwrap();
+        let javascript = ParserConfiguration::new(tree_sitter_javascript::language());
+        let toml = ParserConfiguration::new(tree_sitter_toml::language());
+        let resolve_injected_language = |name: &str| match name {
+            "js" => Some(&javascript),
+            "toml" => Some(&toml),
+            _ => None,
+        };
+        let options = ParsingOptions {
+            resolve_injected_language: &resolve_injected_language,
+            revision_tag: Some(1),
+            ..Default::default()
+        };
+        let injected_languages = |src| {
+            let mut languages = vec![];
+            find_injected_languages(
+                &parse_file(src, &config, &options).unwrap().root,
+                &mut languages,
+            );
+            languages
+        };
+
+        // e.g. the revisions of two merges running with the same configuration
+        assert_eq!(vec!["js"], injected_languages("```js\nlet a = 1;\n```\n"));
+        assert_eq!(vec!["toml"], injected_languages("```toml\na = 1\n```\n"));
+    }
+
+    #[test]
+    fn trees_parsed_without_a_revision_tag_do_not_share_ids() {
+        let config = ParserConfiguration::new(tree_sitter_java::language());
+        let src = "class A {}";
+
+        let first = parse_file(src, &config, &ParsingOptions::default()).unwrap();
+        let second = parse_file(src, &config, &ParsingOptions::default()).unwrap();
+
+        assert_ne!(first.root.id(), second.root.id());
+    }
 }
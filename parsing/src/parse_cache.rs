@@ -0,0 +1,365 @@
+use std::{
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+};
+
+use model::{retag_node_id, OwnedCSTNode, StableHasher};
+use serde::{Deserialize, Serialize};
+
+use crate::{parse::parse_file, ParserConfiguration, ParsingError, ParsingOptions};
+
+/// Bumped whenever the trees built for the same source change, so that stale entries are ignored.
+const CACHE_FORMAT_VERSION: &str = "5";
+
+/// A directory of parsed trees, keyed by a hash of the source, of its language, of the parser
+/// configuration and of the parsing options, so that files parsed by previous runs skip parsing
+/// and the parsing handlers.
+///
+/// Each revision is looked up on its own, so the cache does not parse edited versions
+/// incrementally from the tree of base: a hit skips parsing entirely, and a miss parses the file
+/// from scratch. Either way the tree is the same as the one an incremental parse would give.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCache {
+    directory: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    /// Compared with the file being parsed, as different sources may share a hash.
+    source: String,
+    root: OwnedCSTNode,
+}
+
+impl ParseCache {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        ParseCache {
+            directory: directory.into(),
+        }
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Parses the file from scratch, or loads its tree if it was already parsed with the same
+    /// language and options. Files whose language has injections are never cached, as their
+    /// regions are parsed with the configurations of other languages, which the key leaves out.
+    pub fn parse<'a>(
+        &self,
+        language_name: &str,
+        src: &'a str,
+        config: &'a ParserConfiguration,
+        options: &ParsingOptions<'_, 'a>,
+    ) -> Result<OwnedCSTNode, ParsingError> {
+        if config.injections.is_some() {
+            return parse_file(src, config, options).map(|file| OwnedCSTNode::from(&file.root));
+        }
+
+        // The same entry may be loaded for several revisions, e.g. when one is left unchanged, so
+        // its ids are given the tag of the revision being parsed
+        let revision_tag = options.get_revision_tag();
+        let path = self.get_entry_path(language_name, src, config, options);
+        if let Some(mut entry) = read_entry(&path).filter(|entry| entry.source == src) {
+            log::debug!("Loaded parsed tree from {}", path.display());
+            retag_ids(&mut entry.root, revision_tag);
+            return Ok(entry.root);
+        }
+
+        let options = ParsingOptions {
+            revision_tag: Some(revision_tag),
+            ..*options
+        };
+        let entry = CacheEntry {
+            source: src.to_string(),
+            root: OwnedCSTNode::from(&parse_file(src, config, &options)?.root),
+        };
+        if let Err(error) = self.write_entry(&path, &entry) {
+            log::warn!(
+                "Could not cache parsed tree in {}: {}",
+                path.display(),
+                error
+            );
+        }
+        Ok(entry.root)
+    }
+
+    fn get_entry_path(
+        &self,
+        language_name: &str,
+        src: &str,
+        config: &ParserConfiguration,
+        options: &ParsingOptions,
+    ) -> PathBuf {
+        let mut hasher = StableHasher::default();
+        CACHE_FORMAT_VERSION.hash(&mut hasher);
+        env!("CARGO_PKG_VERSION").hash(&mut hasher);
+        language_name.hash(&mut hasher);
+        config.hash(&mut hasher);
+        options.allow_syntax_errors.hash(&mut hasher);
+        options.stop_compilation_at.hash(&mut hasher);
+        options.disabled_handlers.hash(&mut hasher);
+        src.hash(&mut hasher);
+        self.directory
+            .join(format!("{:016x}.json", hasher.finish()))
+    }
+
+    /// Writes to a temporary file first, so that concurrent runs never read a partial entry.
+    fn write_entry(&self, path: &Path, entry: &CacheEntry) -> std::io::Result<()> {
+        std::fs::create_dir_all(&self.directory)?;
+        let temporary_path = path.with_extension(format!("{}.tmp", std::process::id()));
+        std::fs::write(&temporary_path, serde_json::to_vec(entry)?)?;
+        std::fs::rename(temporary_path, path)
+    }
+}
+
+fn retag_ids(node: &mut OwnedCSTNode, revision_tag: u32) {
+    match node {
+        OwnedCSTNode::Terminal(terminal) => {
+            terminal.id = retag_node_id(terminal.id, revision_tag);
+        }
+        OwnedCSTNode::NonTerminal(non_terminal) => {
+            non_terminal.id = retag_node_id(non_terminal.id, revision_tag);
+            for child in non_terminal.children.iter_mut() {
+                retag_ids(child, revision_tag);
+            }
+        }
+    }
+}
+
+fn read_entry(path: &Path) -> Option<CacheEntry> {
+    let contents = std::fs::read(path).ok()?;
+    serde_json::from_slice(&contents)
+        .inspect_err(|error| {
+            log::warn!(
+                "Ignoring corrupted cache entry {}: {}",
+                path.display(),
+                error
+            )
+        })
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use model::OwnedCSTNode;
+
+    use super::ParseCache;
+    use crate::{parse_file, ParserConfiguration, ParsingOptions};
+
+    fn make_cache(name: &str) -> ParseCache {
+        let directory = std::env::temp_dir()
+            .join("generic-merge-parse-cache")
+            .join(format!("{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        ParseCache::new(directory)
+    }
+
+    fn rename_identifiers(node: &mut OwnedCSTNode, name: &str) {
+        match node {
+            OwnedCSTNode::Terminal(terminal) if terminal.kind == "identifier" => {
+                terminal.value = name.into()
+            }
+            OwnedCSTNode::Terminal(_) => {}
+            OwnedCSTNode::NonTerminal(non_terminal) => non_terminal
+                .children
+                .iter_mut()
+                .for_each(|child| rename_identifiers(child, name)),
+        }
+    }
+
+    #[test]
+    fn parsed_trees_are_loaded_from_the_cache() {
+        let cache = make_cache("loaded");
+        let config = ParserConfiguration::new(tree_sitter_java::language());
+        let options = ParsingOptions::default();
+        let src = "class A { void a() {} }";
+
+        let parsed = cache.parse("java", src, &config, &options).unwrap();
+        let entries: Vec<_> = std::fs::read_dir(cache.directory()).unwrap().collect();
+        assert_eq!(1, entries.len());
+
+        // Tampering with the entry shows that the tree is not parsed again
+        let path = entries[0].as_ref().unwrap().path();
+        let mut entry: super::CacheEntry =
+            serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(parsed, entry.root);
+        rename_identifiers(&mut entry.root, "B");
+        std::fs::write(&path, serde_json::to_vec(&entry).unwrap()).unwrap();
+
+        let loaded = cache.parse("java", src, &config, &options).unwrap();
+        assert_ne!(entry.root.id(), loaded.id());
+        assert!(loaded.as_cst_node().contents().contains("class B"));
+    }
+
+    #[test]
+    fn trees_are_cached_per_language_and_options() {
+        let cache = make_cache("keys");
+        let config = ParserConfiguration::new(tree_sitter_java::language());
+        let src = "class A { void a() {} }";
+        let stop_compilation_at = ["block".to_string()];
+
+        cache
+            .parse("java", src, &config, &ParsingOptions::default())
+            .unwrap();
+        cache
+            .parse("jav", src, &config, &Default::default())
+            .unwrap();
+        let parsed = cache
+            .parse(
+                "java",
+                src,
+                &config,
+                &ParsingOptions {
+                    stop_compilation_at: &stop_compilation_at,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        assert_eq!(3, std::fs::read_dir(cache.directory()).unwrap().count());
+        assert!(parsed.as_cst_node().contents().contains("{}"));
+    }
+
+    #[test]
+    fn trees_are_cached_per_parser_configuration() {
+        let cache = make_cache("configurations");
+        let src = "class A { void a() {} }";
+        let configurations = [
+            ParserConfiguration::new(tree_sitter_java::language()),
+            ParserConfiguration::new(tree_sitter_java::language())
+                .with_kinds_with_unordered_children(&["class_body"]),
+            ParserConfiguration::new(tree_sitter_java::language())
+                .with_label_queries("(method_declaration name: (identifier) @name) @node")
+                .unwrap(),
+        ];
+
+        for config in configurations.iter() {
+            cache
+                .parse("java", src, config, &Default::default())
+                .unwrap();
+        }
+
+        assert_eq!(3, std::fs::read_dir(cache.directory()).unwrap().count());
+    }
+
+    #[test]
+    fn entries_of_other_sources_are_parsed_again() {
+        let cache = make_cache("collisions");
+        let config = ParserConfiguration::new(tree_sitter_java::language());
+        let src = "class A {}";
+
+        cache
+            .parse("java", src, &config, &Default::default())
+            .unwrap();
+        // Stands for another source whose entry has the same hash
+        for entry in std::fs::read_dir(cache.directory()).unwrap() {
+            let path = entry.unwrap().path();
+            let mut entry: super::CacheEntry =
+                serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+            entry.source = "class B {}".into();
+            rename_identifiers(&mut entry.root, "B");
+            std::fs::write(&path, serde_json::to_vec(&entry).unwrap()).unwrap();
+        }
+
+        let parsed = cache
+            .parse("java", src, &config, &Default::default())
+            .unwrap();
+        assert!(parsed.as_cst_node().contents().contains("class A"));
+    }
+
+    #[test]
+    fn trees_loaded_from_the_cache_get_the_ids_of_their_revision() {
+        let cache = make_cache("tagged");
+        let config = ParserConfiguration::new(tree_sitter_java::language());
+        let src = "class A {}";
+        let tagged = |revision_tag| ParsingOptions {
+            revision_tag: Some(revision_tag),
+            ..Default::default()
+        };
+
+        let parsed = cache.parse("java", src, &config, &tagged(1)).unwrap();
+        let loaded = cache.parse("java", src, &config, &tagged(2)).unwrap();
+        let expected = OwnedCSTNode::from(&parse_file(src, &config, &tagged(2)).unwrap().root);
+
+        assert_eq!(expected, loaded);
+        assert_ne!(parsed.id(), loaded.id());
+    }
+
+    fn collect_ids(node: &OwnedCSTNode, ids: &mut HashSet<uuid::Uuid>) {
+        ids.insert(node.id());
+        if let OwnedCSTNode::NonTerminal(non_terminal) = node {
+            non_terminal
+                .children
+                .iter()
+                .for_each(|child| collect_ids(child, ids));
+        }
+    }
+
+    #[test]
+    fn nodes_created_by_parsing_handlers_get_the_ids_of_their_revision_too() {
+        let cache = make_cache("derived");
+        // The Java handlers group the thrown types in a type_list of their own
+        let config = ParserConfiguration::new(tree_sitter_java::language())
+            .with_handlers(parsing_handlers::get_default_java_parsing_handlers());
+        let src = "class A { void a() throws B, C {} }";
+        let tagged = |revision_tag| ParsingOptions {
+            revision_tag: Some(revision_tag),
+            ..Default::default()
+        };
+
+        let parsed = cache.parse("java", src, &config, &tagged(1)).unwrap();
+        let loaded = cache.parse("java", src, &config, &tagged(2)).unwrap();
+        let expected = OwnedCSTNode::from(&parse_file(src, &config, &tagged(2)).unwrap().root);
+
+        assert_eq!(expected, loaded);
+        let mut parsed_ids = HashSet::new();
+        collect_ids(&parsed, &mut parsed_ids);
+        let mut loaded_ids = HashSet::new();
+        collect_ids(&loaded, &mut loaded_ids);
+        assert!(parsed_ids.is_disjoint(&loaded_ids));
+    }
+
+    #[test]
+    fn trees_loaded_from_the_cache_match_the_ones_parsed_incrementally() {
+        let cache = make_cache("incremental");
+        let config = ParserConfiguration::new(tree_sitter_java::language());
+        let base = "class A {\n  void a() {}\n}\n";
+        let left = "class A {\n  int x;\n  void a() {}\n}\n";
+        let tagged = |revision_tag| ParsingOptions {
+            revision_tag: Some(revision_tag),
+            ..Default::default()
+        };
+
+        cache.parse("java", left, &config, &tagged(1)).unwrap();
+        let loaded = cache.parse("java", left, &config, &tagged(2)).unwrap();
+        let base_file = parse_file(base, &config, &tagged(1)).unwrap();
+        let incremental = base_file.parse_edited_version(left, &tagged(2)).unwrap();
+
+        assert_eq!(OwnedCSTNode::from(&incremental.root), loaded);
+    }
+
+    #[test]
+    fn corrupted_entries_are_parsed_again() {
+        let cache = make_cache("corrupted");
+        let config = ParserConfiguration::new(tree_sitter_java::language());
+        let src = "class A {}";
+
+        let parsed = cache
+            .parse("java", src, &config, &Default::default())
+            .unwrap();
+        for entry in std::fs::read_dir(cache.directory()).unwrap() {
+            std::fs::write(entry.unwrap().path(), "{").unwrap();
+        }
+
+        assert_eq!(
+            parsed.as_cst_node().contents(),
+            cache
+                .parse("java", src, &config, &Default::default())
+                .unwrap()
+                .as_cst_node()
+                .contents()
+        );
+    }
+}
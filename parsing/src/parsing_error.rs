@@ -0,0 +1,135 @@
+use std::fmt;
+
+use model::Point;
+
+/// Longest excerpt of unexpected source code shown in a syntax error.
+const MAX_EXCERPT_LENGTH: usize = 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsingError {
+    InvalidLanguage,
+    InvalidRange,
+    ParserFailed,
+    SyntaxError(SyntaxError),
+}
+
+impl fmt::Display for ParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParsingError::InvalidLanguage => {
+                write!(f, "There was an error while setting the parser language")
+            }
+            ParsingError::InvalidRange => {
+                write!(f, "There was an error while setting the range to be parsed")
+            }
+            ParsingError::ParserFailed => write!(f, "It was not possible to parse the tree"),
+            ParsingError::SyntaxError(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for ParsingError {}
+
+/// The first `ERROR` or `MISSING` node found by tree-sitter. Positions are zero-based, but are
+/// displayed one-based, as editors do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+    pub kind: SyntaxErrorKind,
+    pub position: Point,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyntaxErrorKind {
+    /// Source code the grammar could not make sense of.
+    Unexpected(String),
+    /// A node the parser inserted to recover, e.g. a `;`.
+    Missing(String),
+}
+
+impl SyntaxError {
+    pub(crate) fn find(node: tree_sitter::Node, src: &str) -> Option<SyntaxError> {
+        let kind = if node.is_missing() {
+            SyntaxErrorKind::Missing(node.kind().to_string())
+        } else if node.is_error() {
+            SyntaxErrorKind::Unexpected(get_excerpt(&src[node.byte_range()]))
+        } else if node.has_error() {
+            let mut cursor = node.walk();
+            let error = node
+                .children(&mut cursor)
+                .find_map(|child| SyntaxError::find(child, src));
+            return error;
+        } else {
+            return None;
+        };
+
+        Some(SyntaxError {
+            kind,
+            position: Point {
+                row: node.start_position().row,
+                column: node.start_position().column,
+            },
+        })
+    }
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Syntax error at line {}, column {}: ",
+            self.position.row + 1,
+            self.position.column + 1
+        )?;
+        match &self.kind {
+            SyntaxErrorKind::Unexpected(excerpt) => write!(f, "unexpected \"{}\"", excerpt),
+            SyntaxErrorKind::Missing(kind) => write!(f, "missing \"{}\"", kind),
+        }
+    }
+}
+
+fn get_excerpt(text: &str) -> String {
+    let first_line = text.trim().lines().next().unwrap_or_default();
+    match first_line.char_indices().nth(MAX_EXCERPT_LENGTH) {
+        Some((index, _)) => format!("{}...", &first_line[..index]),
+        None => first_line.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::Point;
+
+    use super::{SyntaxError, SyntaxErrorKind};
+
+    fn find(src: &str) -> Option<SyntaxError> {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(tree_sitter_java::language()).unwrap();
+        let tree = parser.parse(src, None).unwrap();
+        SyntaxError::find(tree.root_node(), src)
+    }
+
+    #[test]
+    fn valid_files_have_no_syntax_errors() {
+        assert_eq!(None, find("class A {\n  int a;\n}\n"));
+    }
+
+    #[test]
+    fn missing_nodes_are_reported_with_their_position() {
+        let error = find("class A {\n  int a\n}\n").unwrap();
+
+        assert_eq!(SyntaxErrorKind::Missing(";".into()), error.kind);
+        assert_eq!(Point { row: 1, column: 7 }, error.position);
+        assert_eq!(
+            "Syntax error at line 2, column 8: missing \";\"",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn unexpected_code_is_reported_with_an_excerpt() {
+        let error = find("class A {\n  void a() { int = = 1 }\n}\n").unwrap();
+
+        assert!(matches!(error.kind, SyntaxErrorKind::Unexpected(_)));
+        assert_eq!(1, error.position.row);
+    }
+}
@@ -1,30 +1,283 @@
-use model::Language;
+use crate::grammar::{get_builtin_grammar, get_grammar_kind, load_grammar_from_library};
+use crate::injections::Injections;
+use crate::label_queries::LabelQueries;
+use model::LanguageDefinition;
 use parsing_handlers::ParsingHandlers;
-use std::collections::HashSet;
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{Hash, Hasher},
+};
 
 pub struct ParserConfiguration {
     pub(crate) language: tree_sitter::Language,
     pub(crate) stop_compilation_at: HashSet<&'static str>,
     pub(crate) kinds_with_unordered_children: HashSet<&'static str>,
     pub(crate) block_end_delimiters: HashSet<&'static str>,
+    pub(crate) kinds_with_separated_children: HashMap<&'static str, &'static str>,
+    pub(crate) separated_list_delimiters: HashSet<&'static str>,
     pub(crate) handlers: ParsingHandlers,
+    pub(crate) label_queries: Option<LabelQueries>,
+    pub(crate) injections: Option<Injections>,
+    /// The library a grammar loaded at runtime comes from. It is declared last so that it is
+    /// unloaded after the queries that point to its language.
+    _grammar_library: Option<libloading::Library>,
 }
 
-impl From<Language> for ParserConfiguration {
-    fn from(language: Language) -> Self {
-        match language {
-            Language::Java => ParserConfiguration {
-                language: tree_sitter_java::language(),
-                stop_compilation_at: [].into(),
-                kinds_with_unordered_children: [
-                    "interface_body",
-                    "class_body",
-                    "enum_body_declarations",
-                ]
-                .into(),
-                block_end_delimiters: ["}"].into(),
-                handlers: ParsingHandlers::from(Language::Java),
-            },
+impl ParserConfiguration {
+    /// Creates a configuration for a grammar without any tweak, to be built upon by languages
+    /// that are not built into the tool.
+    pub fn new(language: tree_sitter::Language) -> Self {
+        ParserConfiguration {
+            language,
+            stop_compilation_at: HashSet::new(),
+            kinds_with_unordered_children: HashSet::new(),
+            block_end_delimiters: HashSet::new(),
+            kinds_with_separated_children: HashMap::new(),
+            separated_list_delimiters: HashSet::new(),
+            handlers: ParsingHandlers::new(vec![]),
+            label_queries: None,
+            injections: None,
+            _grammar_library: None,
         }
     }
+
+    pub fn with_stop_compilation_at(mut self, kinds: &[&'static str]) -> Self {
+        self.stop_compilation_at.extend(kinds);
+        self
+    }
+
+    pub fn with_kinds_with_unordered_children(mut self, kinds: &[&'static str]) -> Self {
+        self.kinds_with_unordered_children.extend(kinds);
+        self
+    }
+
+    pub fn with_block_end_delimiters(mut self, kinds: &[&'static str]) -> Self {
+        self.block_end_delimiters.extend(kinds);
+        self
+    }
+
+    /// Children of the given kinds are separated by a token, e.g. `,`, which is dropped while
+    /// parsing, so that adding an element to the list does not require aligning separators.
+    /// Trailing separators, which no element follows, are kept.
+    pub fn with_kinds_with_separated_children(
+        mut self,
+        kinds: &[(&'static str, &'static str)],
+    ) -> Self {
+        self.kinds_with_separated_children
+            .extend(kinds.iter().copied());
+        self
+    }
+
+    /// Kinds of the children of separated lists that are not elements of the list, e.g. the
+    /// declarations following the constants of a Java enum, so that a separator before them is
+    /// kept as a trailing one.
+    pub fn with_separated_list_delimiters(mut self, kinds: &[&'static str]) -> Self {
+        self.separated_list_delimiters.extend(kinds);
+        self
+    }
+
+    pub fn with_handlers(self, handlers: ParsingHandlers) -> Self {
+        ParserConfiguration { handlers, ..self }
+    }
+
+    pub fn handlers(&self) -> &ParsingHandlers {
+        &self.handlers
+    }
+
+    /// Lets the options of the handlers be set, e.g. from the command line.
+    pub fn handlers_mut(&mut self) -> &mut ParsingHandlers {
+        &mut self.handlers
+    }
+
+    /// Computes the identity of nodes with the given tree-sitter queries while parsing.
+    pub fn with_label_queries(self, source: &str) -> Result<Self, String> {
+        Ok(ParserConfiguration {
+            label_queries: Some(LabelQueries::new(self.language, source)?),
+            ..self
+        })
+    }
+
+    pub fn label_queries(&self) -> Option<&LabelQueries> {
+        self.label_queries.as_ref()
+    }
+
+    /// Parses regions of the file written in other languages, found with tree-sitter injection
+    /// queries, with the configuration of their own language.
+    pub fn with_injections(self, source: &str) -> Result<Self, String> {
+        Ok(ParserConfiguration {
+            injections: Some(Injections::new(self.language, source)?),
+            ..self
+        })
+    }
+
+    pub fn injections(&self) -> Option<&Injections> {
+        self.injections.as_ref()
+    }
+
+    /// Looks up the kinds named by a language definition in the grammar. The returned names are
+    /// owned by the grammar, which stays loaded as long as the configuration does.
+    pub fn get_grammar_kinds(&self, kinds: &[String]) -> Result<Vec<&'static str>, String> {
+        kinds
+            .iter()
+            .map(|kind| self.get_grammar_kind(kind))
+            .collect()
+    }
+
+    pub fn get_grammar_kind(&self, kind: &str) -> Result<&'static str, String> {
+        get_grammar_kind(self.language, kind)
+            .ok_or(format!("Unknown node kind \"{}\" in the grammar", kind))
+    }
+}
+
+/// Hashes everything the trees built with the configuration depend on, so that the parse cache
+/// tells apart the trees built with different configurations of the same language. Injected
+/// regions are left out, as files with injections are never cached.
+impl Hash for ParserConfiguration {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.language.version().hash(state);
+        for kind_id in 0..self.language.node_kind_count() as u16 {
+            self.language.node_kind_for_id(kind_id).hash(state);
+        }
+        for field_id in 1..=self.language.field_count() as u16 {
+            self.language.field_name_for_id(field_id).hash(state);
+        }
+
+        for kinds in [
+            &self.stop_compilation_at,
+            &self.kinds_with_unordered_children,
+            &self.block_end_delimiters,
+            &self.separated_list_delimiters,
+        ] {
+            let mut kinds: Vec<_> = kinds.iter().collect();
+            kinds.sort();
+            kinds.hash(state);
+        }
+        let mut kinds_with_separated_children: Vec<_> =
+            self.kinds_with_separated_children.iter().collect();
+        kinds_with_separated_children.sort();
+        kinds_with_separated_children.hash(state);
+
+        self.handlers.hash(state);
+        self.label_queries
+            .as_ref()
+            .map(LabelQueries::source)
+            .hash(state);
+    }
+}
+
+impl TryFrom<&LanguageDefinition> for ParserConfiguration {
+    type Error = String;
+
+    fn try_from(definition: &LanguageDefinition) -> Result<Self, Self::Error> {
+        let configuration = match &definition.grammar_path {
+            Some(path) => {
+                let (language, library) = load_grammar_from_library(path, &definition.grammar)?;
+                ParserConfiguration {
+                    _grammar_library: Some(library),
+                    ..ParserConfiguration::new(language)
+                }
+            }
+            None => ParserConfiguration::new(get_builtin_grammar(&definition.grammar).ok_or(
+                format!(
+                    "Unknown grammar \"{}\" for language \"{}\"",
+                    definition.grammar, definition.name
+                ),
+            )?),
+        };
+
+        let stop_compilation_at =
+            configuration.get_grammar_kinds(&definition.stop_compilation_at)?;
+        let kinds_with_unordered_children =
+            configuration.get_grammar_kinds(&definition.kinds_with_unordered_children)?;
+        let block_end_delimiters =
+            configuration.get_grammar_kinds(&definition.block_end_delimiters)?;
+        let kinds_with_separated_children = definition
+            .kinds_with_separated_children
+            .iter()
+            .map(|(kind, separator)| {
+                Ok((
+                    configuration.get_grammar_kind(kind)?,
+                    configuration.get_grammar_kind(separator)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let separated_list_delimiters = configuration.get_grammar_kinds(&definition.delimiters)?;
+
+        let configuration = configuration
+            .with_stop_compilation_at(&stop_compilation_at)
+            .with_kinds_with_unordered_children(&kinds_with_unordered_children)
+            .with_block_end_delimiters(&block_end_delimiters)
+            .with_kinds_with_separated_children(&kinds_with_separated_children)
+            .with_separated_list_delimiters(&separated_list_delimiters);
+
+        let configuration = match &definition.label_queries {
+            Some(path) => {
+                let source = std::fs::read_to_string(path).map_err(|error| {
+                    format!("Could not read label queries {}: {}", path.display(), error)
+                })?;
+                configuration.with_label_queries(&source)?
+            }
+            None => configuration,
+        };
+
+        match &definition.injections {
+            Some(path) => {
+                let source = std::fs::read_to_string(path).map_err(|error| {
+                    format!("Could not read injections {}: {}", path.display(), error)
+                })?;
+                configuration.with_injections(&source)
+            }
+            None => Ok(configuration),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::LanguageDefinition;
+
+    use super::ParserConfiguration;
+
+    #[test]
+    fn definitions_use_the_builtin_grammar_of_the_given_name() {
+        let definition = LanguageDefinition {
+            name: "java-without-handlers".into(),
+            grammar: "java".into(),
+            kinds_with_unordered_children: vec!["class_body".into()],
+            ..Default::default()
+        };
+
+        let configuration = ParserConfiguration::try_from(&definition).unwrap();
+        assert_eq!(
+            tree_sitter_java::language().node_kind_count(),
+            configuration.language.node_kind_count()
+        );
+        assert!(configuration
+            .kinds_with_unordered_children
+            .contains("class_body"));
+    }
+
+    #[test]
+    fn definitions_with_an_unknown_grammar_are_rejected() {
+        let definition = LanguageDefinition {
+            name: "cobol".into(),
+            grammar: "cobol".into(),
+            ..Default::default()
+        };
+
+        assert!(ParserConfiguration::try_from(&definition).is_err());
+    }
+
+    #[test]
+    fn definitions_with_kinds_unknown_to_the_grammar_are_rejected() {
+        let definition = LanguageDefinition {
+            name: "java-with-a-typo".into(),
+            grammar: "java".into(),
+            kinds_with_unordered_children: vec!["clas_body".into()],
+            ..Default::default()
+        };
+
+        let error = ParserConfiguration::try_from(&definition).err().unwrap();
+        assert!(error.contains("clas_body"), "{}", error);
+    }
 }
@@ -0,0 +1,68 @@
+use model::{cst_node::NonTerminal, CSTNode};
+
+/// The order of modifiers and annotations has no meaning, so `public static` and
+/// `static public`, or annotations added by both sides at different positions, are merged as a
+/// set rather than as a sequence.
+pub fn mark_modifiers_as_unordered(root: CSTNode<'_>) -> CSTNode<'_> {
+    match root {
+        CSTNode::Terminal(_) => root,
+        CSTNode::NonTerminal(non_terminal) => CSTNode::NonTerminal(NonTerminal {
+            id: non_terminal.id,
+            kind: non_terminal.kind,
+            start_position: non_terminal.start_position,
+            end_position: non_terminal.end_position,
+            are_children_unordered: non_terminal.are_children_unordered
+                || non_terminal.kind == "modifiers",
+            identity: non_terminal.identity,
+            injected_language: non_terminal.injected_language,
+            children: non_terminal
+                .children
+                .into_iter()
+                .map(mark_modifiers_as_unordered)
+                .collect(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    #[test]
+    fn it_marks_nested_modifiers_as_unordered() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "class_body",
+            children: vec![CSTNode::NonTerminal(NonTerminal {
+                kind: "method_declaration",
+                children: vec![CSTNode::NonTerminal(NonTerminal {
+                    kind: "modifiers",
+                    children: vec![CSTNode::Terminal(Terminal {
+                        kind: "public",
+                        value: "public",
+                        ..Default::default()
+                    })],
+                    ..Default::default()
+                })],
+                ..Default::default()
+            })],
+            ..Default::default()
+        });
+
+        let CSTNode::NonTerminal(class_body) = super::mark_modifiers_as_unordered(root) else {
+            panic!("Expected a non-terminal");
+        };
+        let CSTNode::NonTerminal(method) = &class_body.children[0] else {
+            panic!("Expected a non-terminal");
+        };
+        let CSTNode::NonTerminal(modifiers) = &method.children[0] else {
+            panic!("Expected a non-terminal");
+        };
+
+        assert!(!class_body.are_children_unordered);
+        assert!(!method.are_children_unordered);
+        assert!(modifiers.are_children_unordered);
+    }
+}
@@ -1,11 +1,29 @@
+mod mark_modifiers_as_unordered;
 mod remove_block_comments;
 mod tweak_import_declarations;
+mod tweak_type_lists;
 
-use crate::ParsingHandlers;
+use crate::{FunctionHandler, ParsingHandlers};
 
+/// Comments inside modifiers and type lists would be taken for elements of the sets, so those
+/// are marked as unordered once comments are gone.
 pub fn get_default_java_parsing_handlers() -> ParsingHandlers {
     ParsingHandlers::new(vec![
-        tweak_import_declarations::tweak_import_declarations,
-        remove_block_comments::remove_block_comments,
+        Box::new(FunctionHandler::new(
+            "tweak_import_declarations",
+            tweak_import_declarations::tweak_import_declarations,
+        )),
+        Box::new(remove_block_comments::RemoveBlockComments::default()),
+        Box::new(
+            FunctionHandler::new(
+                "mark_modifiers_as_unordered",
+                mark_modifiers_as_unordered::mark_modifiers_as_unordered,
+            )
+            .after(&["remove_block_comments"]),
+        ),
+        Box::new(
+            FunctionHandler::new("tweak_type_lists", tweak_type_lists::tweak_type_lists)
+                .after(&["remove_block_comments"]),
+        ),
     ])
 }
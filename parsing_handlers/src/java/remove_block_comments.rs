@@ -1,21 +1,64 @@
 use model::{cst_node::NonTerminal, CSTNode};
 
-pub fn remove_block_comments(root: CSTNode<'_>) -> CSTNode<'_> {
-    match root {
-        CSTNode::Terminal(_) => root,
-        CSTNode::NonTerminal(non_terminal) => CSTNode::NonTerminal(NonTerminal {
-            id: non_terminal.id,
-            kind: non_terminal.kind,
-            start_position: non_terminal.start_position,
-            end_position: non_terminal.end_position,
-            children: non_terminal
-                .children
-                .into_iter()
-                .filter(|node| node.kind() != "block_comment" && node.kind() != "line_comment")
-                .map(|node| remove_block_comments(node))
-                .collect(),
-            are_children_unordered: non_terminal.are_children_unordered,
-        }),
+use crate::ParsingHandler;
+
+/// Removes comments, which otherwise get in the way of matching. The `kinds` option lists the
+/// comment kinds to remove, e.g. `line_comment` to keep block comments.
+pub struct RemoveBlockComments {
+    kinds: Vec<String>,
+}
+
+impl Default for RemoveBlockComments {
+    fn default() -> Self {
+        RemoveBlockComments {
+            kinds: vec!["block_comment".into(), "line_comment".into()],
+        }
+    }
+}
+
+impl ParsingHandler for RemoveBlockComments {
+    fn name(&self) -> &str {
+        "remove_block_comments"
+    }
+
+    fn set_option(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "kinds" => {
+                self.kinds = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|kind| !kind.is_empty())
+                    .map(String::from)
+                    .collect();
+                Ok(())
+            }
+            _ => Err(format!(
+                "Parsing handler {} has no option \"{}\"",
+                self.name(),
+                key
+            )),
+        }
+    }
+
+    fn handle<'a>(&self, root: CSTNode<'a>) -> CSTNode<'a> {
+        match root {
+            CSTNode::Terminal(_) => root,
+            CSTNode::NonTerminal(non_terminal) => CSTNode::NonTerminal(NonTerminal {
+                id: non_terminal.id,
+                kind: non_terminal.kind,
+                start_position: non_terminal.start_position,
+                end_position: non_terminal.end_position,
+                children: non_terminal
+                    .children
+                    .into_iter()
+                    .filter(|node| !self.kinds.iter().any(|kind| kind == node.kind()))
+                    .map(|node| self.handle(node))
+                    .collect(),
+                are_children_unordered: non_terminal.are_children_unordered,
+                identity: non_terminal.identity.clone(),
+                injected_language: non_terminal.injected_language.clone(),
+            }),
+        }
     }
 }
 
@@ -26,6 +69,10 @@ mod tests {
         CSTNode,
     };
 
+    use crate::ParsingHandler;
+
+    use super::RemoveBlockComments;
+
     #[test]
     fn it_removes_first_level_comments() {
         let root = CSTNode::NonTerminal(NonTerminal {
@@ -55,7 +102,7 @@ mod tests {
         });
 
         assert_eq!(
-            super::remove_block_comments(root).contents(),
+            RemoveBlockComments::default().handle(root).contents(),
             expected_root.contents()
         );
     }
@@ -95,8 +142,41 @@ mod tests {
         });
 
         assert_eq!(
-            super::remove_block_comments(root).contents(),
+            RemoveBlockComments::default().handle(root).contents(),
             expected_root.contents()
         );
     }
+
+    #[test]
+    fn it_removes_only_the_configured_kinds() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            children: vec![
+                CSTNode::Terminal(Terminal {
+                    kind: "block_comment",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "line_comment",
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        });
+
+        let mut handler = RemoveBlockComments::default();
+        handler.set_option("kinds", "line_comment").unwrap();
+        assert!(handler.set_option("unknown", "").is_err());
+
+        let CSTNode::NonTerminal(result) = handler.handle(root) else {
+            panic!("Expected a non-terminal");
+        };
+        assert_eq!(
+            vec!["block_comment"],
+            result
+                .children
+                .iter()
+                .map(CSTNode::kind)
+                .collect::<Vec<_>>()
+        );
+    }
 }
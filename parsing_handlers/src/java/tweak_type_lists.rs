@@ -0,0 +1,238 @@
+use model::{cst_node::NonTerminal, derive_node_id, CSTNode};
+
+/// The types listed by `implements`, `extends` (of interfaces), `permits` and `throws` form a
+/// set, so they are kept in an unordered `type_list`. The types of `throws` are grouped into a
+/// `type_list` of their own, as the grammar lists them directly under the clause. Comments kept
+/// between the types are moved after the list, so they are not taken for elements of the set.
+pub fn tweak_type_lists(root: CSTNode<'_>) -> CSTNode<'_> {
+    match root {
+        CSTNode::Terminal(_) => root,
+        CSTNode::NonTerminal(non_terminal) => {
+            let children = non_terminal
+                .children
+                .into_iter()
+                .map(tweak_type_lists)
+                .flat_map(move_comments_after_type_list)
+                .collect();
+
+            let non_terminal = NonTerminal {
+                children,
+                ..non_terminal
+            };
+
+            CSTNode::NonTerminal(match non_terminal.kind {
+                "type_list" => NonTerminal {
+                    are_children_unordered: true,
+                    identity: vec![],
+                    injected_language: None,
+                    ..non_terminal
+                },
+                "throws" => group_thrown_types(non_terminal),
+                _ => non_terminal,
+            })
+        }
+    }
+}
+
+fn is_comment(node: &CSTNode) -> bool {
+    matches!(node.kind(), "block_comment" | "line_comment")
+}
+
+fn move_comments_after_type_list(node: CSTNode<'_>) -> Vec<CSTNode<'_>> {
+    match node {
+        CSTNode::NonTerminal(type_list) if type_list.kind == "type_list" => {
+            let (comments, types): (Vec<_>, Vec<_>) =
+                type_list.children.into_iter().partition(is_comment);
+            let type_list = CSTNode::NonTerminal(NonTerminal {
+                children: types,
+                ..type_list
+            });
+            [type_list].into_iter().chain(comments).collect()
+        }
+        node => vec![node],
+    }
+}
+
+fn group_thrown_types(throws: NonTerminal<'_>) -> NonTerminal<'_> {
+    let (keywords, others): (Vec<_>, Vec<_>) = throws
+        .children
+        .into_iter()
+        .partition(|child| child.kind() == "throws");
+    let (comments, types): (Vec<_>, Vec<_>) = others.into_iter().partition(is_comment);
+
+    let (Some(first), Some(last)) = (types.first(), types.last()) else {
+        return NonTerminal {
+            children: keywords.into_iter().chain(comments).collect(),
+            ..throws
+        };
+    };
+
+    // Derived from the id of the clause, the id of the list follows it when the tree is retagged
+    let type_list = CSTNode::NonTerminal(NonTerminal {
+        id: derive_node_id(throws.id, "type_list"),
+        kind: "type_list",
+        start_position: first.start_position(),
+        end_position: last.end_position(),
+        are_children_unordered: true,
+        identity: vec![],
+        injected_language: None,
+        children: types,
+    });
+
+    NonTerminal {
+        children: keywords
+            .into_iter()
+            .chain([type_list])
+            .chain(comments)
+            .collect(),
+        ..throws
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    fn make_terminal<'a>(kind: &'a str, value: &'a str) -> CSTNode<'a> {
+        CSTNode::Terminal(Terminal {
+            kind,
+            value,
+            ..Default::default()
+        })
+    }
+
+    fn get_kinds<'a>(node: &'a CSTNode<'a>) -> Vec<&'a str> {
+        match node {
+            CSTNode::NonTerminal(non_terminal) => {
+                non_terminal.children.iter().map(CSTNode::kind).collect()
+            }
+            CSTNode::Terminal(_) => panic!("Expected a non-terminal"),
+        }
+    }
+
+    #[test]
+    fn type_lists_are_unordered() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "super_interfaces",
+            children: vec![
+                make_terminal("implements", "implements"),
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "type_list",
+                    children: vec![
+                        make_terminal("type_identifier", "A"),
+                        make_terminal("type_identifier", "B"),
+                    ],
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        });
+
+        let result = super::tweak_type_lists(root);
+        let CSTNode::NonTerminal(super_interfaces) = &result else {
+            panic!("Expected a non-terminal");
+        };
+        let CSTNode::NonTerminal(type_list) = &super_interfaces.children[1] else {
+            panic!("Expected a non-terminal");
+        };
+
+        assert!(type_list.are_children_unordered);
+        assert_eq!(
+            vec!["type_identifier", "type_identifier"],
+            get_kinds(&super_interfaces.children[1])
+        );
+    }
+
+    #[test]
+    fn thrown_types_are_grouped_into_a_type_list() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "throws",
+            children: vec![
+                make_terminal("throws", "throws"),
+                make_terminal("type_identifier", "A"),
+                make_terminal("type_identifier", "B"),
+            ],
+            ..Default::default()
+        });
+
+        let result = super::tweak_type_lists(root);
+        let CSTNode::NonTerminal(throws) = &result else {
+            panic!("Expected a non-terminal");
+        };
+        let CSTNode::NonTerminal(type_list) = &throws.children[1] else {
+            panic!("Expected a non-terminal");
+        };
+
+        assert_eq!(vec!["throws", "type_list"], get_kinds(&result));
+        assert!(type_list.are_children_unordered);
+        assert_eq!(
+            vec!["type_identifier", "type_identifier"],
+            get_kinds(&throws.children[1])
+        );
+    }
+
+    #[test]
+    fn comments_between_thrown_types_are_moved_after_the_type_list() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "throws",
+            children: vec![
+                make_terminal("throws", "throws"),
+                make_terminal("type_identifier", "A"),
+                make_terminal("block_comment", "/* checked */"),
+                make_terminal("type_identifier", "B"),
+            ],
+            ..Default::default()
+        });
+
+        let result = super::tweak_type_lists(root);
+        let CSTNode::NonTerminal(throws) = &result else {
+            panic!("Expected a non-terminal");
+        };
+
+        assert_eq!(
+            vec!["throws", "type_list", "block_comment"],
+            get_kinds(&result)
+        );
+        assert_eq!(
+            vec!["type_identifier", "type_identifier"],
+            get_kinds(&throws.children[1])
+        );
+    }
+
+    #[test]
+    fn comments_between_listed_types_are_moved_after_the_type_list() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "super_interfaces",
+            children: vec![
+                make_terminal("implements", "implements"),
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "type_list",
+                    children: vec![
+                        make_terminal("type_identifier", "A"),
+                        make_terminal("line_comment", "// first"),
+                        make_terminal("type_identifier", "B"),
+                    ],
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        });
+
+        let result = super::tweak_type_lists(root);
+        let CSTNode::NonTerminal(super_interfaces) = &result else {
+            panic!("Expected a non-terminal");
+        };
+
+        assert_eq!(
+            vec!["implements", "type_list", "line_comment"],
+            get_kinds(&result)
+        );
+        assert_eq!(
+            vec!["type_identifier", "type_identifier"],
+            get_kinds(&super_interfaces.children[1])
+        );
+    }
+}
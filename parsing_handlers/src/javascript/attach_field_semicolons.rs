@@ -0,0 +1,126 @@
+use model::{cst_node::NonTerminal, CSTNode};
+
+/// The grammar places the semicolon that ends a class field next to it, in the class body.
+/// As class bodies are merged regardless of the order of their members, the semicolon is moved
+/// into the field it ends, so that it is kept along with it.
+pub fn attach_field_semicolons(root: CSTNode<'_>) -> CSTNode<'_> {
+    match root {
+        CSTNode::Terminal(_) => root,
+        CSTNode::NonTerminal(node) => {
+            let children = node
+                .children
+                .into_iter()
+                .map(attach_field_semicolons)
+                .collect();
+            let children = match node.kind {
+                "class_body" => attach_semicolons(children),
+                _ => children,
+            };
+
+            CSTNode::NonTerminal(NonTerminal { children, ..node })
+        }
+    }
+}
+
+fn attach_semicolons(children: Vec<CSTNode<'_>>) -> Vec<CSTNode<'_>> {
+    let mut result: Vec<CSTNode> = Vec::with_capacity(children.len());
+
+    for child in children {
+        match (result.last_mut(), &child) {
+            (Some(CSTNode::NonTerminal(field)), CSTNode::Terminal(semicolon))
+                if field.kind == "field_definition" && semicolon.kind == ";" =>
+            {
+                field.end_position = semicolon.end_position;
+                field.children.push(child);
+            }
+            _ => result.push(child),
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode, Point,
+    };
+
+    use super::attach_field_semicolons;
+
+    fn make_terminal<'a>(kind: &'a str, value: &'a str) -> CSTNode<'a> {
+        CSTNode::Terminal(Terminal {
+            id: uuid::Uuid::new_v4(),
+            kind,
+            value,
+            start_position: Point { row: 0, column: 0 },
+            end_position: Point { row: 0, column: 1 },
+            is_block_end_delimiter: false,
+            is_opaque: false,
+            identity: vec![],
+        })
+    }
+
+    fn make_non_terminal<'a>(kind: &'a str, children: Vec<CSTNode<'a>>) -> CSTNode<'a> {
+        CSTNode::NonTerminal(NonTerminal {
+            id: uuid::Uuid::new_v4(),
+            kind,
+            children,
+            ..Default::default()
+        })
+    }
+
+    fn kinds<'a>(node: &'a CSTNode<'a>) -> Vec<&'a str> {
+        match node {
+            CSTNode::NonTerminal(node) => node.children.iter().map(CSTNode::kind).collect(),
+            CSTNode::Terminal(_) => vec![],
+        }
+    }
+
+    #[test]
+    fn semicolons_are_moved_into_the_fields_they_end() {
+        let class_body = make_non_terminal(
+            "class_body",
+            vec![
+                make_terminal("{", "{"),
+                make_non_terminal(
+                    "field_definition",
+                    vec![make_terminal("property_identifier", "a")],
+                ),
+                make_terminal(";", ";"),
+                make_non_terminal(
+                    "method_definition",
+                    vec![make_terminal("property_identifier", "b")],
+                ),
+                make_terminal(";", ";"),
+                make_terminal("}", "}"),
+            ],
+        );
+        let root = make_non_terminal(
+            "program",
+            vec![make_non_terminal("class_declaration", vec![class_body])],
+        );
+
+        let result = attach_field_semicolons(root);
+
+        let CSTNode::NonTerminal(program) = &result else {
+            panic!("Expected a non terminal")
+        };
+        let CSTNode::NonTerminal(class_declaration) = &program.children[0] else {
+            panic!("Expected a non terminal")
+        };
+        let class_body = &class_declaration.children[0];
+        assert_eq!(
+            vec!["{", "field_definition", "method_definition", ";", "}"],
+            kinds(class_body)
+        );
+        let CSTNode::NonTerminal(class_body) = class_body else {
+            panic!("Expected a non terminal")
+        };
+        assert_eq!(
+            vec!["property_identifier", ";"],
+            kinds(&class_body.children[1])
+        );
+    }
+}
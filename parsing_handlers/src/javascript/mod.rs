@@ -0,0 +1,10 @@
+mod attach_field_semicolons;
+
+use crate::{FunctionHandler, ParsingHandlers};
+
+pub fn get_default_javascript_parsing_handlers() -> ParsingHandlers {
+    ParsingHandlers::new(vec![Box::new(FunctionHandler::new(
+        "attach_field_semicolons",
+        attach_field_semicolons::attach_field_semicolons,
+    ))])
+}
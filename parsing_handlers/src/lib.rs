@@ -1,5 +1,18 @@
 mod java;
-mod language;
+mod javascript;
 mod parsing_handlers;
+mod php;
+mod protobuf;
+mod scala;
+mod swift;
+mod toml;
+mod utils;
 
-pub use parsing_handlers::{ParsingHandler, ParsingHandlers};
+pub use java::get_default_java_parsing_handlers;
+pub use javascript::get_default_javascript_parsing_handlers;
+pub use parsing_handlers::{FunctionHandler, ParsingHandler, ParsingHandlers};
+pub use php::get_default_php_parsing_handlers;
+pub use protobuf::get_default_protobuf_parsing_handlers;
+pub use scala::get_default_scala_parsing_handlers;
+pub use swift::get_default_swift_parsing_handlers;
+pub use toml::get_default_toml_parsing_handlers;
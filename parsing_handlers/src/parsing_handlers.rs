@@ -1,17 +1,327 @@
+use std::hash::{Hash, Hasher};
+
 use model::CSTNode;
 
-pub type ParsingHandler = fn(root: CSTNode) -> CSTNode;
+/// Tweaks the tree built by the parser before it is matched, e.g. to group nodes or to mark
+/// them as unordered.
+pub trait ParsingHandler: Send + Sync {
+    /// Name the handler is enabled or disabled by, e.g. `remove_block_comments`.
+    fn name(&self) -> &str;
+
+    /// Names of the handlers that must run before this one, if they are registered.
+    fn run_after(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    /// Handlers without options reject all of them.
+    fn set_option(&mut self, key: &str, _value: &str) -> Result<(), String> {
+        Err(format!(
+            "Parsing handler {} has no option \"{}\"",
+            self.name(),
+            key
+        ))
+    }
+
+    fn handle<'a>(&self, root: CSTNode<'a>) -> CSTNode<'a>;
+}
+
+/// A handler without options, implemented by a plain function.
+pub struct FunctionHandler {
+    name: &'static str,
+    run_after: Vec<&'static str>,
+    function: fn(CSTNode) -> CSTNode,
+}
+
+impl FunctionHandler {
+    pub fn new(name: &'static str, function: fn(CSTNode) -> CSTNode) -> Self {
+        FunctionHandler {
+            name,
+            run_after: vec![],
+            function,
+        }
+    }
+
+    pub fn after(mut self, names: &[&'static str]) -> Self {
+        self.run_after.extend(names);
+        self
+    }
+}
+
+impl ParsingHandler for FunctionHandler {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn run_after(&self) -> Vec<&str> {
+        self.run_after.clone()
+    }
+
+    fn handle<'a>(&self, root: CSTNode<'a>) -> CSTNode<'a> {
+        (self.function)(root)
+    }
+}
 
+struct RegisteredHandler {
+    handler: Box<dyn ParsingHandler>,
+    is_enabled: bool,
+}
+
+/// The handlers of a language, run in the order they were registered in, unless a handler
+/// declares it must run after one registered later.
 pub struct ParsingHandlers {
-    handlers: Vec<ParsingHandler>,
+    handlers: Vec<RegisteredHandler>,
+    /// The options set so far, as the name of the handler, the key and the value.
+    options: Vec<(String, String, String)>,
 }
 
 impl ParsingHandlers {
-    pub fn new(handlers: Vec<ParsingHandler>) -> Self {
-        Self { handlers }
+    /// Panics if handlers share a name or if their declared ordering has a cycle, as built-in
+    /// handlers are expected to be consistent.
+    pub fn new(handlers: Vec<Box<dyn ParsingHandler>>) -> Self {
+        let mut result = ParsingHandlers {
+            handlers: vec![],
+            options: vec![],
+        };
+        for handler in handlers {
+            result
+                .insert_boxed(handler)
+                .expect("Parsing handlers must have unique names and a consistent ordering");
+        }
+        result
+    }
+
+    pub fn insert(&mut self, handler: impl ParsingHandler + 'static) -> Result<(), String> {
+        self.insert_boxed(Box::new(handler))
+    }
+
+    fn insert_boxed(&mut self, handler: Box<dyn ParsingHandler>) -> Result<(), String> {
+        if self.find(handler.name()).is_some() {
+            return Err(format!(
+                "Parsing handler {} is already registered",
+                handler.name()
+            ));
+        }
+        self.handlers.push(RegisteredHandler {
+            handler,
+            is_enabled: true,
+        });
+
+        match get_ordering(&self.handlers) {
+            Some(ordering) => {
+                let mut handlers: Vec<Option<RegisteredHandler>> =
+                    self.handlers.drain(..).map(Some).collect();
+                self.handlers = ordering
+                    .into_iter()
+                    .filter_map(|index| handlers[index].take())
+                    .collect();
+                Ok(())
+            }
+            None => {
+                let handler = self.handlers.pop().unwrap().handler;
+                Err(format!(
+                    "Parsing handler {} can not run after {}, as they depend on each other",
+                    handler.name(),
+                    handler.run_after().join(", ")
+                ))
+            }
+        }
+    }
+
+    pub fn enable(&mut self, name: &str) -> Result<(), String> {
+        self.find_mut(name)?.is_enabled = true;
+        Ok(())
+    }
+
+    pub fn disable(&mut self, name: &str) -> Result<(), String> {
+        self.find_mut(name)?.is_enabled = false;
+        Ok(())
+    }
+
+    pub fn set_option(&mut self, name: &str, key: &str, value: &str) -> Result<(), String> {
+        self.find_mut(name)?.handler.set_option(key, value)?;
+        self.options
+            .push((name.to_string(), key.to_string(), value.to_string()));
+        Ok(())
+    }
+
+    /// Names of the registered handlers, in the order they run in.
+    pub fn names(&self) -> Vec<&str> {
+        self.handlers
+            .iter()
+            .map(|registered| registered.handler.name())
+            .collect()
+    }
+
+    /// Runs the enabled handlers, except for the ones given, e.g. from the command line.
+    pub fn run<'a>(&self, root: CSTNode<'a>, disabled: &[String]) -> CSTNode<'a> {
+        self.handlers
+            .iter()
+            .filter(|registered| registered.is_enabled)
+            .filter(|registered| {
+                !disabled
+                    .iter()
+                    .any(|name| name == registered.handler.name())
+            })
+            .fold(root, |acc, registered| registered.handler.handle(acc))
     }
 
-    pub fn run<'a>(&'a self, root: CSTNode<'a>) -> CSTNode<'a> {
-        self.handlers.iter().fold(root, |acc, handler| handler(acc))
+    fn find(&self, name: &str) -> Option<&RegisteredHandler> {
+        self.handlers
+            .iter()
+            .find(|registered| registered.handler.name() == name)
+    }
+
+    fn find_mut(&mut self, name: &str) -> Result<&mut RegisteredHandler, String> {
+        self.handlers
+            .iter_mut()
+            .find(|registered| registered.handler.name() == name)
+            .ok_or(format!("Unknown parsing handler {}", name))
+    }
+}
+
+/// Hashes which handlers run, in which order and with which options, so that trees built with
+/// different handlers are told apart, e.g. by the parse cache.
+impl Hash for ParsingHandlers {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for registered in self.handlers.iter() {
+            registered.handler.name().hash(state);
+            registered.is_enabled.hash(state);
+        }
+        self.options.hash(state);
+    }
+}
+
+/// Orders the handlers so that each one runs after the ones it declares, keeping the order
+/// they were registered in otherwise. Returns `None` if the declared ordering has a cycle.
+fn get_ordering(handlers: &[RegisteredHandler]) -> Option<Vec<usize>> {
+    let mut ordering: Vec<usize> = Vec::with_capacity(handlers.len());
+
+    while ordering.len() < handlers.len() {
+        let next = (0..handlers.len()).find(|index| {
+            !ordering.contains(index)
+                && handlers[*index]
+                    .handler
+                    .run_after()
+                    .iter()
+                    .all(|dependency| {
+                        handlers
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, other)| other.handler.name() == *dependency)
+                            .all(|(other_index, _)| ordering.contains(&other_index))
+                    })
+        })?;
+        ordering.push(next);
+    }
+
+    Some(ordering)
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{cst_node::Terminal, CSTNode};
+
+    use super::{FunctionHandler, ParsingHandler, ParsingHandlers};
+
+    fn rename(root: CSTNode<'_>) -> CSTNode<'_> {
+        match root {
+            CSTNode::Terminal(terminal) => CSTNode::Terminal(Terminal {
+                value: "renamed",
+                ..terminal
+            }),
+            root => root,
+        }
+    }
+
+    fn make_terminal() -> CSTNode<'static> {
+        CSTNode::Terminal(Terminal {
+            kind: "identifier",
+            value: "original",
+            ..Default::default()
+        })
+    }
+
+    struct Suffix {
+        suffix: &'static str,
+    }
+
+    impl ParsingHandler for Suffix {
+        fn name(&self) -> &str {
+            "suffix"
+        }
+
+        fn set_option(&mut self, key: &str, value: &str) -> Result<(), String> {
+            match (key, value) {
+                ("suffix", "s") => self.suffix = "s",
+                _ => return Err(format!("Invalid option {}", key)),
+            }
+            Ok(())
+        }
+
+        fn handle<'a>(&self, root: CSTNode<'a>) -> CSTNode<'a> {
+            match root {
+                CSTNode::Terminal(terminal) => CSTNode::Terminal(Terminal {
+                    kind: self.suffix,
+                    ..terminal
+                }),
+                root => root,
+            }
+        }
+    }
+
+    #[test]
+    fn handlers_run_after_the_ones_they_declare() {
+        let handlers = ParsingHandlers::new(vec![
+            Box::new(FunctionHandler::new("c", rename).after(&["b"])),
+            Box::new(FunctionHandler::new("a", rename)),
+            Box::new(FunctionHandler::new("b", rename).after(&["a", "unknown"])),
+        ]);
+
+        assert_eq!(vec!["a", "b", "c"], handlers.names());
+    }
+
+    #[test]
+    fn handlers_with_cyclic_orderings_are_rejected() {
+        let mut handlers = ParsingHandlers::new(vec![Box::new(
+            FunctionHandler::new("a", rename).after(&["b"]),
+        )]);
+
+        assert!(handlers
+            .insert(FunctionHandler::new("b", rename).after(&["a"]))
+            .is_err());
+        assert!(handlers.insert(FunctionHandler::new("a", rename)).is_err());
+        assert_eq!(vec!["a"], handlers.names());
+    }
+
+    #[test]
+    fn disabled_handlers_do_not_run() {
+        let mut handlers =
+            ParsingHandlers::new(vec![Box::new(FunctionHandler::new("rename", rename))]);
+
+        handlers.disable("rename").unwrap();
+        assert_eq!(make_terminal(), handlers.run(make_terminal(), &[]));
+        assert!(handlers.disable("unknown").is_err());
+
+        handlers.enable("rename").unwrap();
+        let CSTNode::Terminal(renamed) = handlers.run(make_terminal(), &[]) else {
+            panic!("Expected a terminal");
+        };
+        assert_eq!("renamed", renamed.value);
+
+        let CSTNode::Terminal(kept) = handlers.run(make_terminal(), &["rename".into()]) else {
+            panic!("Expected a terminal");
+        };
+        assert_eq!("original", kept.value);
+    }
+
+    #[test]
+    fn options_are_passed_on_to_the_handler() {
+        let mut handlers = ParsingHandlers::new(vec![]);
+        handlers.insert(Suffix { suffix: "" }).unwrap();
+
+        assert!(handlers.set_option("suffix", "suffix", "x").is_err());
+        handlers.set_option("suffix", "suffix", "s").unwrap();
+
+        assert_eq!("s", handlers.run(make_terminal(), &[]).kind());
     }
 }
@@ -0,0 +1,10 @@
+mod tweak_namespace_use_declarations;
+
+use crate::{FunctionHandler, ParsingHandlers};
+
+pub fn get_default_php_parsing_handlers() -> ParsingHandlers {
+    ParsingHandlers::new(vec![Box::new(FunctionHandler::new(
+        "tweak_namespace_use_declarations",
+        tweak_namespace_use_declarations::tweak_namespace_use_declarations,
+    ))])
+}
@@ -0,0 +1,81 @@
+use model::CSTNode;
+
+use crate::utils::group_children_of_kind;
+
+pub fn tweak_namespace_use_declarations(root: CSTNode<'_>) -> CSTNode<'_> {
+    if root.kind() != "program" {
+        return root;
+    }
+
+    group_children_of_kind(
+        root,
+        "namespace_use_declaration",
+        "namespace_use_declarations",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    #[test]
+    fn it_groups_use_declarations_into_an_unordered_node() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "program",
+            children: vec![
+                CSTNode::Terminal(Terminal {
+                    kind: "php_tag",
+                    value: "<?php",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "namespace_use_declaration",
+                    value: "use App\\Models\\User;",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "namespace_use_declaration",
+                    value: "use App\\Models\\Post;",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "class_declaration",
+                    value: "class A {}",
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        });
+
+        let result = super::tweak_namespace_use_declarations(root);
+
+        let CSTNode::NonTerminal(program) = result else {
+            panic!("Expected program to remain a non terminal");
+        };
+        let kinds: Vec<&str> = program.children.iter().map(|node| node.kind()).collect();
+        assert_eq!(
+            vec!["php_tag", "namespace_use_declarations", "class_declaration"],
+            kinds
+        );
+
+        let CSTNode::NonTerminal(group) = &program.children[1] else {
+            panic!("Expected use declarations to be grouped in a non terminal");
+        };
+        assert!(group.are_children_unordered);
+        assert_eq!(2, group.children.len());
+    }
+
+    #[test]
+    fn if_the_root_is_not_a_program_we_just_return_it() {
+        let root = CSTNode::Terminal(Terminal {
+            kind: "terminal",
+            value: "not_a_program",
+            ..Default::default()
+        });
+
+        assert_eq!(super::tweak_namespace_use_declarations(root.clone()), root);
+    }
+}
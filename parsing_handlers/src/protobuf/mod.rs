@@ -0,0 +1,10 @@
+mod tweak_service_bodies;
+
+use crate::{FunctionHandler, ParsingHandlers};
+
+pub fn get_default_protobuf_parsing_handlers() -> ParsingHandlers {
+    ParsingHandlers::new(vec![Box::new(FunctionHandler::new(
+        "tweak_service_bodies",
+        tweak_service_bodies::tweak_service_bodies,
+    ))])
+}
@@ -0,0 +1,129 @@
+use model::{cst_node::NonTerminal, derive_node_id, CSTNode};
+
+/// The grammar places the rpcs and options of a service directly under the `service` node,
+/// next to its name. They are moved, along with the braces, into an unordered `service_body`
+/// node, mirroring how message and enum bodies are represented.
+pub fn tweak_service_bodies(root: CSTNode<'_>) -> CSTNode<'_> {
+    if root.kind() != "source_file" {
+        return root;
+    }
+
+    match root {
+        CSTNode::Terminal(_) => root,
+        CSTNode::NonTerminal(source_file) => CSTNode::NonTerminal(NonTerminal {
+            id: source_file.id,
+            kind: source_file.kind,
+            start_position: source_file.start_position,
+            end_position: source_file.end_position,
+            children: source_file
+                .children
+                .into_iter()
+                .map(tweak_service_body)
+                .collect(),
+            are_children_unordered: source_file.are_children_unordered,
+            identity: source_file.identity.clone(),
+            injected_language: source_file.injected_language.clone(),
+        }),
+    }
+}
+
+fn tweak_service_body(node: CSTNode<'_>) -> CSTNode<'_> {
+    match node {
+        CSTNode::NonTerminal(service) if service.kind == "service" => {
+            let Some(body_start) = service.children.iter().position(|node| node.kind() == "{")
+            else {
+                return CSTNode::NonTerminal(service);
+            };
+
+            let mut children = service.children.into_iter();
+            let mut new_service_children: Vec<CSTNode> =
+                children.by_ref().take(body_start).collect();
+            let body_children: Vec<CSTNode> = children.collect();
+
+            new_service_children.push(CSTNode::NonTerminal(NonTerminal {
+                id: derive_node_id(service.id, "service_body"),
+                kind: "service_body",
+                start_position: body_children.first().unwrap().start_position(),
+                end_position: body_children.last().unwrap().end_position(),
+                children: body_children,
+                are_children_unordered: true,
+                identity: vec![],
+                injected_language: None,
+            }));
+
+            CSTNode::NonTerminal(NonTerminal {
+                id: service.id,
+                kind: service.kind,
+                start_position: service.start_position,
+                end_position: service.end_position,
+                children: new_service_children,
+                are_children_unordered: service.are_children_unordered,
+                identity: service.identity.clone(),
+                injected_language: service.injected_language.clone(),
+            })
+        }
+        _ => node,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    #[test]
+    fn it_moves_the_contents_of_services_into_an_unordered_body() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "source_file",
+            children: vec![CSTNode::NonTerminal(NonTerminal {
+                kind: "service",
+                children: vec![
+                    make_terminal("service", "service"),
+                    make_terminal("service_name", "OrderService"),
+                    make_terminal("{", "{"),
+                    make_terminal("rpc", "rpc Get(GetRequest) returns (Order);"),
+                    make_terminal("rpc", "rpc List(ListRequest) returns (Orders);"),
+                    make_terminal("}", "}"),
+                ],
+                ..Default::default()
+            })],
+            ..Default::default()
+        });
+
+        let CSTNode::NonTerminal(source_file) = super::tweak_service_bodies(root) else {
+            panic!("Expected source file to remain a non terminal");
+        };
+        let CSTNode::NonTerminal(service) = &source_file.children[0] else {
+            panic!("Expected service to remain a non terminal");
+        };
+        let kinds: Vec<&str> = service.children.iter().map(|node| node.kind()).collect();
+        assert_eq!(vec!["service", "service_name", "service_body"], kinds);
+
+        let CSTNode::NonTerminal(service_body) = &service.children[2] else {
+            panic!("Expected service body to be a non terminal");
+        };
+        assert!(service_body.are_children_unordered);
+        let kinds: Vec<&str> = service_body
+            .children
+            .iter()
+            .map(|node| node.kind())
+            .collect();
+        assert_eq!(vec!["{", "rpc", "rpc", "}"], kinds);
+    }
+
+    #[test]
+    fn if_the_root_is_not_a_source_file_we_just_return_it() {
+        let root = make_terminal("terminal", "not_a_source_file");
+        assert_eq!(super::tweak_service_bodies(root.clone()), root);
+    }
+
+    fn make_terminal<'a>(kind: &'a str, value: &'a str) -> CSTNode<'a> {
+        CSTNode::Terminal(Terminal {
+            kind,
+            value,
+            ..Default::default()
+        })
+    }
+}
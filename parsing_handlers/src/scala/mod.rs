@@ -0,0 +1,10 @@
+mod tweak_import_declarations;
+
+use crate::{FunctionHandler, ParsingHandlers};
+
+pub fn get_default_scala_parsing_handlers() -> ParsingHandlers {
+    ParsingHandlers::new(vec![Box::new(FunctionHandler::new(
+        "tweak_import_declarations",
+        tweak_import_declarations::tweak_import_declarations,
+    ))])
+}
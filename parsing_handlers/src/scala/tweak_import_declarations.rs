@@ -0,0 +1,73 @@
+use model::CSTNode;
+
+use crate::utils::group_children_of_kind;
+
+pub fn tweak_import_declarations(root: CSTNode<'_>) -> CSTNode<'_> {
+    if root.kind() != "compilation_unit" {
+        return root;
+    }
+
+    group_children_of_kind(root, "import_declaration", "import_declarations")
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    #[test]
+    fn it_groups_imports_of_the_compilation_unit_into_an_unordered_node() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "compilation_unit",
+            children: vec![
+                CSTNode::Terminal(Terminal {
+                    kind: "package_clause",
+                    value: "package com.example",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "import_declaration",
+                    value: "import scala.collection.mutable",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "import_declaration",
+                    value: "import scala.util.Try",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "object_definition",
+                    value: "object Main",
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        });
+
+        let CSTNode::NonTerminal(compilation_unit) = super::tweak_import_declarations(root) else {
+            panic!("Expected compilation unit to remain a non terminal");
+        };
+        let kinds: Vec<&str> = compilation_unit
+            .children
+            .iter()
+            .map(|node| node.kind())
+            .collect();
+        assert_eq!(
+            vec!["package_clause", "import_declarations", "object_definition"],
+            kinds
+        );
+    }
+
+    #[test]
+    fn if_the_root_is_not_a_compilation_unit_we_just_return_it() {
+        let root = CSTNode::Terminal(Terminal {
+            kind: "terminal",
+            value: "not_a_compilation_unit",
+            ..Default::default()
+        });
+
+        assert_eq!(super::tweak_import_declarations(root.clone()), root);
+    }
+}
@@ -0,0 +1,73 @@
+use model::CSTNode;
+
+use crate::utils::group_children_of_kind;
+
+pub fn tweak_import_declarations(root: CSTNode<'_>) -> CSTNode<'_> {
+    if root.kind() != "source_file" {
+        return root;
+    }
+
+    group_children_of_kind(root, "import_declaration", "import_declarations")
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    #[test]
+    fn it_groups_imports_of_the_source_file_into_an_unordered_node() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "source_file",
+            children: vec![
+                CSTNode::Terminal(Terminal {
+                    kind: "comment",
+                    value: "// Main.swift",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "import_declaration",
+                    value: "import Foundation",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "import_declaration",
+                    value: "import UIKit",
+                    ..Default::default()
+                }),
+                CSTNode::Terminal(Terminal {
+                    kind: "class_declaration",
+                    value: "class Main",
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        });
+
+        let CSTNode::NonTerminal(source_file) = super::tweak_import_declarations(root) else {
+            panic!("Expected source file to remain a non terminal");
+        };
+        let kinds: Vec<&str> = source_file
+            .children
+            .iter()
+            .map(|node| node.kind())
+            .collect();
+        assert_eq!(
+            vec!["comment", "import_declarations", "class_declaration"],
+            kinds
+        );
+    }
+
+    #[test]
+    fn if_the_root_is_not_a_source_file_we_just_return_it() {
+        let root = CSTNode::Terminal(Terminal {
+            kind: "terminal",
+            value: "not_a_source_file",
+            ..Default::default()
+        });
+
+        assert_eq!(super::tweak_import_declarations(root.clone()), root);
+    }
+}
@@ -0,0 +1,10 @@
+mod tweak_table_pairs;
+
+use crate::{FunctionHandler, ParsingHandlers};
+
+pub fn get_default_toml_parsing_handlers() -> ParsingHandlers {
+    ParsingHandlers::new(vec![Box::new(FunctionHandler::new(
+        "tweak_table_pairs",
+        tweak_table_pairs::tweak_table_pairs,
+    ))])
+}
@@ -0,0 +1,205 @@
+use model::{cst_node::NonTerminal, derive_node_id, CSTNode, Point};
+
+const TABLE_KINDS: [&str; 2] = ["table", "table_array_element"];
+const TABLE_HEADER_END_DELIMITERS: [&str; 2] = ["]", "]]"];
+
+/// Moves the key/value pairs of the document root and of every table into an unordered `pairs`
+/// node, so that the table header stays in place while its entries are merged as a set.
+/// The node is created even for empty tables, so that pairs added by a single revision still
+/// have a counterpart to be merged with.
+pub fn tweak_table_pairs(root: CSTNode<'_>) -> CSTNode<'_> {
+    if root.kind() != "document" {
+        return root;
+    }
+
+    match root {
+        CSTNode::Terminal(_) => root,
+        CSTNode::NonTerminal(document) => {
+            let first_table_index = document
+                .children
+                .iter()
+                .position(|node| TABLE_KINDS.contains(&node.kind()))
+                .unwrap_or(document.children.len());
+
+            let mut children = document.children.into_iter();
+            let root_pairs: Vec<CSTNode> = children.by_ref().take(first_table_index).collect();
+
+            let mut new_document_children = vec![make_pairs_node(
+                document.id,
+                root_pairs,
+                document.start_position,
+            )];
+            new_document_children.extend(children.map(tweak_table));
+
+            CSTNode::NonTerminal(NonTerminal {
+                id: document.id,
+                kind: document.kind,
+                start_position: document.start_position,
+                end_position: document.end_position,
+                children: new_document_children,
+                are_children_unordered: document.are_children_unordered,
+                identity: document.identity.clone(),
+                injected_language: document.injected_language.clone(),
+            })
+        }
+    }
+}
+
+fn tweak_table(node: CSTNode<'_>) -> CSTNode<'_> {
+    match node {
+        CSTNode::NonTerminal(table) if TABLE_KINDS.contains(&table.kind) => {
+            let header_length = table
+                .children
+                .iter()
+                .position(|node| TABLE_HEADER_END_DELIMITERS.contains(&node.kind()))
+                .map_or(0, |index| index + 1);
+            let header_end = table.children[..header_length]
+                .last()
+                .map_or(table.start_position, |node| node.end_position());
+
+            let mut children = table.children.into_iter();
+            let mut new_table_children: Vec<CSTNode> =
+                children.by_ref().take(header_length).collect();
+            new_table_children.push(make_pairs_node(table.id, children.collect(), header_end));
+
+            CSTNode::NonTerminal(NonTerminal {
+                id: table.id,
+                kind: table.kind,
+                start_position: table.start_position,
+                end_position: table.end_position,
+                children: new_table_children,
+                are_children_unordered: table.are_children_unordered,
+                identity: table.identity.clone(),
+                injected_language: table.injected_language.clone(),
+            })
+        }
+        _ => node,
+    }
+}
+
+fn make_pairs_node(
+    table_id: uuid::Uuid,
+    children: Vec<CSTNode<'_>>,
+    fallback_position: Point,
+) -> CSTNode<'_> {
+    let start_position = children
+        .first()
+        .map_or(fallback_position, |node| node.start_position());
+    let end_position = children
+        .last()
+        .map_or(fallback_position, |node| node.end_position());
+
+    CSTNode::NonTerminal(NonTerminal {
+        id: derive_node_id(table_id, "pairs"),
+        kind: "pairs",
+        children,
+        start_position,
+        end_position,
+        are_children_unordered: true,
+        identity: vec![],
+        injected_language: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use model::{
+        cst_node::{NonTerminal, Terminal},
+        CSTNode,
+    };
+
+    fn make_terminal(kind: &str) -> CSTNode<'_> {
+        CSTNode::Terminal(Terminal {
+            kind,
+            value: kind,
+            ..Default::default()
+        })
+    }
+
+    fn kinds_of<'a>(node: &'a CSTNode<'a>) -> Vec<&'a str> {
+        match node {
+            CSTNode::NonTerminal(non_terminal) => non_terminal
+                .children
+                .iter()
+                .map(|child| child.kind())
+                .collect(),
+            CSTNode::Terminal(_) => vec![],
+        }
+    }
+
+    #[test]
+    fn it_groups_pairs_of_the_root_and_of_each_table() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "document",
+            children: vec![
+                make_terminal("pair"),
+                CSTNode::NonTerminal(NonTerminal {
+                    kind: "table",
+                    children: vec![
+                        make_terminal("["),
+                        make_terminal("bare_key"),
+                        make_terminal("]"),
+                        make_terminal("pair"),
+                        make_terminal("comment"),
+                        make_terminal("pair"),
+                    ],
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        });
+
+        let result = super::tweak_table_pairs(root);
+
+        assert_eq!(vec!["pairs", "table"], kinds_of(&result));
+        let CSTNode::NonTerminal(document) = &result else {
+            panic!("Expected document to remain a non terminal");
+        };
+        assert_eq!(vec!["pair"], kinds_of(&document.children[0]));
+        assert_eq!(
+            vec!["[", "bare_key", "]", "pairs"],
+            kinds_of(&document.children[1])
+        );
+        let CSTNode::NonTerminal(table) = &document.children[1] else {
+            panic!("Expected table to remain a non terminal");
+        };
+        assert_eq!(
+            vec!["pair", "comment", "pair"],
+            kinds_of(&table.children[3])
+        );
+    }
+
+    #[test]
+    fn it_creates_an_empty_group_for_tables_without_pairs() {
+        let root = CSTNode::NonTerminal(NonTerminal {
+            kind: "document",
+            children: vec![CSTNode::NonTerminal(NonTerminal {
+                kind: "table_array_element",
+                children: vec![
+                    make_terminal("[["),
+                    make_terminal("bare_key"),
+                    make_terminal("]]"),
+                ],
+                ..Default::default()
+            })],
+            ..Default::default()
+        });
+
+        let result = super::tweak_table_pairs(root);
+
+        let CSTNode::NonTerminal(document) = &result else {
+            panic!("Expected document to remain a non terminal");
+        };
+        assert_eq!(
+            vec!["[[", "bare_key", "]]", "pairs"],
+            kinds_of(&document.children[1])
+        );
+        assert!(kinds_of(&document.children[0]).is_empty());
+    }
+
+    #[test]
+    fn if_the_root_is_not_a_document_we_just_return_it() {
+        let root = make_terminal("pair");
+        assert_eq!(super::tweak_table_pairs(root.clone()), root);
+    }
+}
@@ -0,0 +1,68 @@
+use model::{cst_node::NonTerminal, derive_node_id, CSTNode};
+
+/// Moves every child of `root` of the given `kind` into a single unordered node of kind
+/// `group_kind`, placed where the first of those children used to be.
+pub fn group_children_of_kind<'a>(
+    root: CSTNode<'a>,
+    kind: &str,
+    group_kind: &'static str,
+) -> CSTNode<'a> {
+    match root {
+        CSTNode::Terminal(_) => root,
+        CSTNode::NonTerminal(program) => {
+            let grouped_children: Vec<CSTNode> = program
+                .children
+                .iter()
+                .filter(|node| node.kind() == kind)
+                .cloned()
+                .collect();
+
+            if grouped_children.is_empty() {
+                return CSTNode::NonTerminal(program);
+            }
+
+            let group_start = grouped_children.first().unwrap().start_position();
+            let group_end = grouped_children.last().unwrap().end_position();
+
+            let group = CSTNode::NonTerminal(NonTerminal {
+                id: derive_node_id(program.id, group_kind),
+                kind: group_kind,
+                children: grouped_children,
+                start_position: group_start,
+                end_position: group_end,
+                are_children_unordered: true,
+                identity: vec![],
+                injected_language: None,
+            });
+
+            let first_index = program
+                .children
+                .iter()
+                .position(|node| node.kind() == kind)
+                .unwrap();
+            let last_index = program
+                .children
+                .iter()
+                .rposition(|node| node.kind() == kind)
+                .unwrap();
+
+            let mut new_program_children: Vec<CSTNode<'_>> = vec![];
+            new_program_children
+                .extend_from_slice(&program.children.iter().as_slice()[..first_index]);
+            new_program_children.push(group);
+            new_program_children
+                .extend_from_slice(&program.children.iter().as_slice()[last_index + 1..]);
+
+            CSTNode::NonTerminal(NonTerminal {
+                id: program.id,
+                kind: program.kind,
+                start_position: program.start_position,
+                end_position: program.end_position,
+                children: new_program_children,
+                are_children_unordered: program.are_children_unordered,
+                identity: program.identity.clone(),
+                injected_language: program.injected_language.clone(),
+            })
+        }
+    }
+}